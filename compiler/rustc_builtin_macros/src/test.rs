@@ -7,6 +7,7 @@
 use rustc_ast::ptr::P;
 use rustc_ast_pretty::pprust;
 use rustc_expand::base::*;
+use rustc_session::parse::feature_err;
 use rustc_session::Session;
 use rustc_span::symbol::{sym, Ident, Symbol};
 use rustc_span::Span;
@@ -54,8 +55,43 @@ pub fn expand_test(
     meta_item: &ast::MetaItem,
     item: Annotatable,
 ) -> Vec<Annotatable> {
-    check_builtin_macro_attribute(cx, meta_item, sym::test);
-    expand_test_or_bench(cx, attr_sp, item, false)
+    let resource = test_resource(cx, attr_sp, meta_item);
+    expand_test_or_bench(cx, attr_sp, item, false, resource)
+}
+
+/// Handles `#[test(resource = "name")]`, which keeps tests that use the same resource
+/// from running at the same time.
+fn test_resource(cx: &ExtCtxt<'_>, attr_sp: Span, meta_item: &ast::MetaItem) -> Option<Symbol> {
+    let list = match meta_item.meta_item_list() {
+        Some(list) => list,
+        None => {
+            check_builtin_macro_attribute(cx, meta_item, sym::test);
+            return None;
+        }
+    };
+
+    if !cx.ecfg.features.map_or(true, |features| features.test_resource_groups) {
+        feature_err(
+            &cx.sess.parse_sess,
+            sym::test_resource_groups,
+            attr_sp,
+            "the `resource` argument of `#[test]` is experimental",
+        )
+        .emit();
+    }
+
+    let resource = list
+        .iter()
+        .find(|mi| mi.has_name(sym::resource))
+        .and_then(|mi| mi.meta_item())
+        .and_then(|mi| mi.value_str());
+    if list.len() != 1 || resource.is_none() {
+        cx.sess
+            .parse_sess
+            .span_diagnostic
+            .span_err(meta_item.span, "argument must be of the form: `resource = \"name\"`");
+    }
+    resource
 }
 
 pub fn expand_bench(
@@ -65,7 +101,7 @@ pub fn expand_bench(
     item: Annotatable,
 ) -> Vec<Annotatable> {
     check_builtin_macro_attribute(cx, meta_item, sym::bench);
-    expand_test_or_bench(cx, attr_sp, item, true)
+    expand_test_or_bench(cx, attr_sp, item, true, None)
 }
 
 pub fn expand_test_or_bench(
@@ -73,6 +109,7 @@ pub fn expand_test_or_bench(
     attr_sp: Span,
     item: Annotatable,
     is_bench: bool,
+    resource: Option<Symbol>,
 ) -> Vec<Annotatable> {
     // If we're not in test configuration, remove the annotated item
     if !cx.ecfg.should_test {
@@ -150,6 +187,12 @@ pub fn expand_test_or_bench(
     // creates $name: $expr
     let field = |name, expr| cx.field_imm(sp, Ident::from_str_and_span(name, sp), expr);
 
+    // The same path `file!()` would expand to inside the test function.
+    let source_file = {
+        let loc = cx.source_map().lookup_char_pos(item.span.lo());
+        Symbol::intern(&loc.file.name.prefer_remapped().to_string_lossy())
+    };
+
     let test_fn = if is_bench {
         // A simple ident for a lambda
         let b = Ident::from_str_and_span("b", attr_sp);
@@ -296,6 +339,30 @@ pub fn expand_test_or_bench(
                                             }
                                         },
                                     ),
+                                    // libtest only has the fields below when it is not built
+                                    // by the bootstrap compiler, whose `#[test]` doesn't emit
+                                    // them; this macro always expands against that libtest.
+                                    //
+                                    // metadata: &[]
+                                    field("metadata", cx.expr_vec_slice(sp, vec![])),
+                                    // resource: Some("...") | None
+                                    field(
+                                        "resource",
+                                        match resource {
+                                            Some(resource) => {
+                                                cx.expr_some(sp, cx.expr_str(sp, resource))
+                                            }
+                                            None => cx.expr_path(cx.path_global(
+                                                sp,
+                                                cx.std_path(&[sym::option, sym::Option, sym::None]),
+                                            )),
+                                        },
+                                    ),
+                                    // source_file: Some("path/to/file.rs")
+                                    field(
+                                        "source_file",
+                                        cx.expr_some(sp, cx.expr_str(sp, source_file)),
+                                    ),
                                     // },
                                 ],
                             ),
@@ -0,0 +1,8 @@
+// Checks that `intrinsics::abort()` lowers to a trap that kills the process with `SIGILL`.
+
+#![feature(core_intrinsics)]
+
+fn main() {
+    println!("aborting");
+    std::intrinsics::abort();
+}
@@ -0,0 +1,25 @@
+// Only symbols with C export level should end up in the dynamic symbol table of a cdylib. The
+// others should be hidden when they can't be made local to their codegen unit.
+
+mod a {
+    #[no_mangle]
+    pub extern "C" fn cg_clif_exported_fn() -> u32 {
+        crate::shared::cg_clif_hidden_fn() + 1
+    }
+}
+
+mod b {
+    #[no_mangle]
+    pub extern "C" fn cg_clif_other_exported_fn() -> u32 {
+        crate::shared::cg_clif_hidden_fn() + 2
+    }
+}
+
+mod shared {
+    // Every module gets its own codegen unit, so this is used from two of them and can't be
+    // internalized. It isn't exported either, so it gets hidden visibility.
+    #[inline(never)]
+    pub(crate) fn cg_clif_hidden_fn() -> u32 {
+        41
+    }
+}
@@ -1,4 +1,4 @@
-#![feature(core_intrinsics, generators, generator_trait, is_sorted)]
+#![feature(core_intrinsics, generators, generator_trait, is_sorted, bench_black_box, rustc_attrs)]
 
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
@@ -93,6 +93,9 @@ fn main() {
     }
 
     test_checked_mul();
+    test_bit_intrinsics();
+    test_copy();
+    test_discriminants();
 
     let _a = 1u32 << 2u8;
 
@@ -314,6 +317,177 @@ fn test_checked_mul() {
     assert_eq!(i64::MIN.checked_mul(i64::MIN), None);
 }
 
+// Checks the bit manipulation intrinsics against models that only use shifts and masks.
+fn test_bit_intrinsics() {
+    macro_rules! check {
+        ($($t:ident)*) => {$({
+            const BITS: u32 = $t::BITS;
+            let values = [0, 1, 2, $t::MAX, $t::MAX - 1, $t::MAX >> 1, !($t::MAX >> 1), 0x5a as $t];
+            for &x in &values {
+                let x = std::hint::black_box(x);
+
+                let mut ones = 0;
+                let mut leading_zeros = 0;
+                let mut trailing_zeros = 0;
+                for i in 0..BITS {
+                    if (x >> i) & 1 == 1 {
+                        ones += 1;
+                    }
+                    if x >> (BITS - 1 - i) == 0 {
+                        leading_zeros += 1;
+                    }
+                    if x << (BITS - 1 - i) == 0 {
+                        trailing_zeros += 1;
+                    }
+                }
+                assert_eq!(x.count_ones(), ones, "{}::count_ones({:#x})", stringify!($t), x);
+                assert_eq!(x.leading_zeros(), leading_zeros, "{}::leading_zeros({:#x})", stringify!($t), x);
+                assert_eq!(x.trailing_zeros(), trailing_zeros, "{}::trailing_zeros({:#x})", stringify!($t), x);
+
+                for &n in &[0, 1, BITS / 2 - 1, BITS / 2, BITS / 2 + 1, BITS - 1, BITS, BITS + 3] {
+                    let n = std::hint::black_box(n);
+                    let m = n % BITS;
+                    let left = if m == 0 { x } else { (x << m) | (x >> (BITS - m)) };
+                    let right = if m == 0 { x } else { (x >> m) | (x << (BITS - m)) };
+                    assert_eq!(x.rotate_left(n), left, "{}::rotate_left({:#x}, {})", stringify!($t), x, n);
+                    assert_eq!(x.rotate_right(n), right, "{}::rotate_right({:#x}, {})", stringify!($t), x, n);
+                }
+            }
+        })*};
+    }
+
+    check!(u8 u16 u32 u64 u128 usize);
+
+    assert_eq!(std::hint::black_box(-1i128).count_ones(), 128);
+    assert_eq!(std::hint::black_box(i128::MIN).rotate_left(1), 1);
+    assert_eq!(std::hint::black_box(-2i128).rotate_right(65), !(1 << 63));
+}
+
+fn test_copy() {
+    // Sizes below, at and above the inline load/store threshold, with both constant and runtime
+    // element counts.
+    fn check<T: Copy + PartialEq + std::fmt::Debug, const N: usize>(init: [T; N]) {
+        let mut dst = init;
+        unsafe { std::ptr::copy_nonoverlapping(init.as_ptr(), dst.as_mut_ptr(), N) };
+        assert_eq!(dst, init);
+
+        // Overlapping copy to the right and to the left.
+        let mut buf = init;
+        unsafe { std::ptr::copy(buf.as_ptr(), buf.as_mut_ptr().add(1), N - 1) };
+        assert_eq!(buf[0], init[0]);
+        assert_eq!(&buf[1..], &init[..N - 1]);
+
+        let mut buf = init;
+        unsafe { std::ptr::copy(buf.as_ptr().add(1), buf.as_mut_ptr(), N - 1) };
+        assert_eq!(&buf[..N - 1], &init[1..]);
+        assert_eq!(buf[N - 1], init[N - 1]);
+
+        let count = std::hint::black_box(N - 1);
+        let mut buf = init;
+        unsafe { std::ptr::copy(buf.as_ptr(), buf.as_mut_ptr().add(1), count) };
+        assert_eq!(&buf[1..], &init[..N - 1]);
+    }
+
+    check([1u8, 2, 3]);
+    check([1u8, 2, 3, 4, 5, 6, 7, 8, 9]);
+    check([1u16, 2, 3, 4, 5]);
+    check([1u32, 2, 3, 4, 5, 6, 7]);
+    check([1u64, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17]);
+    check([[1u8; 3], [2; 3], [3; 3], [4; 3]]);
+
+    // Zero-sized copies must not touch memory.
+    let mut empty: [u32; 0] = [];
+    unsafe { std::ptr::copy_nonoverlapping([].as_ptr(), empty.as_mut_ptr(), 0) };
+}
+
+fn test_discriminants() {
+    use std::hint::black_box;
+
+    // Niche in a non-null pointer.
+    fn option_ref(x: Option<&u32>) -> u32 {
+        match x {
+            Some(&v) => v,
+            None => 42,
+        }
+    }
+    let value = 7;
+    assert_eq!(option_ref(black_box(Some(&value))), 7);
+    assert_eq!(option_ref(black_box(None)), 42);
+
+    // C-like enum with explicit and negative discriminants.
+    #[derive(Copy, Clone)]
+    #[repr(i8)]
+    enum CLike {
+        A = -3,
+        B = 5,
+        C,
+    }
+    fn c_like(x: CLike) -> u8 {
+        match x {
+            CLike::A => 1,
+            CLike::B => 2,
+            CLike::C => 3,
+        }
+    }
+    assert_eq!(c_like(black_box(CLike::A)), 1);
+    assert_eq!(c_like(black_box(CLike::B)), 2);
+    assert_eq!(c_like(black_box(CLike::C)), 3);
+    assert_eq!(black_box(CLike::A) as i8, -3);
+    assert_eq!(black_box(CLike::C) as i8, 6);
+
+    // Data-carrying enum with a separate tag.
+    enum Data {
+        Int(u64),
+        Pair(u8, u16),
+        Empty,
+    }
+    fn data(x: &Data) -> u64 {
+        match *x {
+            Data::Int(i) => i,
+            Data::Pair(a, b) => u64::from(a) + u64::from(b),
+            Data::Empty => 0,
+        }
+    }
+    assert_eq!(data(black_box(&Data::Int(100))), 100);
+    assert_eq!(data(black_box(&Data::Pair(1, 2))), 3);
+    assert_eq!(data(black_box(&Data::Empty)), 0);
+
+    // Several niche variants whose niche values wrap around from 255 to 0.
+    #[rustc_layout_scalar_valid_range_start(2)]
+    #[rustc_layout_scalar_valid_range_end(253)]
+    #[derive(Copy, Clone)]
+    struct Small(u8);
+
+    #[derive(Copy, Clone)]
+    enum Wrapping {
+        Val(Small),
+        A,
+        B,
+        C,
+        D,
+    }
+    fn wrapping(x: Wrapping) -> u8 {
+        match x {
+            Wrapping::Val(Small(v)) => v,
+            Wrapping::A => 1,
+            Wrapping::B => 2,
+            Wrapping::C => 3,
+            Wrapping::D => 4,
+        }
+    }
+    assert_eq!(std::mem::size_of::<Wrapping>(), 1);
+    let small = unsafe { Small(100) };
+    assert_eq!(wrapping(black_box(Wrapping::Val(small))), 100);
+    assert_eq!(wrapping(black_box(Wrapping::A)), 1);
+    assert_eq!(wrapping(black_box(Wrapping::B)), 2);
+    assert_eq!(wrapping(black_box(Wrapping::C)), 3);
+    assert_eq!(wrapping(black_box(Wrapping::D)), 4);
+    for (x, niche) in [(Wrapping::A, 254u8), (Wrapping::B, 255), (Wrapping::C, 0), (Wrapping::D, 1)]
+    {
+        assert_eq!(unsafe { std::mem::transmute::<Wrapping, u8>(black_box(x)) }, niche);
+    }
+}
+
 #[derive(PartialEq)]
 enum LoopState {
     Continue(()),
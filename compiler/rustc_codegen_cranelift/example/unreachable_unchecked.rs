@@ -0,0 +1,14 @@
+// Checks that reaching `hint::unreachable_unchecked()` with debug assertions enabled traps and
+// kills the process with `SIGILL` instead of running into whatever code follows.
+
+#![feature(bench_black_box)]
+
+use std::hint::{black_box, unreachable_unchecked};
+
+fn main() {
+    println!("reaching unreachable_unchecked");
+    if black_box(true) {
+        unsafe { unreachable_unchecked() }
+    }
+    println!("unreachable_unchecked returned");
+}
@@ -0,0 +1,41 @@
+// Volatile accesses must neither be removed nor merged. If they were, the spin loop below
+// would only read `FLAG` once and never see the store made by the other thread.
+
+use std::cell::UnsafeCell;
+use std::ptr;
+use std::thread;
+use std::time::{Duration, Instant};
+
+struct Shared(UnsafeCell<u32>);
+
+unsafe impl Sync for Shared {}
+
+static FLAG: Shared = Shared(UnsafeCell::new(0));
+
+fn main() {
+    let flag = FLAG.0.get();
+
+    let writer = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        unsafe { ptr::write_volatile(FLAG.0.get(), 1) };
+    });
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while unsafe { ptr::read_volatile(flag) } == 0 {
+        assert!(Instant::now() < deadline, "volatile load never observed the store");
+    }
+    writer.join().unwrap();
+
+    // Each volatile store must reach memory, even when it is overwritten right away.
+    let mut value = 0u64;
+    let value_ptr: *mut u64 = &mut value;
+    for i in 1..=3 {
+        unsafe { ptr::write_volatile(value_ptr, i) };
+        assert_eq!(unsafe { ptr::read_volatile(value_ptr) }, i);
+    }
+
+    // Scalar pairs are loaded and stored field by field.
+    let mut pair = (1u8, 2u32);
+    unsafe { ptr::write_volatile(&mut pair, (3, 4)) };
+    assert_eq!(unsafe { ptr::read_volatile(&pair) }, (3, 4));
+}
@@ -65,13 +65,63 @@ function base_sysroot_tests() {
     $MY_RUSTC example/subslice-patterns-const-eval.rs --crate-type bin -Cpanic=abort --target "$TARGET_TRIPLE"
     $RUN_WRAPPER ./target/out/subslice-patterns-const-eval
 
+    echo "[AOT] volatile"
+    $MY_RUSTC example/volatile.rs --crate-type bin --target "$TARGET_TRIPLE"
+    $RUN_WRAPPER ./target/out/volatile
+
     echo "[AOT] track-caller-attribute"
     $MY_RUSTC example/track-caller-attribute.rs --crate-type bin -Cpanic=abort --target "$TARGET_TRIPLE"
     $RUN_WRAPPER ./target/out/track-caller-attribute
 
+    echo "[AOT] abort"
+    $MY_RUSTC example/abort.rs --crate-type bin -Cpanic=abort --target "$TARGET_TRIPLE"
+    status=0
+    $RUN_WRAPPER ./target/out/abort > target/out/abort.stdout || status=$?
+    grep -q "aborting" target/out/abort.stdout
+    if [[ "$TARGET_TRIPLE" == *"linux"* && "$status" -ne 132 ]]; then
+        echo "abort exited with status $status instead of SIGILL"
+        false
+    elif [[ "$status" -eq 0 ]]; then
+        echo "abort exited successfully"
+        false
+    fi
+
+    echo "[AOT] unreachable_unchecked"
+    $MY_RUSTC example/unreachable_unchecked.rs --crate-type bin -Cdebug-assertions --target "$TARGET_TRIPLE"
+    status=0
+    $RUN_WRAPPER ./target/out/unreachable_unchecked > target/out/unreachable_unchecked.stdout || status=$?
+    grep -q "reaching unreachable_unchecked" target/out/unreachable_unchecked.stdout
+    if grep -q "returned" target/out/unreachable_unchecked.stdout; then
+        echo "unreachable_unchecked returned"
+        false
+    elif [[ "$TARGET_TRIPLE" == *"linux"* && "$status" -ne 132 ]]; then
+        echo "unreachable_unchecked exited with status $status instead of SIGILL"
+        false
+    elif [[ "$status" -eq 0 ]]; then
+        echo "unreachable_unchecked exited successfully"
+        false
+    fi
+
     echo "[AOT] mod_bench"
     $MY_RUSTC example/mod_bench.rs --crate-type bin --target "$TARGET_TRIPLE"
     $RUN_WRAPPER ./target/out/mod_bench
+
+    if [[ "$TARGET_TRIPLE" == *"linux"* ]]; then
+        echo "[AOT] hidden_symbols"
+        $MY_RUSTC example/hidden_symbols.rs --crate-type cdylib --target "$TARGET_TRIPLE"
+        nm -D --defined-only target/out/libhidden_symbols.so | grep -q cg_clif_exported_fn
+        if nm -D --defined-only target/out/libhidden_symbols.so | grep -q cg_clif_hidden_fn; then
+            echo "cg_clif_hidden_fn is exported from libhidden_symbols.so"
+            false
+        fi
+        if ! readelf -sW target/out/libhidden_symbols.so | grep cg_clif_hidden_fn | grep -q HIDDEN
+        then
+            echo "cg_clif_hidden_fn doesn't have hidden visibility in libhidden_symbols.so"
+            false
+        fi
+    else
+        echo "[AOT] hidden_symbols (skipped)"
+    fi
 }
 
 function extended_sysroot_tests() {
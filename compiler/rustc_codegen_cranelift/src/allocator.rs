@@ -20,7 +20,12 @@ pub(crate) fn codegen(
     if any_dynamic_crate {
         false
     } else if let Some(kind) = tcx.allocator_kind(()) {
-        codegen_inner(module, unwind_context, kind);
+        let linkage = if tcx.sess.target.default_hidden_visibility {
+            Linkage::Hidden
+        } else {
+            Linkage::Export
+        };
+        codegen_inner(module, unwind_context, kind, linkage);
         true
     } else {
         false
@@ -31,6 +36,7 @@ fn codegen_inner(
     module: &mut impl Module,
     unwind_context: &mut UnwindContext,
     kind: AllocatorKind,
+    linkage: Linkage,
 ) {
     let usize_ty = module.target_config().pointer_type();
 
@@ -67,7 +73,7 @@ fn codegen_inner(
         let callee_name = kind.fn_name(method.name);
         //eprintln!("Codegen allocator shim {} -> {} ({:?} -> {:?})", caller_name, callee_name, sig.params, sig.returns);
 
-        let func_id = module.declare_function(&caller_name, Linkage::Export, &sig).unwrap();
+        let func_id = module.declare_function(&caller_name, linkage, &sig).unwrap();
 
         let callee_func_id = module.declare_function(&callee_name, Linkage::Import, &sig).unwrap();
 
@@ -108,7 +114,7 @@ fn codegen_inner(
     //eprintln!("Codegen allocator shim {} -> {} ({:?} -> {:?})", caller_name, callee_name, sig.params, sig.returns);
 
     let func_id =
-        module.declare_function("__rust_alloc_error_handler", Linkage::Export, &sig).unwrap();
+        module.declare_function("__rust_alloc_error_handler", linkage, &sig).unwrap();
 
     let callee_func_id = module.declare_function(&callee_name, Linkage::Import, &sig).unwrap();
 
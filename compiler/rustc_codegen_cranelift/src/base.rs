@@ -407,7 +407,7 @@ fn codegen_fn_content(fx: &mut FunctionCx<'_, '_, '_>) {
                 trap_unreachable(fx, "[corruption] Unwinding bb reached.");
             }
             TerminatorKind::Unreachable => {
-                trap_unreachable(fx, "[corruption] Hit unreachable code.");
+                trap_unreachable_hint(fx, "[corruption] Hit unreachable code.");
             }
             TerminatorKind::Yield { .. }
             | TerminatorKind::FalseEdge { .. }
@@ -774,17 +774,12 @@ fn is_fat_ptr<'tcx>(fx: &FunctionCx<'_, '_, 'tcx>, ty: Ty<'tcx>) -> bool {
         StatementKind::Coverage { .. } => fx.tcx.sess.fatal("-Zcoverage is unimplemented"),
         StatementKind::CopyNonOverlapping(inner) => {
             let dst = codegen_operand(fx, &inner.dst);
-            let pointee = dst
-                .layout()
-                .pointee_info_at(fx, rustc_target::abi::Size::ZERO)
-                .expect("Expected pointer");
+            let elem_ty = dst.layout().ty.builtin_deref(true).expect("Expected pointer").ty;
+            let elem_layout = fx.layout_of(elem_ty);
             let dst = dst.load_scalar(fx);
             let src = codegen_operand(fx, &inner.src).load_scalar(fx);
             let count = codegen_operand(fx, &inner.count).load_scalar(fx);
-            let elem_size: u64 = pointee.size.bytes();
-            let bytes =
-                if elem_size != 1 { fx.bcx.ins().imul_imm(count, elem_size as i64) } else { count };
-            fx.bcx.call_memcpy(fx.module.target_config(), dst, src, bytes);
+            crate::intrinsics::codegen_copy(fx, elem_layout, src, dst, count, true);
         }
     }
 }
@@ -165,3 +165,37 @@ pub(crate) fn maybe_codegen<'tcx>(
         }
     }
 }
+
+/// Rotates the 128-bit `val` left or right by `amount` bits, using 64-bit operations on its
+/// halves.
+pub(crate) fn codegen_rotate_i128(
+    fx: &mut FunctionCx<'_, '_, '_>,
+    val: Value,
+    amount: Value,
+    left: bool,
+) -> Value {
+    let amount = fx.bcx.ins().ireduce(types::I64, amount);
+    // A right rotation is a left rotation by the negated amount.
+    let amount = if left { amount } else { fx.bcx.ins().ineg(amount) };
+    let amount = fx.bcx.ins().band_imm(amount, 127);
+
+    // Rotating by 64 or more bits swaps the halves, leaving a rotation by less than 64 bits.
+    let (lsb, msb) = fx.bcx.ins().isplit(val);
+    let swap = fx.bcx.ins().icmp_imm(IntCC::UnsignedGreaterThanOrEqual, amount, 64);
+    let lo = fx.bcx.ins().select(swap, msb, lsb);
+    let hi = fx.bcx.ins().select(swap, lsb, msb);
+
+    // `x >> (64 - n)` is computed as `(x >> 1) >> (63 - n)`, so that it is zero rather than a
+    // shift by the full width when `n` is zero.
+    let n = fx.bcx.ins().band_imm(amount, 63);
+    let inv_n = fx.bcx.ins().irsub_imm(n, 63);
+    let rotate_half = |fx: &mut FunctionCx<'_, '_, '_>, upper: Value, lower: Value| {
+        let upper = fx.bcx.ins().ishl(upper, n);
+        let lower = fx.bcx.ins().ushr_imm(lower, 1);
+        let lower = fx.bcx.ins().ushr(lower, inv_n);
+        fx.bcx.ins().bor(upper, lower)
+    };
+    let new_hi = rotate_half(fx, hi, lo);
+    let new_lo = rotate_half(fx, lo, hi);
+    fx.bcx.ins().iconcat(new_lo, new_hi)
+}
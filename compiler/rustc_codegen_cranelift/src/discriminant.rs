@@ -48,11 +48,13 @@ pub(crate) fn codegen_set_discriminant<'tcx>(
             if variant_index != dataful_variant {
                 let niche = place.place_field(fx, mir::Field::new(tag_field));
                 let niche_value = variant_index.as_u32() - niche_variants.start().as_u32();
-                let niche_value = ty::ScalarInt::try_from_uint(
+                // The niche values may wrap around the end of the niche's range, so the sum
+                // needs to be truncated to the size of the niche.
+                let niche_value = niche.layout().size.truncate(
                     u128::from(niche_value).wrapping_add(niche_start),
-                    niche.layout().size,
-                )
-                .unwrap();
+                );
+                let niche_value =
+                    ty::ScalarInt::try_from_uint(niche_value, niche.layout().size).unwrap();
                 let niche_llval = CValue::const_val(fx, niche.layout(), niche_value);
                 niche.write_cvalue(fx, niche_llval);
             }
@@ -128,9 +130,16 @@ pub(crate) fn codegen_get_discriminant<'tcx>(
             // comparison (see also the comment on `let niche_discr`).
             let relative_discr = if niche_start == 0 {
                 tag
+            } else if fx.bcx.func.dfg.value_type(tag) == types::I128 {
+                // FIXME legalize `iadd_imm.i128` in Cranelift
+                let lsb = fx.bcx.ins().iconst(types::I64, niche_start as u64 as i64);
+                let msb = fx.bcx.ins().iconst(types::I64, (niche_start >> 64) as u64 as i64);
+                let niche_start = fx.bcx.ins().iconcat(lsb, msb);
+                fx.bcx.ins().isub(tag, niche_start)
             } else {
-                // FIXME handle niche_start > i64::MAX
-                fx.bcx.ins().iadd_imm(tag, -i64::try_from(niche_start).unwrap())
+                // The subtraction wraps around at the size of the tag, so negating `niche_start`
+                // modulo 2^64 works even if it doesn't fit in an `i64`.
+                fx.bcx.ins().iadd_imm(tag, (niche_start as u64).wrapping_neg() as i64)
             };
             let relative_max = niche_variants.end().as_u32() - niche_variants.start().as_u32();
             let is_niche = {
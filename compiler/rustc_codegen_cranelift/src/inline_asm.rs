@@ -21,8 +21,9 @@ pub(crate) fn codegen_inline_asm<'tcx>(
         // Black box
         return;
     } else if template[0] == InlineAsmTemplatePiece::String("int $$0x29".to_string()) {
+        // `__fastfail`, which is how `std` aborts on Windows.
         let true_ = fx.bcx.ins().iconst(types::I32, 1);
-        fx.bcx.ins().trapnz(true_, TrapCode::User(1));
+        fx.bcx.ins().trapnz(true_, crate::trap::TRAP_CODE_ABORT);
         return;
     } else if template[0] == InlineAsmTemplatePiece::String("movq %rbx, ".to_string())
         && matches!(
@@ -392,6 +392,63 @@ fn bool_to_zero_or_max_uint<'tcx>(
     );
 }
 
+/// The flags for the loads and stores of the volatile intrinsics.
+///
+/// Unlike ordinary accesses these are not marked `notrap`, and never `readonly`: a volatile
+/// access may fault or have side effects (e.g. on MMIO registers), so Cranelift has to keep
+/// every one of them, in program order, instead of merging, removing or hoisting them.
+fn volatile_mem_flags(aligned: bool) -> MemFlags {
+    let mut flags = MemFlags::new();
+    if aligned {
+        flags.set_aligned();
+    }
+    flags
+}
+
+/// Copies `count` elements of type `elem_layout` from `src` to `dst`.
+///
+/// When the size of the copy is known at compile time, `emit_small_memory_copy` is used, which
+/// emits a short sequence of loads and stores for small sizes and only falls back to a `memcpy`
+/// or `memmove` libcall for larger ones. For `non_overlapping == false` all loads are emitted
+/// before the first store, so overlapping copies remain correct.
+pub(crate) fn codegen_copy<'tcx>(
+    fx: &mut FunctionCx<'_, '_, 'tcx>,
+    elem_layout: TyAndLayout<'tcx>,
+    src: Value,
+    dst: Value,
+    count: Value,
+    non_overlapping: bool,
+) {
+    let elem_size: u64 = elem_layout.size.bytes();
+    let known_size = crate::optimize::peephole::maybe_known_iconst(&fx.bcx, count)
+        .and_then(|count| u64::try_from(count).ok())
+        .and_then(|count| count.checked_mul(elem_size));
+
+    if let Some(size) = known_size {
+        // Both pointers are required to be aligned for the element type.
+        let align = elem_layout.align.abi.bytes().min(128) as u8;
+        fx.bcx.emit_small_memory_copy(
+            fx.module.target_config(),
+            dst,
+            src,
+            size,
+            align,
+            align,
+            non_overlapping,
+            MemFlags::new(),
+        );
+        return;
+    }
+
+    let byte_amount =
+        if elem_size != 1 { fx.bcx.ins().imul_imm(count, elem_size as i64) } else { count };
+    if non_overlapping {
+        fx.bcx.call_memcpy(fx.module.target_config(), dst, src, byte_amount);
+    } else {
+        fx.bcx.call_memmove(fx.module.target_config(), dst, src, byte_amount);
+    }
+}
+
 pub(crate) fn codegen_intrinsic_call<'tcx>(
     fx: &mut FunctionCx<'_, '_, 'tcx>,
     instance: Instance<'tcx>,
@@ -487,21 +544,9 @@ pub(crate) fn codegen_intrinsic_call<'tcx>(
             fx.bcx.ins().debugtrap();
         };
         copy | copy_nonoverlapping, <elem_ty> (v src, v dst, v count) {
-            let elem_size: u64 = fx.layout_of(elem_ty).size.bytes();
             assert_eq!(args.len(), 3);
-            let byte_amount = if elem_size != 1 {
-                fx.bcx.ins().imul_imm(count, elem_size as i64)
-            } else {
-                count
-            };
-
-            if intrinsic == sym::copy_nonoverlapping {
-                // FIXME emit_small_memcpy
-                fx.bcx.call_memcpy(fx.module.target_config(), dst, src, byte_amount);
-            } else {
-                // FIXME emit_small_memmove
-                fx.bcx.call_memmove(fx.module.target_config(), dst, src, byte_amount);
-            }
+            let elem_layout = fx.layout_of(elem_ty);
+            codegen_copy(fx, elem_layout, src, dst, count, intrinsic == sym::copy_nonoverlapping);
         };
         // NOTE: the volatile variants have src and dst swapped
         volatile_copy_memory | volatile_copy_nonoverlapping_memory, <elem_ty> (v dst, v src, v count) {
@@ -632,22 +677,20 @@ pub(crate) fn codegen_intrinsic_call<'tcx>(
         };
         rotate_left, <T>(v x, v y) {
             let layout = fx.layout_of(T);
-            let y = if fx.bcx.func.dfg.value_type(y) == types::I128 {
-                fx.bcx.ins().ireduce(types::I64, y)
+            let res = if fx.bcx.func.dfg.value_type(x) == types::I128 {
+                crate::codegen_i128::codegen_rotate_i128(fx, x, y, true)
             } else {
-                y
+                fx.bcx.ins().rotl(x, y)
             };
-            let res = fx.bcx.ins().rotl(x, y);
             ret.write_cvalue(fx, CValue::by_val(res, layout));
         };
         rotate_right, <T>(v x, v y) {
             let layout = fx.layout_of(T);
-            let y = if fx.bcx.func.dfg.value_type(y) == types::I128 {
-                fx.bcx.ins().ireduce(types::I64, y)
+            let res = if fx.bcx.func.dfg.value_type(x) == types::I128 {
+                crate::codegen_i128::codegen_rotate_i128(fx, x, y, false)
             } else {
-                y
+                fx.bcx.ins().rotr(x, y)
             };
-            let res = fx.bcx.ins().rotr(x, y);
             ret.write_cvalue(fx, CValue::by_val(res, layout));
         };
 
@@ -685,7 +728,6 @@ pub(crate) fn codegen_intrinsic_call<'tcx>(
         ctlz | ctlz_nonzero, <T> (v arg) {
             // FIXME trap on `ctlz_nonzero` with zero arg.
             let res = if T == fx.tcx.types.u128 || T == fx.tcx.types.i128 {
-                // FIXME verify this algorithm is correct
                 let (lsb, msb) = fx.bcx.ins().isplit(arg);
                 let lsb_lz = fx.bcx.ins().clz(lsb);
                 let msb_lz = fx.bcx.ins().clz(msb);
@@ -702,7 +744,6 @@ pub(crate) fn codegen_intrinsic_call<'tcx>(
         cttz | cttz_nonzero, <T> (v arg) {
             // FIXME trap on `cttz_nonzero` with zero arg.
             let res = if T == fx.tcx.types.u128 || T == fx.tcx.types.i128 {
-                // FIXME verify this algorithm is correct
                 let (lsb, msb) = fx.bcx.ins().isplit(arg);
                 let lsb_tz = fx.bcx.ins().ctz(lsb);
                 let msb_tz = fx.bcx.ins().ctz(msb);
@@ -717,7 +758,15 @@ pub(crate) fn codegen_intrinsic_call<'tcx>(
             ret.write_cvalue(fx, res);
         };
         ctpop, <T> (v arg) {
-            let res = fx.bcx.ins().popcnt(arg);
+            let res = if T == fx.tcx.types.u128 || T == fx.tcx.types.i128 {
+                let (lsb, msb) = fx.bcx.ins().isplit(arg);
+                let lsb_ones = fx.bcx.ins().popcnt(lsb);
+                let msb_ones = fx.bcx.ins().popcnt(msb);
+                let res = fx.bcx.ins().iadd(lsb_ones, msb_ones);
+                fx.bcx.ins().uextend(types::I128, res)
+            } else {
+                fx.bcx.ins().popcnt(arg)
+            };
             let res = CValue::by_val(res, fx.layout_of(T));
             ret.write_cvalue(fx, res);
         };
@@ -836,18 +885,17 @@ fn swap(bcx: &mut FunctionBuilder<'_>, v: Value) -> Value {
         };
 
         volatile_load | unaligned_volatile_load, (c ptr) {
-            // Cranelift treats loads as volatile by default
-            // FIXME correctly handle unaligned_volatile_load
             let inner_layout =
                 fx.layout_of(ptr.layout().ty.builtin_deref(true).unwrap().ty);
-            let val = CValue::by_ref(Pointer::new(ptr.load_scalar(fx)), inner_layout);
+            let flags = volatile_mem_flags(intrinsic == sym::volatile_load);
+            let ptr = Pointer::new(ptr.load_scalar(fx));
+            let val = CValue::load_volatile(fx, ptr, inner_layout, flags);
             ret.write_cvalue(fx, val);
         };
         volatile_store | unaligned_volatile_store, (v ptr, c val) {
-            // Cranelift treats stores as volatile by default
-            // FIXME correctly handle unaligned_volatile_store
+            let flags = volatile_mem_flags(intrinsic == sym::volatile_store);
             let dest = CPlace::for_ptr(Pointer::new(ptr), val.layout());
-            dest.write_cvalue(fx, val);
+            dest.write_cvalue_volatile(fx, val, flags);
         };
 
         pref_align_of | min_align_of | needs_drop | type_id | type_name | variant_count, () {
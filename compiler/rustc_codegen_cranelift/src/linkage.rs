@@ -1,3 +1,4 @@
+use rustc_middle::middle::exported_symbols::SymbolExportLevel;
 use rustc_middle::mir::mono::{Linkage as RLinkage, MonoItem, Visibility};
 
 use crate::prelude::*;
@@ -11,9 +12,12 @@ pub(crate) fn get_clif_linkage(
     match (linkage, visibility) {
         (RLinkage::External, Visibility::Default) if is_compiler_builtins => Linkage::Hidden,
         (RLinkage::External, Visibility::Default) => Linkage::Export,
-        (RLinkage::Internal, Visibility::Default) => Linkage::Local,
+        // FIXME cranelift-module doesn't support protected visibility, so export the symbol as
+        // that is the closest match.
+        (RLinkage::External, Visibility::Protected) => Linkage::Export,
+        (RLinkage::Internal, _) => Linkage::Local,
         (RLinkage::External, Visibility::Hidden) => Linkage::Hidden,
-        (RLinkage::WeakAny, Visibility::Default) => Linkage::Preemptible,
+        (RLinkage::WeakAny, Visibility::Default | Visibility::Protected) => Linkage::Preemptible,
         _ => panic!("{:?} = {:?} {:?}", mono_item, linkage, visibility),
     }
 }
@@ -29,7 +33,16 @@ pub(crate) fn get_static_linkage(tcx: TyCtxt<'_>, def_id: DefId) -> Linkage {
             _ => panic!("{:?}", linkage),
         }
     } else if tcx.is_reachable_non_generic(def_id) {
-        Linkage::Export
+        // Mirror the visibility the partitioner gives to functions: on targets that hide
+        // symbols by default, only statics with C export level stay visible.
+        if tcx.sess.target.default_hidden_visibility
+            && tcx.reachable_non_generics(def_id.krate).get(&def_id)
+                != Some(&SymbolExportLevel::C)
+        {
+            Linkage::Hidden
+        } else {
+            Linkage::Export
+        }
     } else {
         Linkage::Hidden
     }
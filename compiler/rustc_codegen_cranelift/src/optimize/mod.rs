@@ -1,4 +1,8 @@
 //! Various optimizations specific to cg_clif
+//!
+//! Generic cleanups don't belong here: unreachable blocks and unused pure instructions are
+//! already removed by Cranelift's `eliminate_unreachable_code` and `dce` passes, which
+//! `codegen_fn` runs before calling [`optimize_function`].
 
 use cranelift_codegen::isa::TargetIsa;
 
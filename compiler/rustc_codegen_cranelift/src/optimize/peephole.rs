@@ -104,3 +104,16 @@ pub(crate) fn maybe_known_branch_taken(
         _ => None,
     }
 }
+
+/// Returns the value of the given integer if it was produced by an `iconst` instruction, or `None`
+/// if it isn't statically known.
+pub(crate) fn maybe_known_iconst(bcx: &FunctionBuilder<'_>, arg: Value) -> Option<i64> {
+    if let ValueDef::Result(arg_inst, 0) = bcx.func.dfg.value_def(arg) {
+        match bcx.func.dfg[arg_inst] {
+            InstructionData::UnaryImm { opcode: Opcode::Iconst, imm } => Some(imm.bits()),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
@@ -25,10 +25,17 @@ fn codegen_print(fx: &mut FunctionCx<'_, '_, '_>, msg: &str) {
     fx.bcx.ins().call(puts, &[msg_ptr]);
 }
 
-/// Trap code: user1
+/// Trap code used for Rust aborts, e.g. `intrinsics::abort()`.
+///
+/// The trap itself is always lowered to the target's trap instruction (`ud2` on x86), so an
+/// abort shows up as `SIGILL` rather than as a random undefined instruction. The code only
+/// distinguishes aborts from other traps in the trap metadata.
+pub(crate) const TRAP_CODE_ABORT: TrapCode = TrapCode::User(1);
+
+/// Trap code: [`TRAP_CODE_ABORT`]
 pub(crate) fn trap_abort(fx: &mut FunctionCx<'_, '_, '_>, msg: impl AsRef<str>) {
     codegen_print(fx, msg.as_ref());
-    fx.bcx.ins().trap(TrapCode::User(1));
+    fx.bcx.ins().trap(TRAP_CODE_ABORT);
 }
 
 /// Use this for example when a function call should never return. This will fill the current block,
@@ -40,6 +47,20 @@ pub(crate) fn trap_unreachable(fx: &mut FunctionCx<'_, '_, '_>, msg: impl AsRef<
     fx.bcx.ins().trap(TrapCode::UnreachableCodeReached);
 }
 
+/// Use this for `TerminatorKind::Unreachable`, which `intrinsics::unreachable()` and thus
+/// `hint::unreachable_unchecked()` are lowered to. Reaching it is UB, so with debug assertions
+/// enabled a message is printed before trapping to make the misuse easy to spot. Otherwise only
+/// the trap itself is emitted, which ends the block without any extra code. This will fill the
+/// current block, so you can **not** add instructions to it afterwards.
+///
+/// Trap code: user65535
+pub(crate) fn trap_unreachable_hint(fx: &mut FunctionCx<'_, '_, '_>, msg: impl AsRef<str>) {
+    if fx.tcx.sess.opts.debug_assertions {
+        codegen_print(fx, msg.as_ref());
+    }
+    fx.bcx.ins().trap(TrapCode::UnreachableCodeReached);
+}
+
 /// Like `trap_unreachable` but returns a fake value of the specified type.
 ///
 /// Trap code: user65535
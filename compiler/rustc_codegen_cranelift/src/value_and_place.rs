@@ -168,6 +168,35 @@ pub(crate) fn load_scalar_pair(self, fx: &mut FunctionCx<'_, '_, 'tcx>) -> (Valu
         }
     }
 
+    /// Load the value of type `layout` behind `ptr` as a volatile access.
+    ///
+    /// Values with a scalar or scalar pair abi are loaded right away using `flags`, so that
+    /// exactly one load per component happens at this point, no matter how often the
+    /// result is used later on.
+    pub(crate) fn load_volatile(
+        fx: &mut FunctionCx<'_, '_, 'tcx>,
+        ptr: Pointer,
+        layout: TyAndLayout<'tcx>,
+        flags: MemFlags,
+    ) -> CValue<'tcx> {
+        match layout.abi {
+            Abi::Scalar(ref scalar) => {
+                let clif_ty = scalar_to_clif_type(fx.tcx, scalar.clone());
+                CValue::by_val(ptr.load(fx, clif_ty, flags), layout)
+            }
+            Abi::ScalarPair(ref a_scalar, ref b_scalar) => {
+                let b_offset = scalar_pair_calculate_b_offset(fx.tcx, a_scalar, b_scalar);
+                let clif_ty1 = scalar_to_clif_type(fx.tcx, a_scalar.clone());
+                let clif_ty2 = scalar_to_clif_type(fx.tcx, b_scalar.clone());
+                let val1 = ptr.load(fx, clif_ty1, flags);
+                let val2 = ptr.offset(fx, b_offset).load(fx, clif_ty2, flags);
+                CValue::by_val_pair(val1, val2, layout)
+            }
+            // FIXME make the copy volatile; it currently goes through `emit_small_memory_copy`
+            _ => CValue::by_ref(ptr, layout),
+        }
+    }
+
     pub(crate) fn value_field(
         self,
         fx: &mut FunctionCx<'_, '_, 'tcx>,
@@ -409,6 +438,36 @@ pub(crate) fn write_cvalue_transmute(
         self.write_cvalue_maybe_transmute(fx, from, "write_cvalue_transmute");
     }
 
+    /// Store `from` to the memory this place points to as a volatile access, using `flags`
+    /// for every store of a scalar or scalar pair component.
+    pub(crate) fn write_cvalue_volatile(
+        self,
+        fx: &mut FunctionCx<'_, '_, 'tcx>,
+        from: CValue<'tcx>,
+        flags: MemFlags,
+    ) {
+        assert_assignable(fx, from.layout().ty, self.layout().ty);
+
+        let to_ptr = match self.inner {
+            CPlaceInner::Addr(ptr, None) => ptr,
+            _ => bug!("volatile store to a place that isn't a sized pointer: {:?}", self),
+        };
+        match from.layout().abi {
+            Abi::Scalar(_) => {
+                let val = from.load_scalar(fx);
+                to_ptr.store(fx, val, flags);
+            }
+            Abi::ScalarPair(ref a_scalar, ref b_scalar) => {
+                let (value, extra) = from.load_scalar_pair(fx);
+                let b_offset = scalar_pair_calculate_b_offset(fx.tcx, a_scalar, b_scalar);
+                to_ptr.store(fx, value, flags);
+                to_ptr.offset(fx, b_offset).store(fx, extra, flags);
+            }
+            // FIXME make the copy volatile; it currently goes through `emit_small_memory_copy`
+            _ => self.write_cvalue(fx, from),
+        }
+    }
+
     fn write_cvalue_maybe_transmute(
         self,
         fx: &mut FunctionCx<'_, '_, 'tcx>,
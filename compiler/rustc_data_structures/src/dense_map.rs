@@ -0,0 +1,150 @@
+use std::fmt;
+use std::iter::FromIterator;
+
+use rustc_index::vec::{Idx, IndexVec};
+
+/// A map from small, densely allocated index keys to values, stored as a vector with one
+/// optional slot per key.
+///
+/// Lookups are a bounds check and an index rather than a hash, and the vector grows on
+/// demand to fit the largest key inserted so far. This is a good fit for keys that are not
+/// assigned contiguously (so an `IndexVec` would need a filler value) but whose range is still
+/// small; for large, sparse key ranges a hash map is the better choice.
+#[derive(Clone, PartialEq, Eq)]
+pub struct DenseMap<K: Idx, V> {
+    slots: IndexVec<K, Option<V>>,
+}
+
+impl<K: Idx, V> DenseMap<K, V> {
+    #[inline]
+    pub fn new() -> Self {
+        DenseMap { slots: IndexVec::new() }
+    }
+
+    /// Sets the value for `k`, growing the map if needed, and returns the old value.
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        self.slot_mut(k).replace(v)
+    }
+
+    /// Removes the value for `k`, leaving an empty slot behind, and returns it.
+    pub fn remove(&mut self, k: K) -> Option<V> {
+        self.slots.get_mut(k).and_then(Option::take)
+    }
+
+    #[inline]
+    pub fn get(&self, k: K) -> Option<&V> {
+        self.slots.get(k).and_then(Option::as_ref)
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, k: K) -> Option<&mut V> {
+        self.slots.get_mut(k).and_then(Option::as_mut)
+    }
+
+    #[inline]
+    pub fn contains_key(&self, k: K) -> bool {
+        self.get(k).is_some()
+    }
+
+    /// Gets the entry for `k`, growing the map if needed, for in-place manipulation.
+    pub fn entry(&mut self, k: K) -> Entry<'_, V> {
+        Entry { slot: self.slot_mut(k) }
+    }
+
+    /// Iterates over the occupied slots in increasing key order.
+    pub fn iter(&self) -> impl Iterator<Item = (K, &V)> + '_ {
+        self.slots.iter_enumerated().filter_map(|(k, v)| Some((k, v.as_ref()?)))
+    }
+
+    /// Iterates mutably over the occupied slots in increasing key order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (K, &mut V)> + '_ {
+        self.slots.iter_enumerated_mut().filter_map(|(k, v)| Some((k, v.as_mut()?)))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = K> + '_ {
+        self.iter().map(|(k, _)| k)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.slots.iter().filter_map(Option::as_ref)
+    }
+
+    /// Returns `true` if the map contains no values. This is linear in the largest key.
+    pub fn is_empty(&self) -> bool {
+        self.values().next().is_none()
+    }
+
+    fn slot_mut(&mut self, k: K) -> &mut Option<V> {
+        self.slots.ensure_contains_elem(k, || None);
+        &mut self.slots[k]
+    }
+}
+
+impl<K: Idx, V> Default for DenseMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Idx, V: fmt::Debug> fmt::Debug for DenseMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Idx, V> FromIterator<(K, V)> for DenseMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = DenseMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Idx, V> Extend<(K, V)> for DenseMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+/// A slot of a [`DenseMap`], which may or may not hold a value.
+pub struct Entry<'a, V> {
+    slot: &'a mut Option<V>,
+}
+
+impl<'a, V> Entry<'a, V> {
+    /// Ensures a value is in the entry by inserting `default` if empty, and returns a mutable
+    /// reference to the value in the entry.
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.slot.get_or_insert(default)
+    }
+
+    /// Like `or_insert`, but only computes the default value if the entry is empty.
+    #[inline]
+    pub fn or_insert_with(self, default: impl FnOnce() -> V) -> &'a mut V {
+        self.slot.get_or_insert_with(default)
+    }
+
+    #[inline]
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Applies `f` to the value in the entry, if any.
+    #[inline]
+    pub fn and_modify(self, f: impl FnOnce(&mut V)) -> Self {
+        if let Some(v) = self.slot.as_mut() {
+            f(v);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests;
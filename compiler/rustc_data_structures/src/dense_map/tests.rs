@@ -0,0 +1,71 @@
+use super::*;
+
+#[test]
+fn test_insert_out_of_order() {
+    let mut map = DenseMap::new();
+    assert_eq!(map.insert(7usize, "seven"), None);
+    assert_eq!(map.insert(2, "two"), None);
+    assert_eq!(map.insert(4, "four"), None);
+    assert_eq!(map.insert(2, "deux"), Some("two"));
+
+    assert_eq!(map.get(2), Some(&"deux"));
+    assert_eq!(map.get(4), Some(&"four"));
+    assert_eq!(map.get(7), Some(&"seven"));
+    assert_eq!(map.get(3), None);
+    assert_eq!(map.get(100), None);
+    assert_eq!(map.iter().collect::<Vec<_>>(), vec![(2, &"deux"), (4, &"four"), (7, &"seven")]);
+}
+
+#[test]
+fn test_remove_leaves_holes() {
+    let mut map: DenseMap<usize, u32> = (0..5).map(|i| (i, i as u32 * 10)).collect();
+    assert_eq!(map.remove(1), Some(10));
+    assert_eq!(map.remove(1), None);
+    assert_eq!(map.remove(4), Some(40));
+    assert_eq!(map.remove(42), None);
+
+    assert!(!map.contains_key(1));
+    assert!(map.contains_key(2));
+    assert_eq!(map.keys().collect::<Vec<_>>(), vec![0, 2, 3]);
+
+    assert_eq!(map.insert(1, 11), None);
+    assert_eq!(map.keys().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn test_iter_skips_empty_slots() {
+    let mut map = DenseMap::new();
+    assert!(map.is_empty());
+    assert_eq!(map.iter().next(), None);
+
+    map.insert(5usize, 'a');
+    map.insert(1, 'b');
+    map.remove(5);
+    assert!(!map.is_empty());
+    assert_eq!(map.iter().collect::<Vec<_>>(), vec![(1, &'b')]);
+    assert_eq!(map.values().collect::<Vec<_>>(), vec![&'b']);
+
+    for (_, v) in map.iter_mut() {
+        *v = 'c';
+    }
+    assert_eq!(map.get(1), Some(&'c'));
+
+    map.remove(1);
+    assert!(map.is_empty());
+    assert_eq!(format!("{:?}", map), "{}");
+}
+
+#[test]
+fn test_entry() {
+    let mut map: DenseMap<usize, Vec<u32>> = DenseMap::new();
+    map.entry(3).or_default().push(1);
+    map.entry(3).or_default().push(2);
+    map.entry(0).or_insert_with(|| vec![0]);
+    map.entry(0).and_modify(|v| v.push(9)).or_insert(vec![]);
+    map.entry(6).and_modify(|v| v.push(9));
+
+    assert_eq!(map.get(3), Some(&vec![1, 2]));
+    assert_eq!(map.get(0), Some(&vec![0, 9]));
+    assert_eq!(map.get(6), None);
+    assert_eq!(map.keys().collect::<Vec<_>>(), vec![0, 3]);
+}
@@ -3,6 +3,9 @@
 use std::convert::TryInto;
 use std::hash::{Hash, Hasher};
 
+#[cfg(test)]
+mod tests;
+
 #[derive(Eq, PartialEq, Ord, PartialOrd, Debug, Clone, Copy)]
 #[repr(C)]
 pub struct Fingerprint(u64, u64);
@@ -45,8 +48,14 @@ pub fn combine(self, other: Fingerprint) -> Fingerprint {
         )
     }
 
-    // Combines two hashes in an order independent way. Make sure this is what
-    // you want.
+    /// Combines two hashes in an order independent way, by adding them as
+    /// 128-bit integers with wrapping.
+    ///
+    /// This is meant for hashing unordered collections such as sets, where
+    /// the fingerprints of the elements can be folded together in whatever
+    /// order the collection happens to yield them. Make sure this is what you
+    /// want: unlike [`Fingerprint::combine`], swapping the operands (or the
+    /// elements of a collection) does not change the result.
     #[inline]
     pub fn combine_commutative(self, other: Fingerprint) -> Fingerprint {
         let a = u128::from(self.1) << 64 | u128::from(self.0);
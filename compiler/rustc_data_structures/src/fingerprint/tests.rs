@@ -0,0 +1,26 @@
+use super::*;
+
+#[test]
+fn combine_commutative_is_order_independent() {
+    let a = Fingerprint::new(0x0123_4567_89ab_cdef, 0xfedc_ba98_7654_3210);
+    let b = Fingerprint::new(u64::MAX, 1);
+    let c = Fingerprint::new(42, u64::MAX);
+
+    assert_eq!(a.combine_commutative(b), b.combine_commutative(a));
+
+    let abc = a.combine_commutative(b).combine_commutative(c);
+    let cab = c.combine_commutative(a).combine_commutative(b);
+    let bca = b.combine_commutative(c).combine_commutative(a);
+    assert_eq!(abc, cab);
+    assert_eq!(abc, bca);
+}
+
+#[test]
+fn combine_commutative_differs_from_combine() {
+    let a = Fingerprint::new(1, 2);
+    let b = Fingerprint::new(3, 4);
+
+    assert_ne!(a.combine(b), b.combine(a));
+    assert_ne!(a.combine_commutative(b), a.combine(b));
+    assert_ne!(a.combine_commutative(b), b.combine(a));
+}
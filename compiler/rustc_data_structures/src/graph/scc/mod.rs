@@ -79,6 +79,46 @@ pub fn reverse(&self) -> VecGraph<S> {
                 .collect(),
         )
     }
+
+    /// Returns, for each SCC, the nodes that belong to it, in increasing order.
+    pub fn members(&self) -> IndexVec<S, Vec<N>> {
+        let mut members = IndexVec::from_elem_n(Vec::new(), self.num_sccs());
+        for (node, &scc) in self.scc_indices.iter_enumerated() {
+            members[scc].push(node);
+        }
+        members
+    }
+
+    /// Adds the edge `source -> target` to the underlying graph and updates the
+    /// SCCs to be the same as if the edge had been present from the start.
+    ///
+    /// Only the SCC graph is walked, not the original graph: if the new edge
+    /// closes a cycle, all the SCCs on that cycle are merged into one. As the
+    /// SCCs are kept in dependency order, this can renumber them, so SCC indices
+    /// obtained before the call must not be used afterwards.
+    ///
+    /// Returns `false` if the edge didn't change anything, i.e. `source` and
+    /// `target` already were in the same SCC or in adjacent ones.
+    pub fn add_edge(&mut self, source: N, target: N) -> bool {
+        let (source, target) = (self.scc(source), self.scc(target));
+        if source == target || self.successors(source).contains(&target) {
+            return false;
+        }
+
+        let mut edges: Vec<(S, S)> = self
+            .all_sccs()
+            .flat_map(|scc| self.successors(scc).iter().map(move |&succ| (scc, succ)))
+            .collect();
+        edges.push((source, target));
+        let condensation = VecGraph::new(self.num_sccs(), edges);
+        let Sccs { scc_indices: merged, scc_data } = Sccs::<S, S>::new(&condensation);
+
+        for scc in self.scc_indices.iter_mut() {
+            *scc = merged[*scc];
+        }
+        self.scc_data = scc_data;
+        true
+    }
 }
 
 impl<N: Idx, S: Idx> DirectedGraph for Sccs<N, S> {
@@ -168,6 +168,100 @@ fn test_deep_linear() {
     assert_eq!(sccs.scc(NR_NODES - 1), 0);
 }
 
+/// Checks that `sccs` describes the same SCCs, with the same successors, as
+/// computing them from scratch for `graph` does. SCC indices may differ.
+fn assert_same_sccs(sccs: &Sccs<usize, usize>, graph: &TestGraph) {
+    let fresh: Sccs<_, usize> = Sccs::new(graph);
+    assert_eq!(sccs.num_sccs(), fresh.num_sccs());
+
+    let mut members = sccs.members().raw;
+    let mut fresh_members = fresh.members().raw;
+    members.sort();
+    fresh_members.sort();
+    assert_eq!(members, fresh_members);
+
+    for node in 0..graph.num_nodes() {
+        // Compare successors through a representative node of each SCC.
+        let rep = |s: &Sccs<usize, usize>, scc: usize| s.members()[scc][0];
+        let mut succs: Vec<_> =
+            sccs.successors(sccs.scc(node)).iter().map(|&scc| rep(sccs, scc)).collect();
+        let mut fresh_succs: Vec<_> =
+            fresh.successors(fresh.scc(node)).iter().map(|&scc| rep(&fresh, scc)).collect();
+        succs.sort();
+        fresh_succs.sort();
+        assert_eq!(succs, fresh_succs, "successors of the SCC of {}", node);
+    }
+}
+
+#[test]
+fn members() {
+    let graph = TestGraph::new(0, &[(0, 1), (1, 2), (2, 1), (3, 2)]);
+    let sccs: Sccs<_, usize> = Sccs::new(&graph);
+    let members = sccs.members();
+    assert_eq!(members.len(), 3);
+    assert_eq!(members[sccs.scc(0)], [0]);
+    assert_eq!(members[sccs.scc(1)], [1, 2]);
+    assert_eq!(members[sccs.scc(3)], [3]);
+}
+
+#[test]
+fn add_edge_merges_sccs() {
+    /*
+        0
+        |
+        v
+    +-> 1    3
+    |   |    |
+    |   v    |
+    +-- 2 <--+
+         */
+    let mut edges = vec![(0, 1), (1, 2), (2, 1), (3, 2)];
+    let mut sccs: Sccs<_, usize> = Sccs::new(&TestGraph::new(0, &edges));
+    assert_eq!(sccs.num_sccs(), 3);
+
+    // Edges inside an SCC or duplicating an existing SCC edge change nothing.
+    assert!(!sccs.add_edge(2, 1));
+    assert!(!sccs.add_edge(3, 1));
+    assert_eq!(sccs.num_sccs(), 3);
+
+    // `2 -> 0` closes the cycle `0 -> 1 -> 2 -> 0`.
+    assert!(sccs.add_edge(2, 0));
+    edges.push((2, 0));
+    assert_eq!(sccs.num_sccs(), 2);
+    assert_eq!(sccs.scc(0), sccs.scc(1));
+    assert_eq!(sccs.scc(0), sccs.scc(2));
+    assert_ne!(sccs.scc(0), sccs.scc(3));
+    assert_eq!(sccs.successors(sccs.scc(3)), &[sccs.scc(0)]);
+    assert_eq!(sccs.successors(sccs.scc(0)), &[]);
+    assert_same_sccs(&sccs, &TestGraph::new(0, &edges));
+
+    // And `0 -> 3` merges everything.
+    assert!(sccs.add_edge(0, 3));
+    edges.push((0, 3));
+    assert_eq!(sccs.num_sccs(), 1);
+    assert_same_sccs(&sccs, &TestGraph::new(0, &edges));
+}
+
+#[test]
+fn add_edge_without_cycle() {
+    // Two chains, `0 -> 1 -> 2` and `3 -> 4`, joined one edge at a time.
+    let mut edges = vec![(0, 1), (1, 2), (3, 4)];
+    let mut sccs: Sccs<_, usize> = Sccs::new(&TestGraph::new(0, &edges));
+    for &(source, target) in &[(2, 3), (0, 4), (4, 1)] {
+        assert!(sccs.add_edge(source, target));
+        edges.push((source, target));
+        assert_same_sccs(&sccs, &TestGraph::new(0, &edges));
+
+        // Successors still come before the SCCs that point to them.
+        for scc in sccs.all_sccs() {
+            assert!(sccs.successors(scc).iter().all(|&succ| succ < scc));
+        }
+    }
+    // `4 -> 1` closed the cycle `1 -> 2 -> 3 -> 4 -> 1`.
+    assert_eq!(sccs.num_sccs(), 2);
+    assert_eq!(sccs.members()[sccs.scc(1)], [1, 2, 3, 4]);
+}
+
 #[bench]
 fn bench_sccc(b: &mut test::Bencher) {
     // Like `test_three_sccs` but each state is replaced by a group of
@@ -66,6 +66,7 @@ macro_rules! unlikely {
 pub mod base_n;
 pub mod binary_search_util;
 pub mod captures;
+pub mod dense_map;
 pub mod flock;
 pub mod functor;
 pub mod fx;
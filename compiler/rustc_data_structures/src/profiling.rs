@@ -98,6 +98,9 @@
 use measureme::{EventIdBuilder, Profiler, SerializableString, StringId};
 use parking_lot::RwLock;
 
+#[cfg(test)]
+mod tests;
+
 bitflags::bitflags! {
     struct EventFilter: u32 {
         const GENERIC_ACTIVITIES  = 1 << 0;
@@ -138,6 +141,12 @@ struct EventFilter: u32 {
     ("incr-result-hashing", EventFilter::INCR_RESULT_HASHING),
 ];
 
+/// Formats a key/value argument of a generic activity the way it is stored in
+/// the profiling data.
+fn format_event_arg(key: &str, value: &str) -> String {
+    format!("{}={}", key, value)
+}
+
 /// Something that uniquely identifies a query invocation.
 pub struct QueryInvocationId(pub u32);
 
@@ -307,6 +316,35 @@ pub fn generic_activity_with_args(
         })
     }
 
+    /// Start profiling a generic activity annotated with `key=value` arguments.
+    /// Profiling continues until the TimingGuard returned from this call is dropped.
+    ///
+    /// The arguments are only recorded if the `function-args` event filter is
+    /// enabled; nothing at all is recorded if self-profiling is disabled.
+    #[inline(always)]
+    pub fn generic_activity_with_named_args(
+        &self,
+        event_label: &'static str,
+        event_args: &[(&'static str, String)],
+    ) -> TimingGuard<'_> {
+        self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
+            let builder = EventIdBuilder::new(&profiler.profiler);
+            let event_label = profiler.get_or_alloc_cached_string(event_label);
+            let event_id = if profiler.event_filter_mask.contains(EventFilter::FUNCTION_ARGS) {
+                let event_args: Vec<_> = event_args
+                    .iter()
+                    .map(|(key, value)| {
+                        profiler.get_or_alloc_cached_string(format_event_arg(key, value))
+                    })
+                    .collect();
+                builder.from_label_and_args(event_label, &event_args)
+            } else {
+                builder.from_label(event_label)
+            };
+            TimingGuard::start(profiler, profiler.generic_activity_event_kind, event_id)
+        })
+    }
+
     /// Start profiling a query provider. Profiling continues until the
     /// TimingGuard returned from this call is dropped.
     #[inline(always)]
@@ -0,0 +1,111 @@
+use super::*;
+use std::convert::TryInto;
+
+fn profiler_ref(dir: &Path, event_filters: &[&str]) -> SelfProfilerRef {
+    let event_filters = Some(event_filters.iter().map(|s| s.to_string()).collect());
+    let profiler = SelfProfiler::new(dir, Some("test"), &event_filters).unwrap();
+    SelfProfilerRef::new(Some(Arc::new(profiler)), false, false)
+}
+
+fn cached_string_id(profiler: &SelfProfilerRef, s: &str) -> Option<u32> {
+    let profiler = profiler.profiler.as_ref().unwrap();
+    profiler.string_cache.read().get(s).map(|id| id.as_u32())
+}
+
+fn generic_activity_event_kind(profiler: &SelfProfilerRef) -> u32 {
+    profiler.profiler.as_ref().unwrap().generic_activity_event_kind.as_u32()
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes(bytes[..4].try_into().unwrap())
+}
+
+/// Drops the profiler, which flushes its data to disk, and returns the
+/// `(event_kind, event_id)` pair of every event in the recorded event stream.
+fn recorded_events(profiler: SelfProfilerRef, dir: &Path) -> Vec<(u32, u32)> {
+    drop(profiler);
+
+    let mut paths = fs::read_dir(dir).unwrap().map(|entry| entry.unwrap().path());
+    let path = paths.find(|path| path.extension().map_or(false, |ext| ext == "mm_profdata"));
+    let data = fs::read(path.unwrap()).unwrap();
+
+    // The file starts with an 8 byte header, followed by pages made up of a
+    // one byte tag, a little endian u32 length and the page contents.
+    assert_eq!(&data[..4], b"MMPD");
+    let mut events = Vec::new();
+    let mut pos = 8;
+    while pos < data.len() {
+        let tag = data[pos];
+        let len = read_u32(&data[pos + 1..]) as usize;
+        if tag == 0 {
+            events.extend_from_slice(&data[pos + 5..pos + 5 + len]);
+        }
+        pos += 5 + len;
+    }
+
+    // The event stream has its own 8 byte header, followed by fixed size
+    // 24 byte events that start with the event kind and the event id.
+    assert_eq!(&events[..4], b"MMES");
+    events[8..].chunks(24).map(|event| (read_u32(event), read_u32(&event[4..]))).collect()
+}
+
+#[test]
+fn generic_activity_with_named_args_disabled() {
+    let profiler = SelfProfilerRef::new(None, false, false);
+    let guard =
+        profiler.generic_activity_with_named_args("activity", &[("key", "value".to_string())]);
+    assert!(guard.0.is_none());
+}
+
+#[test]
+fn generic_activity_with_named_args_records_args() {
+    let dir = tempfile::tempdir().unwrap();
+    let profiler = profiler_ref(dir.path(), &["default", "args"]);
+
+    drop(profiler.generic_activity_with_named_args(
+        "activity",
+        &[("cgu_name", "foo.1".to_string()), ("size", "42".to_string())],
+    ));
+    drop(profiler.generic_activity_with_named_args(
+        "activity",
+        &[("cgu_name", "foo.2".to_string()), ("size", "42".to_string())],
+    ));
+
+    let event_kind = generic_activity_event_kind(&profiler);
+    let label = cached_string_id(&profiler, "activity").unwrap();
+    let cgu_name = cached_string_id(&profiler, "cgu_name=foo.1").unwrap();
+    let size = cached_string_id(&profiler, "size=42").unwrap();
+
+    let events: Vec<_> = recorded_events(profiler, dir.path())
+        .into_iter()
+        .filter(|&(kind, _)| kind == event_kind)
+        .map(|(_, id)| id)
+        .collect();
+
+    // Each activity is recorded under its own id, which combines the label
+    // with the arguments rather than just naming one of them.
+    assert_eq!(events.len(), 2);
+    assert_ne!(events[0], events[1]);
+    for id in events {
+        assert!(![label, cgu_name, size].contains(&id));
+    }
+}
+
+#[test]
+fn generic_activity_with_named_args_without_args_filter() {
+    let dir = tempfile::tempdir().unwrap();
+    let profiler = profiler_ref(dir.path(), &["default"]);
+
+    drop(profiler.generic_activity_with_named_args("activity", &[("key", "value".to_string())]));
+
+    let event_kind = generic_activity_event_kind(&profiler);
+    let label = cached_string_id(&profiler, "activity").unwrap();
+    assert_eq!(cached_string_id(&profiler, "key=value"), None);
+
+    let events: Vec<_> = recorded_events(profiler, dir.path())
+        .into_iter()
+        .filter(|&(kind, _)| kind == event_kind)
+        .map(|(_, id)| id)
+        .collect();
+    assert_eq!(events, vec![label]);
+}
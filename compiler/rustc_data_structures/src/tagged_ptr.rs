@@ -11,7 +11,9 @@
 //! Many common pointer types already implement the `Pointer` trait.
 //!
 //! The tag must implement the `Tag` trait. We assert that the tag and `Pointer`
-//! are compatible at compile time.
+//! are compatible at compile time: a tag may use at most `MAX_TAG_BITS` bits,
+//! and the pointee must be aligned enough to leave that many low bits of the
+//! pointer free, i.e. a 3-bit tag requires an alignment of at least 8.
 
 use std::mem::ManuallyDrop;
 use std::ops::Deref;
@@ -24,6 +26,15 @@
 pub use copy::CopyTaggedPtr;
 pub use drop::TaggedPtr;
 
+#[cfg(test)]
+mod tests;
+
+/// The maximum number of bits a `Tag` may occupy.
+///
+/// Tags of this width can only be packed into pointers whose pointee is
+/// aligned to at least `1 << MAX_TAG_BITS` bytes.
+pub const MAX_TAG_BITS: usize = 3;
+
 /// This describes the pointer type encapsulated by TaggedPtr.
 ///
 /// # Safety
@@ -74,7 +85,7 @@ pub unsafe trait Pointer: Deref {
 ///
 /// # Safety
 ///
-/// The BITS constant must be correct.
+/// The BITS constant must be correct, and must not exceed `MAX_TAG_BITS`.
 ///
 /// No more than `BITS` least significant bits may be set in the returned usize.
 pub unsafe trait Tag: Copy {
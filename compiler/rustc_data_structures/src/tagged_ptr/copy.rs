@@ -1,4 +1,4 @@
-use super::{Pointer, Tag};
+use super::{Pointer, Tag, MAX_TAG_BITS};
 use crate::stable_hasher::{HashStable, StableHasher};
 use std::fmt;
 use std::marker::PhantomData;
@@ -49,7 +49,9 @@ impl<P, T, const COMPARE_PACKED: bool> CopyTaggedPtr<P, T, COMPARE_PACKED>
     T: Tag,
 {
     const TAG_BIT_SHIFT: usize = usize::BITS as usize - T::BITS;
+    const TAG_MASK: usize = (1 << T::BITS) - 1;
     const ASSERTION: () = {
+        assert!(T::BITS <= MAX_TAG_BITS);
         assert!(T::BITS <= P::BITS);
         // Used for the transmute_copy's below
         assert!(std::mem::size_of::<&P::Target>() == std::mem::size_of::<usize>());
@@ -58,18 +60,30 @@ impl<P, T, const COMPARE_PACKED: bool> CopyTaggedPtr<P, T, COMPARE_PACKED>
     pub fn new(pointer: P, tag: T) -> Self {
         // Trigger assert!
         let () = Self::ASSERTION;
-        let packed_tag = tag.into_usize() << Self::TAG_BIT_SHIFT;
+        let packed_tag = Self::tag_to_usize(tag) << Self::TAG_BIT_SHIFT;
+
+        let pointer = P::into_usize(pointer);
+        debug_assert_eq!(
+            pointer & Self::TAG_MASK,
+            0,
+            "pointer is not aligned enough to hold a {}-bit tag",
+            T::BITS
+        );
 
         Self {
             // SAFETY: We know that the pointer is non-null, as it must be
             // dereferenceable per `Pointer` safety contract.
-            packed: unsafe {
-                NonZeroUsize::new_unchecked((P::into_usize(pointer) >> T::BITS) | packed_tag)
-            },
+            packed: unsafe { NonZeroUsize::new_unchecked((pointer >> T::BITS) | packed_tag) },
             data: PhantomData,
         }
     }
 
+    fn tag_to_usize(tag: T) -> usize {
+        let tag = T::into_usize(tag);
+        debug_assert_eq!(tag & !Self::TAG_MASK, 0, "tag does not fit into {} bits", T::BITS);
+        tag
+    }
+
     pub(super) fn pointer_raw(&self) -> usize {
         self.packed.get() << T::BITS
     }
@@ -99,9 +113,8 @@ pub fn tag(&self) -> T {
     }
     pub fn set_tag(&mut self, tag: T) {
         let mut packed = self.packed.get();
-        let new_tag = T::into_usize(tag) << Self::TAG_BIT_SHIFT;
-        let tag_mask = (1 << T::BITS) - 1;
-        packed &= !(tag_mask << Self::TAG_BIT_SHIFT);
+        let new_tag = Self::tag_to_usize(tag) << Self::TAG_BIT_SHIFT;
+        packed &= !(Self::TAG_MASK << Self::TAG_BIT_SHIFT);
         packed |= new_tag;
         self.packed = unsafe { NonZeroUsize::new_unchecked(packed) };
     }
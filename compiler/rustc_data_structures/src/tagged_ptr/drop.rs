@@ -39,12 +39,24 @@ pub fn new(pointer: P, tag: T) -> Self {
         TaggedPtr { raw: CopyTaggedPtr::new(pointer, tag) }
     }
 
+    /// Consumes the tagged pointer, returning the original pointer.
+    pub fn pointer(self) -> P {
+        let raw = self.raw.pointer_raw();
+        // The pointer is handed back to the caller, so we must not drop it.
+        std::mem::forget(self);
+        // SAFETY: pointer_raw returns the original pointer, and `self` has been
+        // forgotten, so ownership is transferred exactly once.
+        unsafe { P::from_usize(raw) }
+    }
     pub fn pointer_ref(&self) -> &P::Target {
         self.raw.pointer_ref()
     }
     pub fn tag(&self) -> T {
         self.raw.tag()
     }
+    pub fn set_tag(&mut self, tag: T) {
+        self.raw.set_tag(tag)
+    }
 }
 
 impl<P, T, const COMPARE_PACKED: bool> std::ops::Deref for TaggedPtr<P, T, COMPARE_PACKED>
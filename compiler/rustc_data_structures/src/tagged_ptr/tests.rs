@@ -0,0 +1,107 @@
+use super::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Tag3 {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+}
+
+const TAGS: [Tag3; 8] = [Tag3::A, Tag3::B, Tag3::C, Tag3::D, Tag3::E, Tag3::F, Tag3::G, Tag3::H];
+
+unsafe impl Tag for Tag3 {
+    const BITS: usize = 3;
+
+    fn into_usize(self) -> usize {
+        self as usize
+    }
+
+    unsafe fn from_usize(tag: usize) -> Self {
+        TAGS[tag]
+    }
+}
+
+/// Guarantees the 3 free low bits a `Tag3` needs on every target.
+#[derive(Debug, PartialEq, Eq)]
+#[repr(align(8))]
+struct Aligned(u32);
+
+#[test]
+fn three_bit_tags_round_trip() {
+    for (i, &tag) in TAGS.iter().enumerate() {
+        let ptr: TaggedPtr<Box<Aligned>, Tag3, false> =
+            TaggedPtr::new(Box::new(Aligned(i as u32)), tag);
+        assert_eq!(ptr.tag(), tag);
+        assert_eq!(*ptr, Aligned(i as u32));
+        assert_eq!(ptr.pointer(), Box::new(Aligned(i as u32)));
+    }
+}
+
+#[test]
+fn set_tag_keeps_pointer() {
+    let value = Aligned(42);
+    let mut ptr: CopyTaggedPtr<&Aligned, Tag3, true> = CopyTaggedPtr::new(&value, Tag3::A);
+    for &tag in TAGS.iter().chain(TAGS.iter().rev()) {
+        ptr.set_tag(tag);
+        assert_eq!(ptr.tag(), tag);
+        assert!(std::ptr::eq(ptr.pointer(), &value));
+    }
+
+    let mut owned: TaggedPtr<Box<Aligned>, Tag3, false> =
+        TaggedPtr::new(Box::new(Aligned(7)), Tag3::H);
+    for &tag in TAGS.iter() {
+        owned.set_tag(tag);
+        assert_eq!(owned.tag(), tag);
+        assert_eq!(*owned, Aligned(7));
+    }
+}
+
+#[test]
+fn packed_comparison_includes_tag() {
+    let value = Aligned(1);
+    let a: CopyTaggedPtr<&Aligned, Tag3, true> = CopyTaggedPtr::new(&value, Tag3::C);
+    let mut b = a;
+    assert_eq!(a, b);
+    b.set_tag(Tag3::D);
+    assert_ne!(a, b);
+}
+
+/// A pointer type that claims its pointee is aligned to 8 bytes, whether or
+/// not that is true.
+#[derive(Copy, Clone)]
+struct OverclaimedAlignment(&'static u8);
+
+impl std::ops::Deref for OverclaimedAlignment {
+    type Target = u8;
+    fn deref(&self) -> &u8 {
+        self.0
+    }
+}
+
+unsafe impl Pointer for OverclaimedAlignment {
+    const BITS: usize = 3;
+    fn into_usize(self) -> usize {
+        self.0 as *const u8 as usize
+    }
+    unsafe fn from_usize(ptr: usize) -> Self {
+        OverclaimedAlignment(&*(ptr as *const u8))
+    }
+    unsafe fn with_ref<R, F: FnOnce(&Self) -> R>(ptr: usize, f: F) -> R {
+        f(&*(&ptr as *const usize as *const Self))
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "pointer is not aligned enough to hold a 3-bit tag")]
+fn under_aligned_pointer() {
+    static BYTES: [u8; 16] = [0; 16];
+    let unaligned = BYTES.iter().find(|b| (*b as *const u8 as usize) % 8 != 0).unwrap();
+    let _: CopyTaggedPtr<_, Tag3, false> =
+        CopyTaggedPtr::new(OverclaimedAlignment(unaligned), Tag3::B);
+}
@@ -8,6 +8,8 @@ pub struct MaybeTempDir {
     dir: ManuallyDrop<TempDir>,
     // Whether the TempDir should be deleted on drop.
     keep: bool,
+    // Whether the TempDir should be kept if it is dropped during a panic.
+    keep_on_panic: bool,
 }
 
 impl Drop for MaybeTempDir {
@@ -15,7 +17,7 @@ fn drop(&mut self) {
         // SAFETY: We are in the destructor, and no further access will
         // occur.
         let dir = unsafe { ManuallyDrop::take(&mut self.dir) };
-        if self.keep {
+        if self.keep || (self.keep_on_panic && std::thread::panicking()) {
             dir.into_path();
         }
     }
@@ -29,6 +31,16 @@ fn as_ref(&self) -> &Path {
 
 impl MaybeTempDir {
     pub fn new(dir: TempDir, keep_on_drop: bool) -> MaybeTempDir {
-        MaybeTempDir { dir: ManuallyDrop::new(dir), keep: keep_on_drop }
+        MaybeTempDir { dir: ManuallyDrop::new(dir), keep: keep_on_drop, keep_on_panic: false }
+    }
+
+    /// Keeps the directory around if it is dropped while the current thread
+    /// is panicking, so that the files that led to the panic can be inspected.
+    pub fn keep_on_panic(mut self, keep_on_panic: bool) -> MaybeTempDir {
+        self.keep_on_panic = keep_on_panic;
+        self
     }
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,38 @@
+use super::*;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+
+/// Creates a `MaybeTempDir` with `make`, then drops it while panicking and
+/// returns its path.
+fn path_after_panic(make: impl FnOnce(TempDir) -> MaybeTempDir) -> PathBuf {
+    let mut path = None;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let dir = make(tempfile::tempdir().unwrap());
+        path = Some(dir.as_ref().to_owned());
+        panic!("dropping the directory during a panic");
+    }));
+    assert!(result.is_err());
+    path.unwrap()
+}
+
+#[test]
+fn keep_on_panic_keeps_directory_when_panicking() {
+    let path = path_after_panic(|dir| MaybeTempDir::new(dir, false).keep_on_panic(true));
+    assert!(path.is_dir());
+    std::fs::remove_dir(&path).unwrap();
+}
+
+#[test]
+fn keep_on_panic_removes_directory_on_normal_drop() {
+    let dir = MaybeTempDir::new(tempfile::tempdir().unwrap(), false).keep_on_panic(true);
+    let path = dir.as_ref().to_owned();
+    assert!(path.is_dir());
+    drop(dir);
+    assert!(!path.exists());
+}
+
+#[test]
+fn directory_is_removed_on_panic_by_default() {
+    let path = path_after_panic(|dir| MaybeTempDir::new(dir, false));
+    assert!(!path.exists());
+}
@@ -122,6 +122,22 @@ pub fn reachable_from(&self, a: &T) -> Vec<&T> {
         }
     }
 
+    /// Thinking of `x R y` as an edge `x -> y` in a graph, this
+    /// returns all `x` such that the edge `x -> a` was added directly,
+    /// i.e. the immediate predecessors of `a`, ignoring the transitive
+    /// closure. Unlike `parents`, this does not look "up" the lattice.
+    pub fn immediate_predecessors(&self, a: &T) -> Vec<&T> {
+        let a = match self.index(a) {
+            Some(a) => a,
+            None => return vec![],
+        };
+        self.edges
+            .iter()
+            .filter(|edge| edge.target == a)
+            .map(|edge| &self.elements[edge.source.0])
+            .collect()
+    }
+
     /// Picks what I am referring to as the "postdominating"
     /// upper-bound for `a` and `b`. This is usually the least upper
     /// bound, but in cases where there is no single least upper
@@ -208,6 +224,15 @@ pub fn minimal_upper_bounds(&self, a: &T, b: &T) -> Vec<&T> {
         let lub_indices = self.with_closure(|closure| {
             // Easy case is when either a < b or b < a:
             if closure.contains(a.0, b.0) {
+                // If `a` and `b` are part of the same cycle, every element
+                // of that cycle is a minimal upper bound, so pick its
+                // smallest representative, as documented above.
+                if closure.contains(b.0, a.0) {
+                    let scc_min = (0..self.elements.len())
+                        .find(|&x| closure.contains(a.0, x) && closure.contains(x, a.0))
+                        .unwrap_or(a.0);
+                    return vec![scc_min];
+                }
                 return vec![b.0];
             }
             if closure.contains(b.0, a.0) {
@@ -325,6 +325,43 @@ fn mubs_scc_4() {
     assert_eq!(relation.minimal_upper_bounds(&"a", &"b"), vec![&"c"]);
 }
 
+#[test]
+fn mubs_same_scc() {
+    // x -> y <-> z
+    //
+    // `y` and `z` are upper bounds of each other, so the smallest
+    // representative of their cycle is returned whichever way we ask.
+    let mut relation = TransitiveRelation::default();
+    relation.add("x", "y");
+    relation.add("y", "z");
+    relation.add("z", "y");
+
+    assert_eq!(relation.minimal_upper_bounds(&"y", &"z"), vec![&"y"]);
+    assert_eq!(relation.minimal_upper_bounds(&"z", &"y"), vec![&"y"]);
+    assert_eq!(relation.minimal_upper_bounds(&"x", &"z"), vec![&"y"]);
+}
+
+#[test]
+fn immediate_predecessors_diamond() {
+    // a -> b -> d
+    // |         ^
+    // +--> c ---+
+    let mut relation = TransitiveRelation::default();
+    relation.add("a", "b");
+    relation.add("a", "c");
+    relation.add("b", "d");
+    relation.add("c", "d");
+
+    assert_eq!(relation.immediate_predecessors(&"d"), vec![&"b", &"c"]);
+    assert_eq!(relation.immediate_predecessors(&"b"), vec![&"a"]);
+    assert!(relation.immediate_predecessors(&"a").is_empty());
+    assert!(relation.immediate_predecessors(&"z").is_empty());
+
+    // The transitive relation still knows that `a` reaches `d`.
+    assert!(relation.contains(&"a", &"d"));
+    assert_eq!(relation.reachable_from(&"a"), vec![&"b", &"c", &"d"]);
+}
+
 #[test]
 fn parent() {
     // An example that was misbehaving in the compiler.
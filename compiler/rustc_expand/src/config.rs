@@ -36,9 +36,18 @@ fn get_features(
     span_handler: &Handler,
     krate_attrs: &[ast::Attribute],
 ) -> Features {
-    fn feature_removed(span_handler: &Handler, span: Span, reason: Option<&str>) {
+    fn feature_removed(
+        span_handler: &Handler,
+        span: Span,
+        reason: Option<&str>,
+        removed_in: Option<&str>,
+    ) {
         let mut err = struct_span_err!(span_handler, span, E0557, "feature has been removed");
-        err.span_label(span, "feature has been removed");
+        let label = match removed_in {
+            Some(version) => format!("feature has been removed in {}", version),
+            None => "feature has been removed".to_string(),
+        };
+        err.span_label(span, label);
         if let Some(reason) = reason {
             err.note(reason);
         }
@@ -156,11 +165,16 @@ fn active_features_up_to(edition: Edition) -> impl Iterator<Item = &'static Feat
             let removed = REMOVED_FEATURES.iter().find(|f| name == f.name);
             let stable_removed = STABLE_REMOVED_FEATURES.iter().find(|f| name == f.name);
             if let Some(Feature { state, .. }) = removed.or(stable_removed) {
-                if let FeatureState::Removed { reason } | FeatureState::Stabilized { reason } =
-                    state
-                {
-                    feature_removed(span_handler, mi.span(), *reason);
-                    continue;
+                match *state {
+                    FeatureState::Removed { reason, removed_in } => {
+                        feature_removed(span_handler, mi.span(), reason, removed_in);
+                        continue;
+                    }
+                    FeatureState::Stabilized { reason } => {
+                        feature_removed(span_handler, mi.span(), reason, None);
+                        continue;
+                    }
+                    FeatureState::Accepted | FeatureState::Active { .. } => {}
                 }
             }
 
@@ -680,6 +680,14 @@ pub fn set(&self, features: &mut Features, span: Span) {
     /// Allows `cfg(target_abi = "...")`.
     (active, cfg_target_abi, "1.55.0", Some(80970), None),
 
+    // no-tracking-issue-start
+
+    /// Allows `#[test(resource = "...")]` to keep tests that share a resource from running
+    /// at the same time.
+    (active, test_resource_groups, "1.56.0", None, None),
+
+    // no-tracking-issue-end
+
     // -------------------------------------------------------------------------
     // feature-group-end: actual feature gates
     // -------------------------------------------------------------------------
@@ -29,7 +29,7 @@
 pub enum State {
     Accepted,
     Active { set: fn(&mut Features, Span) },
-    Removed { reason: Option<&'static str> },
+    Removed { reason: Option<&'static str>, removed_in: Option<&'static str> },
     Stabilized { reason: Option<&'static str> },
 }
 
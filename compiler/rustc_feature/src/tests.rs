@@ -21,3 +21,19 @@ fn rustc_bootstrap_parsing() {
     // this is technically a breaking change, but there are no stability guarantees for RUSTC_BOOTSTRAP
     assert!(!is_bootstrap("0", None));
 }
+
+#[test]
+fn removed_feature_reports_since_and_removed_in() {
+    use super::{State, REMOVED_FEATURES};
+    use rustc_span::symbol::sym;
+
+    let feature = REMOVED_FEATURES.iter().find(|f| f.name == sym::optin_builtin_traits).unwrap();
+    assert_eq!(feature.since, "1.0.0");
+    match feature.state {
+        State::Removed { removed_in, reason } => {
+            assert_eq!(removed_in, Some("1.50.0"));
+            assert_eq!(reason, Some("renamed to `auto_traits`"));
+        }
+        state => panic!("unexpected state {:?}", state),
+    }
+}
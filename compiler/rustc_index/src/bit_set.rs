@@ -728,6 +728,39 @@ pub fn contains(&self, elem: T) -> bool {
         let (word_index, mask) = word_index_and_mask(elem);
         if let Some(word) = self.bit_set.words.get(word_index) { (word & mask) != 0 } else { false }
     }
+
+    /// Sets `self = self & other` and returns `true` if `self` changed
+    /// (i.e., if any bits were removed). The sets may have different sizes;
+    /// bits of `self` beyond the end of `other` are cleared.
+    pub fn intersect_with(&mut self, other: &GrowableBitSet<T>) -> bool {
+        let len = self.bit_set.words.len().min(other.bit_set.words.len());
+        let (common, excess) = self.bit_set.words.split_at_mut(len);
+        let mut changed = bitwise(common, &other.bit_set.words[..len], |a, b| a & b);
+        for word in excess {
+            changed |= *word != 0;
+            *word = 0;
+        }
+        changed
+    }
+
+    /// Sets `self = self - other` and returns `true` if `self` changed
+    /// (i.e., if any bits were removed). The sets may have different sizes;
+    /// bits of `other` beyond the end of `self` are ignored.
+    pub fn subtract(&mut self, other: &GrowableBitSet<T>) -> bool {
+        let len = self.bit_set.words.len().min(other.bit_set.words.len());
+        bitwise(&mut self.bit_set.words[..len], &other.bit_set.words[..len], |a, b| a & !b)
+    }
+
+    /// Count the number of set bits in the set.
+    pub fn count(&self) -> usize {
+        self.bit_set.count()
+    }
+
+    /// Iterates over the indices of set bits in a sorted order.
+    #[inline]
+    pub fn iter(&self) -> BitIter<'_, T> {
+        self.bit_set.iter()
+    }
 }
 
 /// A fixed-size 2D bit matrix type with a dense representation.
@@ -151,6 +151,66 @@ fn grow() {
     }
 }
 
+#[test]
+fn growable_intersect_with() {
+    let mut small: GrowableBitSet<usize> = GrowableBitSet::new_empty();
+    let mut large: GrowableBitSet<usize> = GrowableBitSet::new_empty();
+    for i in [1, 3, 64, 65] {
+        small.insert(i);
+    }
+    for i in [3, 64, 100, 200] {
+        large.insert(i);
+    }
+
+    // Shorter `other`: the excess words of `self` are cleared.
+    let mut a = large.clone();
+    assert!(a.intersect_with(&small));
+    assert_eq!(a.iter().collect::<Vec<_>>(), [3, 64]);
+    assert_eq!(a.count(), 2);
+    assert!(!a.contains(200));
+    assert!(!a.intersect_with(&small));
+
+    // Longer `other`.
+    let mut b = small.clone();
+    assert!(b.intersect_with(&large));
+    assert_eq!(b.iter().collect::<Vec<_>>(), [3, 64]);
+    assert_eq!(b.count(), 2);
+
+    // Intersecting with an empty set clears everything.
+    assert!(b.intersect_with(&GrowableBitSet::new_empty()));
+    assert!(b.is_empty());
+    assert_eq!(b.count(), 0);
+}
+
+#[test]
+fn growable_subtract() {
+    let mut small: GrowableBitSet<usize> = GrowableBitSet::new_empty();
+    let mut large: GrowableBitSet<usize> = GrowableBitSet::new_empty();
+    for i in [1, 3, 64, 65] {
+        small.insert(i);
+    }
+    for i in [3, 64, 100, 200] {
+        large.insert(i);
+    }
+
+    // Shorter `other`: the bits of `self` beyond its end are kept.
+    let mut a = large.clone();
+    assert!(a.subtract(&small));
+    assert_eq!(a.iter().collect::<Vec<_>>(), [100, 200]);
+    assert_eq!(a.count(), 2);
+    assert!(!a.subtract(&small));
+
+    // Longer `other`: its bits beyond the end of `self` are ignored.
+    let mut b = small.clone();
+    assert!(b.subtract(&large));
+    assert_eq!(b.iter().collect::<Vec<_>>(), [1, 65]);
+    assert_eq!(b.count(), 2);
+
+    // The set can still grow afterwards.
+    assert!(b.insert(300));
+    assert_eq!(b.iter().collect::<Vec<_>>(), [1, 65, 300]);
+}
+
 #[test]
 fn matrix_intersection() {
     let mut matrix: BitMatrix<usize, usize> = BitMatrix::new(200, 200);
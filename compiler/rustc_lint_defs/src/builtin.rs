@@ -2885,6 +2885,45 @@
     "detects large moves or copies",
 }
 
+declare_lint! {
+    /// The `ambiguous_glob_imports` lint detects names that are brought
+    /// into scope by two glob imports which disagree on what the name
+    /// refers to.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,compile_fail
+    /// #![deny(ambiguous_glob_imports)]
+    /// mod a {
+    ///     pub struct Foo;
+    /// }
+    /// mod b {
+    ///     pub struct Foo;
+    /// }
+    ///
+    /// pub use a::*;
+    /// pub use b::*;
+    /// # fn main() {}
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Such an ambiguity is only an error once the name is actually used.
+    /// A library that glob re-exports two modules won't notice the
+    /// ambiguity itself, but its users get an error as soon as they try to
+    /// use the name through it. This lint reports the ambiguity where the
+    /// imports are, whether or not the name is used.
+    ///
+    /// This lint is "allow" by default because glob imports that are
+    /// ambiguous but never used for the ambiguous name are harmless in
+    /// most code.
+    pub AMBIGUOUS_GLOB_IMPORTS,
+    Allow,
+    "detects names made ambiguous by two glob imports, even if they are not used"
+}
+
 declare_lint_pass! {
     /// Does nothing as a lint pass, but registers some `Lint`s
     /// that are used by other parts of the compiler.
@@ -2975,6 +3014,7 @@
         RUST_2021_PRELUDE_COLLISIONS,
         RUST_2021_PREFIXES_INCOMPATIBLE_SYNTAX,
         UNSUPPORTED_CALLING_CONVENTIONS,
+        AMBIGUOUS_GLOB_IMPORTS,
     ]
 }
 
@@ -338,8 +338,13 @@ fn resolve_visibility_speculative<'ast>(
                     PathResult::NonModule(partial_res) => {
                         expected_found_error(partial_res.base_res())
                     }
-                    PathResult::Failed { span, label, suggestion, .. } => {
-                        Err(VisResolutionError::FailedToResolve(span, label, suggestion))
+                    PathResult::Failed { span, label, suggestion, resolved_prefix_span, .. } => {
+                        Err(VisResolutionError::FailedToResolve(
+                            span,
+                            label,
+                            suggestion,
+                            resolved_prefix_span,
+                        ))
                     }
                     PathResult::Indeterminate => Err(VisResolutionError::Indeterminate(path.span)),
                 }
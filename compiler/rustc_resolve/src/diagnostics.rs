@@ -377,10 +377,13 @@ impl<'a> Resolver<'a> {
                 err.span_label(span, "can only appear in an import list with a non-empty prefix");
                 err
             }
-            ResolutionError::FailedToResolve { label, suggestion } => {
+            ResolutionError::FailedToResolve { label, suggestion, resolved_prefix_span } => {
                 let mut err =
                     struct_span_err!(self.session, span, E0433, "failed to resolve: {}", &label);
                 err.span_label(span, label);
+                if let Some(prefix_span) = resolved_prefix_span {
+                    err.span_label(prefix_span, "resolved up to here");
+                }
 
                 if let Some((suggestions, msg, applicability)) = suggestion {
                     err.multipart_suggestion(&msg, suggestions, applicability);
@@ -575,8 +578,11 @@ impl<'a> Resolver<'a> {
                 E0742,
                 "visibilities can only be restricted to ancestor modules"
             ),
-            VisResolutionError::FailedToResolve(span, label, suggestion) => {
-                self.into_struct_error(span, ResolutionError::FailedToResolve { label, suggestion })
+            VisResolutionError::FailedToResolve(span, label, suggestion, resolved_prefix_span) => {
+                self.into_struct_error(
+                    span,
+                    ResolutionError::FailedToResolve { label, suggestion, resolved_prefix_span },
+                )
             }
             VisResolutionError::ExpectedFound(span, path_str, res) => {
                 let mut err = struct_span_err!(
@@ -744,6 +750,25 @@ fn early_lookup_typo_candidate(
         }
     }
 
+    /// Suggests an extern crate whose name is similar to `ident`, for when the first
+    /// segment of a path names a crate that does not exist (e.g. `serde_jsonn`).
+    /// The candidates are the names in the extern prelude, which include every crate
+    /// passed with `--extern` as well as `std`, `core` and root `extern crate` items.
+    crate fn similar_extern_crate_suggestion(&self, ident: Ident) -> Option<Suggestion> {
+        let mut crate_names =
+            self.extern_prelude.keys().map(|ident| ident.name).collect::<Vec<Symbol>>();
+        // Make sure error reporting is deterministic.
+        crate_names.sort_by_cached_key(|name| name.as_str());
+        match find_best_match_for_name(&crate_names, ident.name, None) {
+            Some(found) if found != ident.name => Some((
+                vec![(ident.span, found.to_string())],
+                String::from("there is a crate with a similar name"),
+                Applicability::MaybeIncorrect,
+            )),
+            _ => None,
+        }
+    }
+
     fn lookup_import_candidates_from_module<FilterFn>(
         &mut self,
         lookup_ident: Ident,
@@ -1171,6 +1196,31 @@ fn ctor_fields_span(&self, binding: &NameBinding<'_>) -> Option<Span> {
             err.span_note(note_span, &msg);
         }
 
+        // An item of this crate that is accessed directly can be fixed by making it
+        // visible in the whole crate. The note above already points at the definition,
+        // so only tools get to see the suggestion.
+        if let Res::Def(kind, def_id) = res {
+            let def_span = first_binding.span;
+            let is_plain_item = !matches!(kind, DefKind::Ctor(..) | DefKind::Macro(_));
+            if def_id.is_local()
+                && is_plain_item
+                && !first_binding.is_import()
+                && !def_span.from_expansion()
+                && self
+                    .session
+                    .source_map()
+                    .span_to_snippet(def_span)
+                    .map_or(false, |snippet| !snippet.starts_with("pub"))
+            {
+                err.tool_only_span_suggestion(
+                    def_span.shrink_to_lo(),
+                    &format!("consider making the {} visible in the whole crate", descr),
+                    "pub(crate) ".to_string(),
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+
         err.emit();
     }
 }
@@ -20,7 +20,9 @@
 use rustc_middle::hir::exports::Export;
 use rustc_middle::span_bug;
 use rustc_middle::ty;
-use rustc_session::lint::builtin::{PUB_USE_OF_PRIVATE_EXTERN_CRATE, UNUSED_IMPORTS};
+use rustc_session::lint::builtin::{
+    AMBIGUOUS_GLOB_IMPORTS, PUB_USE_OF_PRIVATE_EXTERN_CRATE, UNUSED_IMPORTS,
+};
 use rustc_session::lint::BuiltinLintDiagnostics;
 use rustc_span::hygiene::LocalExpnId;
 use rustc_span::lev_distance::find_best_match_for_name;
@@ -301,6 +303,20 @@ impl<'a> Resolver<'a> {
                 .ok_or((Determined, Weak::No))
                 .and_then(|binding| {
                     if self.last_import_segment && check_usable(self, binding).is_err() {
+                        // A name that a glob brings into scope but that is not accessible from
+                        // here exists, it is just private. Report it as such (pointing to the
+                        // glob) instead of claiming that the name cannot be found.
+                        let is_unusable_binding =
+                            self.unusable_binding.map_or(false, |b| ptr::eq(b, binding));
+                        if binding.is_glob_import() && !is_unusable_binding {
+                            self.record_use(ident, ns, binding, restricted_shadowing);
+                            self.privacy_errors.push(PrivacyError {
+                                ident,
+                                binding,
+                                dedup_span: path_span,
+                            });
+                            return Ok(binding);
+                        }
                         Err((Determined, Weak::No))
                     } else {
                         self.record_use(ident, ns, binding, restricted_shadowing);
@@ -624,6 +640,11 @@ struct UnresolvedImportError {
     label: Option<String>,
     note: Vec<String>,
     suggestion: Option<Suggestion>,
+    /// The `use` item this error comes from.
+    root_id: NodeId,
+    /// The number of other glob imports in the same `use` item that failed
+    /// with this same error, and were not reported separately.
+    glob_duplicates: usize,
 }
 
 pub struct ImportResolver<'a, 'b> {
@@ -706,6 +727,17 @@ pub fn finalize_imports(&mut self) {
                     );
                     errors.push((path, err));
                     prev_root_id = import.root_id;
+                } else if import.is_glob() {
+                    // A glob failing for the same reason as an import we already
+                    // reported, e.g. because they all start with the same missing
+                    // crate. Only count it so that the user isn't flooded with
+                    // identical errors.
+                    let representative = errors.iter_mut().find(|(_, repr)| {
+                        repr.span == err.span && repr.root_id == import.root_id
+                    });
+                    if let Some((_, representative)) = representative {
+                        representative.glob_duplicates += 1;
+                    }
                 }
             } else if is_indeterminate {
                 // Consider erroneous imports used to avoid duplicate diagnostics.
@@ -720,6 +752,8 @@ pub fn finalize_imports(&mut self) {
                     label: None,
                     note: Vec::new(),
                     suggestion: None,
+                    root_id: import.root_id,
+                    glob_duplicates: 0,
                 };
                 errors.push((path, err));
             }
@@ -763,6 +797,14 @@ fn throw_unresolved_import_error(
                 diag.span_label(err.span, label);
             }
 
+            if err.glob_duplicates > 0 {
+                diag.note(&format!(
+                    "{} other glob import{} in this `use` failed for the same reason",
+                    err.glob_duplicates,
+                    pluralize!(err.glob_duplicates),
+                ));
+            }
+
             if let Some((suggestions, msg, applicability)) = err.suggestion {
                 diag.multipart_suggestion(&msg, suggestions, applicability);
             }
@@ -919,15 +961,33 @@ fn finalize_import(&mut self, import: &'b Import<'b>) -> Option<UnresolvedImport
 
                 module
             }
-            PathResult::Failed { is_error_from_last_segment: false, span, label, suggestion } => {
+            PathResult::Failed {
+                is_error_from_last_segment: false,
+                span,
+                label,
+                suggestion,
+                resolved_prefix_span,
+            } => {
                 if no_ambiguity {
                     assert!(import.imported_module.get().is_none());
-                    self.r
-                        .report_error(span, ResolutionError::FailedToResolve { label, suggestion });
+                    self.r.report_error(
+                        span,
+                        ResolutionError::FailedToResolve {
+                            label,
+                            suggestion,
+                            resolved_prefix_span,
+                        },
+                    );
                 }
                 return None;
             }
-            PathResult::Failed { is_error_from_last_segment: true, span, label, suggestion } => {
+            PathResult::Failed {
+                is_error_from_last_segment: true,
+                span,
+                label,
+                suggestion,
+                ..
+            } => {
                 if no_ambiguity {
                     assert!(import.imported_module.get().is_none());
                     let err = match self.make_path_suggestion(
@@ -944,12 +1004,16 @@ fn finalize_import(&mut self, import: &'b Import<'b>) -> Option<UnresolvedImport
                                 String::from("a similar path exists"),
                                 Applicability::MaybeIncorrect,
                             )),
+                            root_id: import.root_id,
+                            glob_duplicates: 0,
                         },
                         None => UnresolvedImportError {
                             span,
                             label: Some(label),
                             note: Vec::new(),
                             suggestion,
+                            root_id: import.root_id,
+                            glob_duplicates: 0,
                         },
                     };
                     return Some(err);
@@ -997,6 +1061,8 @@ fn finalize_import(&mut self, import: &'b Import<'b>) -> Option<UnresolvedImport
                             label: Some(String::from("cannot glob-import a module into itself")),
                             note: Vec::new(),
                             suggestion: None,
+                            root_id: import.root_id,
+                            glob_duplicates: 0,
                         });
                     }
                 }
@@ -1173,6 +1239,8 @@ fn finalize_import(&mut self, import: &'b Import<'b>) -> Option<UnresolvedImport
                     label: Some(label),
                     note,
                     suggestion,
+                    root_id: import.root_id,
+                    glob_duplicates: 0,
                 })
             } else {
                 // `resolve_ident_in_module` reported a privacy error.
@@ -1387,8 +1455,42 @@ fn finalize_resolutions_in(&mut self, module: Module<'b>) {
         *module.globs.borrow_mut() = Vec::new();
 
         let mut reexports = Vec::new();
+        let mut seen_ambiguities = FxHashSet::default();
 
         module.for_each_child(self.r, |this, ident, _, binding| {
+            // Ambiguities are only errors when the name gets used, but
+            // `ambiguous_glob_imports` reports glob-vs-glob ones right away.
+            // A name can be ambiguous in several namespaces, report it once.
+            if let Some((other, AmbiguityKind::GlobVsGlob)) = binding.ambiguity {
+                if let NameBindingKind::Import { import, .. } = binding.kind {
+                    if seen_ambiguities.insert((ident, binding.span, other.span)) {
+                        let mut spans = MultiSpan::from_span(binding.span);
+                        spans.push_span_label(
+                            binding.span,
+                            format!(
+                                "`{}` could refer to the {} imported here",
+                                ident,
+                                binding.res().descr()
+                            ),
+                        );
+                        spans.push_span_label(
+                            other.span,
+                            format!(
+                                "`{}` could also refer to the {} imported here",
+                                ident,
+                                other.res().descr()
+                            ),
+                        );
+                        let msg = format!(
+                            "`{}` is ambiguous ({})",
+                            ident,
+                            AmbiguityKind::GlobVsGlob.descr()
+                        );
+                        this.lint_buffer.buffer_lint(AMBIGUOUS_GLOB_IMPORTS, import.id, spans, &msg);
+                    }
+                }
+            }
+
             // Filter away ambiguous imports and anything that has def-site hygiene.
             // FIXME: Implement actual cross-crate hygiene.
             let is_good_import =
@@ -222,7 +222,11 @@ enum ResolutionError<'a> {
     /// Error E0431: `self` import can only appear in an import list with a non-empty prefix.
     SelfImportOnlyInImportListWithNonEmptyPrefix,
     /// Error E0433: failed to resolve.
-    FailedToResolve { label: String, suggestion: Option<Suggestion> },
+    FailedToResolve {
+        label: String,
+        suggestion: Option<Suggestion>,
+        resolved_prefix_span: Option<Span>,
+    },
     /// Error E0434: can't capture dynamic environment in a fn item.
     CannotCaptureDynamicEnvironmentInFnItem,
     /// Error E0435: attempt to use a non-constant value in a constant.
@@ -257,7 +261,7 @@ enum ResolutionError<'a> {
 enum VisResolutionError<'a> {
     Relative2018(Span, &'a ast::Path),
     AncestorOnly(Span),
-    FailedToResolve(Span, String, Option<Suggestion>),
+    FailedToResolve(Span, String, Option<Suggestion>, Option<Span>),
     ExpectedFound(Span, String, Res),
     Indeterminate(Span),
     ModuleOnly(Span),
@@ -286,6 +290,22 @@ fn from_ident(ident: Ident) -> Segment {
     fn names_to_string(segments: &[Segment]) -> String {
         names_to_string(&segments.iter().map(|seg| seg.ident.name).collect::<Vec<_>>())
     }
+
+    /// Returns the span covering a successfully resolved path prefix, to be pointed at
+    /// when resolution of the next segment fails. Prefixes consisting of a single
+    /// segment are obvious from the error itself, so `None` is returned for them.
+    fn resolved_prefix_span(prefix: &[Segment]) -> Option<Span> {
+        let mut idents = prefix
+            .iter()
+            .map(|seg| seg.ident)
+            .filter(|ident| ident.name != kw::PathRoot && !ident.span.is_dummy());
+        let first = idents.next()?;
+        let last = idents.last()?;
+        if first.span.ctxt() != last.span.ctxt() {
+            return None;
+        }
+        Some(first.span.to(last.span))
+    }
 }
 
 impl<'a> From<&'a ast::PathSegment> for Segment {
@@ -430,6 +450,9 @@ enum PathResult<'a> {
         label: String,
         suggestion: Option<Suggestion>,
         is_error_from_last_segment: bool,
+        /// The span of the successfully resolved prefix of the path, if it is
+        /// long enough to be worth pointing out.
+        resolved_prefix_span: Option<Span>,
     },
 }
 
@@ -2249,6 +2272,7 @@ fn resolve_path_with_ribs(
                         label: msg,
                         suggestion: None,
                         is_error_from_last_segment: false,
+                        resolved_prefix_span: None,
                     };
                 }
                 if i == 0 {
@@ -2293,6 +2317,7 @@ fn resolve_path_with_ribs(
                     label,
                     suggestion: None,
                     is_error_from_last_segment: false,
+                    resolved_prefix_span: None,
                 };
             }
 
@@ -2402,6 +2427,7 @@ enum FindBindingResult<'a> {
                             label,
                             suggestion: None,
                             is_error_from_last_segment: is_last,
+                            resolved_prefix_span: Segment::resolved_prefix_span(&path[..i]),
                         };
                     }
                 }
@@ -2440,7 +2466,10 @@ enum FindBindingResult<'a> {
                                 )),
                             )
                         } else if self.session.edition() == Edition::Edition2015 {
-                            (format!("maybe a missing crate `{}`?", ident), None)
+                            (
+                                format!("maybe a missing crate `{}`?", ident),
+                                self.similar_extern_crate_suggestion(ident),
+                            )
                         } else {
                             (format!("could not find `{}` in the crate root", ident), None)
                         }
@@ -2506,7 +2535,10 @@ enum FindBindingResult<'a> {
 
                             (format!("use of undeclared type `{}`", ident), suggestion)
                         } else {
-                            (format!("use of undeclared crate or module `{}`", ident), None)
+                            (
+                                format!("use of undeclared crate or module `{}`", ident),
+                                self.similar_extern_crate_suggestion(ident),
+                            )
                         }
                     } else {
                         let parent = path[i - 1].ident.name;
@@ -2554,6 +2586,7 @@ enum FindBindingResult<'a> {
                         label,
                         suggestion,
                         is_error_from_last_segment: is_last,
+                        resolved_prefix_span: Segment::resolved_prefix_span(&path[..i]),
                     };
                 }
             }
@@ -3270,6 +3303,22 @@ pub fn resolve_str_path_error(
         path_str: &str,
         ns: Namespace,
         module_id: DefId,
+    ) -> Result<(ast::Path, Res), ()> {
+        let module = self.get_module(module_id);
+        let parent_scope = ParentScope::module(module, self);
+        self.resolve_str_path_in_scope(span, path_str, ns, &parent_scope)
+    }
+
+    /// Like `resolve_str_path_error`, but resolves `path_str` relative to an existing
+    /// `parent_scope` instead of a fresh scope for a module. Macro and proc-macro support
+    /// code can use this to resolve `self::` and `super::` paths from the scope they were
+    /// expanded in, without building a dummy parent scope.
+    pub fn resolve_str_path_in_scope(
+        &mut self,
+        span: Span,
+        path_str: &str,
+        ns: Namespace,
+        parent_scope: &ParentScope<'a>,
     ) -> Result<(ast::Path, Res), ()> {
         let path = if path_str.starts_with("::") {
             ast::Path {
@@ -3291,8 +3340,6 @@ pub fn resolve_str_path_error(
                 tokens: None,
             }
         };
-        let module = self.get_module(module_id);
-        let parent_scope = &ParentScope::module(module, self);
         let res = self.resolve_ast_path(&path, ns, parent_scope).map_err(|_| ())?;
         Ok((path, res))
     }
@@ -3321,12 +3368,14 @@ fn resolve_ast_path(
                 ResolutionError::FailedToResolve {
                     label: String::from("type-relative paths are not supported in this context"),
                     suggestion: None,
+                    resolved_prefix_span: None,
                 },
             )),
             PathResult::Module(..) | PathResult::Indeterminate => unreachable!(),
-            PathResult::Failed { span, label, suggestion, .. } => {
-                Err((span, ResolutionError::FailedToResolve { label, suggestion }))
-            }
+            PathResult::Failed { span, label, suggestion, resolved_prefix_span, .. } => Err((
+                span,
+                ResolutionError::FailedToResolve { label, suggestion, resolved_prefix_span },
+            )),
         }
     }
 
@@ -1001,6 +1001,7 @@
         repr_simd,
         repr_transparent,
         residual,
+        resource,
         result,
         result_type,
         rhs,
@@ -1252,6 +1253,7 @@
         test_accepted_feature,
         test_case,
         test_removed_feature,
+        test_resource_groups,
         test_runner,
         then_with,
         thread,
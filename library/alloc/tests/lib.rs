@@ -6,6 +6,7 @@
 #![feature(exact_size_is_empty)]
 #![feature(new_uninit)]
 #![feature(pattern)]
+#![feature(round_char_boundary)]
 #![feature(trusted_len)]
 #![feature(try_reserve)]
 #![feature(unboxed_closures)]
@@ -21,6 +22,8 @@
 #![feature(slice_partition_dedup)]
 #![feature(vec_spare_capacity)]
 #![feature(string_remove_matches)]
+#![feature(str_eq_ignore_case)]
+#![feature(substr_range)]
 
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
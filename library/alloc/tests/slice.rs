@@ -1900,10 +1900,10 @@ macro_rules! m {
 }
 
 #[test]
-fn test_group_by() {
+fn test_chunk_by() {
     let slice = &[1, 1, 1, 3, 3, 2, 2, 2, 1, 0];
 
-    let mut iter = slice.group_by(|a, b| a == b);
+    let mut iter = slice.chunk_by(|a, b| a == b);
     assert_eq!(iter.next(), Some(&[1, 1, 1][..]));
     assert_eq!(iter.next(), Some(&[3, 3][..]));
     assert_eq!(iter.next(), Some(&[2, 2, 2][..]));
@@ -1911,7 +1911,7 @@ fn test_group_by() {
     assert_eq!(iter.next(), Some(&[0][..]));
     assert_eq!(iter.next(), None);
 
-    let mut iter = slice.group_by(|a, b| a == b);
+    let mut iter = slice.chunk_by(|a, b| a == b);
     assert_eq!(iter.next_back(), Some(&[0][..]));
     assert_eq!(iter.next_back(), Some(&[1][..]));
     assert_eq!(iter.next_back(), Some(&[2, 2, 2][..]));
@@ -1919,7 +1919,7 @@ fn test_group_by() {
     assert_eq!(iter.next_back(), Some(&[1, 1, 1][..]));
     assert_eq!(iter.next_back(), None);
 
-    let mut iter = slice.group_by(|a, b| a == b);
+    let mut iter = slice.chunk_by(|a, b| a == b);
     assert_eq!(iter.next(), Some(&[1, 1, 1][..]));
     assert_eq!(iter.next_back(), Some(&[0][..]));
     assert_eq!(iter.next(), Some(&[3, 3][..]));
@@ -1929,10 +1929,64 @@ fn test_group_by() {
 }
 
 #[test]
-fn test_group_by_mut() {
+fn test_chunk_by_edge_cases() {
+    let empty: &[i32] = &[];
+    assert_eq!(empty.chunk_by(|a, b| a == b).next(), None);
+    assert_eq!(empty.chunk_by(|a, b| a == b).next_back(), None);
+    assert_eq!(empty.chunk_by(|a, b| a == b).size_hint(), (0, Some(0)));
+
+    // A predicate that always holds yields the whole slice as one run.
+    let slice = &[3, 1, 4, 1, 5];
+    let mut iter = slice.chunk_by(|_, _| true);
+    assert_eq!(iter.next(), Some(&slice[..]));
+    assert_eq!(iter.next(), None);
+    assert_eq!(slice.chunk_by(|_, _| true).next_back(), Some(&slice[..]));
+
+    // Alternating elements never compare equal, so every run is one element.
+    let alternating = &[0, 1, 0, 1, 0];
+    let runs: Vec<_> = alternating.chunk_by(|a, b| a == b).collect();
+    assert_eq!(runs, [&[0][..], &[1], &[0], &[1], &[0]]);
+    let runs: Vec<_> = alternating.chunk_by(|a, b| a == b).rev().collect();
+    assert_eq!(runs, [&[0][..], &[1], &[0], &[1], &[0]]);
+
+    // The iterator is fused.
+    let mut iter = alternating.chunk_by(|a, b| a != b);
+    assert_eq!(iter.next(), Some(&alternating[..]));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn test_chunk_by_mut_disjoint() {
+    let slice = &mut [1, 1, 2, 3, 3, 3, 4];
+    let ptr_range = slice.as_ptr_range();
+
+    // Holding on to every run at once only works if they don't overlap.
+    let runs: Vec<&mut [i32]> = slice.chunk_by_mut(|a, b| a == b).collect();
+    assert_eq!(runs.len(), 4);
+    let mut expected_start = ptr_range.start;
+    for run in runs {
+        let run_range = run.as_ptr_range();
+        assert_eq!(run_range.start, expected_start);
+        expected_start = run_range.end;
+        let len = run.len() as i32;
+        for x in run {
+            *x *= 10 * len;
+        }
+    }
+    assert_eq!(expected_start, ptr_range.end);
+    assert_eq!(slice, &[20, 20, 20, 90, 90, 90, 40]);
+
+    let empty: &mut [i32] = &mut [];
+    assert_eq!(empty.chunk_by_mut(|a, b| a == b).next(), None);
+}
+
+#[test]
+fn test_chunk_by_mut() {
     let slice = &mut [1, 1, 1, 3, 3, 2, 2, 2, 1, 0];
 
-    let mut iter = slice.group_by_mut(|a, b| a == b);
+    let mut iter = slice.chunk_by_mut(|a, b| a == b);
     assert_eq!(iter.next(), Some(&mut [1, 1, 1][..]));
     assert_eq!(iter.next(), Some(&mut [3, 3][..]));
     assert_eq!(iter.next(), Some(&mut [2, 2, 2][..]));
@@ -1940,7 +1994,7 @@ fn test_group_by_mut() {
     assert_eq!(iter.next(), Some(&mut [0][..]));
     assert_eq!(iter.next(), None);
 
-    let mut iter = slice.group_by_mut(|a, b| a == b);
+    let mut iter = slice.chunk_by_mut(|a, b| a == b);
     assert_eq!(iter.next_back(), Some(&mut [0][..]));
     assert_eq!(iter.next_back(), Some(&mut [1][..]));
     assert_eq!(iter.next_back(), Some(&mut [2, 2, 2][..]));
@@ -1948,7 +2002,7 @@ fn test_group_by_mut() {
     assert_eq!(iter.next_back(), Some(&mut [1, 1, 1][..]));
     assert_eq!(iter.next_back(), None);
 
-    let mut iter = slice.group_by_mut(|a, b| a == b);
+    let mut iter = slice.chunk_by_mut(|a, b| a == b);
     assert_eq!(iter.next(), Some(&mut [1, 1, 1][..]));
     assert_eq!(iter.next_back(), Some(&mut [0][..]));
     assert_eq!(iter.next(), Some(&mut [3, 3][..]));
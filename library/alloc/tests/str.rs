@@ -774,6 +774,95 @@ fn test_is_char_boundary() {
     }
 }
 
+#[test]
+fn test_floor_char_boundary() {
+    fn check_many(s: &str, arg: impl IntoIterator<Item = usize>, ret: usize) {
+        for idx in arg {
+            assert_eq!(
+                s.floor_char_boundary(idx),
+                ret,
+                "{:?}.floor_char_boundary({:?}) != {:?}",
+                s,
+                idx,
+                ret
+            );
+        }
+    }
+
+    // edge case
+    check_many("", [0, 1, isize::MAX as usize, usize::MAX], 0);
+
+    // basic check
+    check_many("x", [0], 0);
+    check_many("x", [1, isize::MAX as usize, usize::MAX], 1);
+
+    // 1-byte chars
+    check_many("jp", [0], 0);
+    check_many("jp", [1], 1);
+    check_many("jp", 2..4, 2);
+
+    // 2-byte chars
+    check_many("ĵƥ", 0..2, 0);
+    check_many("ĵƥ", 2..4, 2);
+    check_many("ĵƥ", 4..6, 4);
+
+    // 3-byte chars
+    check_many("日本", 0..3, 0);
+    check_many("日本", 3..6, 3);
+    check_many("日本", 6..8, 6);
+
+    // 4-byte chars
+    check_many("🇯🇵", 0..4, 0);
+    check_many("🇯🇵", 4..8, 4);
+    check_many("🇯🇵", 8..10, 8);
+}
+
+#[test]
+fn test_ceil_char_boundary() {
+    fn check_many(s: &str, arg: impl IntoIterator<Item = usize>, ret: usize) {
+        for idx in arg {
+            assert_eq!(
+                s.ceil_char_boundary(idx),
+                ret,
+                "{:?}.ceil_char_boundary({:?}) != {:?}",
+                s,
+                idx,
+                ret
+            );
+        }
+    }
+
+    // edge case
+    check_many("", [0, 1, usize::MAX], 0);
+
+    // basic check
+    check_many("x", [0], 0);
+    check_many("x", [1, usize::MAX], 1);
+
+    // 1-byte chars
+    check_many("jp", [0], 0);
+    check_many("jp", [1], 1);
+    check_many("jp", [2], 2);
+
+    // 2-byte chars
+    check_many("ĵƥ", 0..=0, 0);
+    check_many("ĵƥ", 1..=2, 2);
+    check_many("ĵƥ", 3..=4, 4);
+
+    // 3-byte chars
+    check_many("日本", 0..=0, 0);
+    check_many("日本", 1..=3, 3);
+    check_many("日本", 4..=6, 6);
+
+    // 4-byte chars
+    check_many("🇯🇵", 0..=0, 0);
+    check_many("🇯🇵", 1..=4, 4);
+    check_many("🇯🇵", 5..=8, 8);
+
+    // beyond the end
+    check_many("日本", 7..10, 6);
+}
+
 #[test]
 fn test_trim_start_matches() {
     let v: &[char] = &[];
@@ -1016,6 +1105,30 @@ fn test_split_at() {
     assert_eq!(b, "");
 }
 
+#[test]
+fn test_substr_range() {
+    let s = "ศไทย中华Việt Nam";
+
+    assert_eq!(s.substr_range(s), Some(0..s.len()));
+    assert_eq!(s.substr_range(&s[..3]), Some(0..3));
+    assert_eq!(s.substr_range(&s[23..]), Some(23..s.len()));
+    assert_eq!(s.substr_range(&s[12..18]), Some(12..18));
+
+    // Empty substrings at either boundary still point into `s`.
+    assert_eq!(s.substr_range(&s[..0]), Some(0..0));
+    assert_eq!(s.substr_range(&s[s.len()..]), Some(s.len()..s.len()));
+
+    // Equal contents in a different allocation are not a substring.
+    let owned = String::from(&s[12..18]);
+    assert_eq!(owned, "中华");
+    assert_eq!(s.substr_range(&owned), None);
+
+    // Neither is a string that extends past either end of `s`.
+    assert_eq!(s[3..23].substr_range(s), None);
+    assert_eq!(s[3..23].substr_range(&s[..6]), None);
+    assert_eq!(s[3..23].substr_range(&s[18..]), None);
+}
+
 #[test]
 fn test_split_at_mut() {
     let mut s = "Hello World".to_string();
@@ -1691,6 +1804,40 @@ fn to_uppercase() {
     assert_eq!("aéǅßﬁᾀ".to_uppercase(), "AÉǄSSFIἈΙ");
 }
 
+#[test]
+fn eq_ignore_case() {
+    assert!("".eq_ignore_case(""));
+    assert!("Ferris".eq_ignore_case("fERRIS"));
+    assert!(!"Ferris".eq_ignore_case("Ferri"));
+    assert!(!"Ferris".eq_ignore_case("Ferriss"));
+
+    // Cyrillic
+    assert!("Привет, Мир".eq_ignore_case("ПРИВЕТ, мир"));
+    assert!("ёжик".eq_ignore_case("ЁЖИК"));
+    assert!(!"ёжик".eq_ignore_case("ЕЖИК"));
+
+    // All three Greek sigma forms fold to the same letter.
+    assert!("ΟΔΥΣΣΕΥΣ".eq_ignore_case("οδυσσευς"));
+    assert!("Σσς".eq_ignore_case("ςΣσ"));
+
+    // Different byte lengths: the Kelvin sign and the Angstrom sign take three bytes
+    // in UTF-8 and fold to one and two byte letters respectively, and the long s
+    // is two bytes long but folds to an ASCII letter.
+    assert!("\u{212A}\u{212B}".eq_ignore_case("kå"));
+    assert!("\u{17F}".eq_ignore_case("S"));
+    assert_ne!("\u{212A}\u{212B}\u{17F}".len(), "KÅS".len());
+    assert!("\u{212A}\u{212B}\u{17F}".eq_ignore_case("KÅS"));
+
+    // Simple folding only: full foldings that expand to several chars don't apply.
+    assert!("ẞ".eq_ignore_case("ß"));
+    assert!(!"ß".eq_ignore_case("ss"));
+    assert!(!"ﬁ".eq_ignore_case("fi"));
+
+    // No locale-specific folding of the Turkic dotted and dotless i.
+    assert!(!"İ".eq_ignore_case("i"));
+    assert!(!"ı".eq_ignore_case("I"));
+}
+
 #[test]
 fn test_into_string() {
     // The only way to acquire a Box<str> in the first place is through a String, so just
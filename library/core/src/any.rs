@@ -221,7 +221,7 @@ pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
             // SAFETY: just checked whether we are pointing to the correct type, and we can rely on
             // that check for memory safety because we have implemented Any for all types; no other
             // impls can exist as they would conflict with our impl.
-            unsafe { Some(&*(self as *const dyn Any as *const T)) }
+            unsafe { Some(self.downcast_ref_unchecked()) }
         } else {
             None
         }
@@ -257,11 +257,81 @@ pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {
             // SAFETY: just checked whether we are pointing to the correct type, and we can rely on
             // that check for memory safety because we have implemented Any for all types; no other
             // impls can exist as they would conflict with our impl.
-            unsafe { Some(&mut *(self as *mut dyn Any as *mut T)) }
+            unsafe { Some(self.downcast_mut_unchecked()) }
         } else {
             None
         }
     }
+
+    /// Returns a reference to the boxed value, blindly assuming it to be of type `T`.
+    ///
+    /// This skips the `TypeId` comparison done by [`downcast_ref`], for callers that have
+    /// already checked the type.
+    ///
+    /// # Safety
+    ///
+    /// The contained value must be of type `T`, i.e. `self.is::<T>()` must be `true`.
+    /// Calling this method with the incorrect type is *undefined behavior*.
+    ///
+    /// [`downcast_ref`]: Self::downcast_ref
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(downcast_unchecked)]
+    ///
+    /// use std::any::Any;
+    ///
+    /// let x: Box<dyn Any> = Box::new(1_usize);
+    ///
+    /// if x.is::<usize>() {
+    ///     // SAFETY: we just checked that `x` contains a `usize`.
+    ///     assert_eq!(unsafe { *x.downcast_ref_unchecked::<usize>() }, 1);
+    /// }
+    /// ```
+    #[unstable(feature = "downcast_unchecked", issue = "90850")]
+    #[inline]
+    pub unsafe fn downcast_ref_unchecked<T: Any>(&self) -> &T {
+        debug_assert!(self.is::<T>());
+        // SAFETY: the caller guarantees that `T` is the type of the value in `self`.
+        unsafe { &*(self as *const dyn Any as *const T) }
+    }
+
+    /// Returns a mutable reference to the boxed value, blindly assuming it to be of type `T`.
+    ///
+    /// This skips the `TypeId` comparison done by [`downcast_mut`], for callers that have
+    /// already checked the type.
+    ///
+    /// # Safety
+    ///
+    /// The contained value must be of type `T`, i.e. `self.is::<T>()` must be `true`.
+    /// Calling this method with the incorrect type is *undefined behavior*.
+    ///
+    /// [`downcast_mut`]: Self::downcast_mut
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(downcast_unchecked)]
+    ///
+    /// use std::any::Any;
+    ///
+    /// let mut x: Box<dyn Any> = Box::new(1_usize);
+    ///
+    /// if x.is::<usize>() {
+    ///     // SAFETY: we just checked that `x` contains a `usize`.
+    ///     unsafe { *x.downcast_mut_unchecked::<usize>() += 1 };
+    /// }
+    ///
+    /// assert_eq!(*x.downcast_ref::<usize>().unwrap(), 2);
+    /// ```
+    #[unstable(feature = "downcast_unchecked", issue = "90850")]
+    #[inline]
+    pub unsafe fn downcast_mut_unchecked<T: Any>(&mut self) -> &mut T {
+        debug_assert!(self.is::<T>());
+        // SAFETY: the caller guarantees that `T` is the type of the value in `self`.
+        unsafe { &mut *(self as *mut dyn Any as *mut T) }
+    }
 }
 
 impl dyn Any + Send {
@@ -340,6 +410,54 @@ pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
     pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {
         <dyn Any>::downcast_mut::<T>(self)
     }
+
+    /// Forwards to the method defined on the type `Any`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(downcast_unchecked)]
+    ///
+    /// use std::any::Any;
+    ///
+    /// let x: Box<dyn Any + Send> = Box::new(1_usize);
+    ///
+    /// if x.is::<usize>() {
+    ///     // SAFETY: we just checked that `x` contains a `usize`.
+    ///     assert_eq!(unsafe { *x.downcast_ref_unchecked::<usize>() }, 1);
+    /// }
+    /// ```
+    #[unstable(feature = "downcast_unchecked", issue = "90850")]
+    #[inline]
+    pub unsafe fn downcast_ref_unchecked<T: Any>(&self) -> &T {
+        // SAFETY: guaranteed by caller
+        unsafe { <dyn Any>::downcast_ref_unchecked::<T>(self) }
+    }
+
+    /// Forwards to the method defined on the type `Any`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(downcast_unchecked)]
+    ///
+    /// use std::any::Any;
+    ///
+    /// let mut x: Box<dyn Any + Send> = Box::new(1_usize);
+    ///
+    /// if x.is::<usize>() {
+    ///     // SAFETY: we just checked that `x` contains a `usize`.
+    ///     unsafe { *x.downcast_mut_unchecked::<usize>() += 1 };
+    /// }
+    ///
+    /// assert_eq!(*x.downcast_ref::<usize>().unwrap(), 2);
+    /// ```
+    #[unstable(feature = "downcast_unchecked", issue = "90850")]
+    #[inline]
+    pub unsafe fn downcast_mut_unchecked<T: Any>(&mut self) -> &mut T {
+        // SAFETY: guaranteed by caller
+        unsafe { <dyn Any>::downcast_mut_unchecked::<T>(self) }
+    }
 }
 
 impl dyn Any + Send + Sync {
@@ -418,6 +536,54 @@ pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
     pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {
         <dyn Any>::downcast_mut::<T>(self)
     }
+
+    /// Forwards to the method defined on the type `Any`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(downcast_unchecked)]
+    ///
+    /// use std::any::Any;
+    ///
+    /// let x: Box<dyn Any + Send + Sync> = Box::new(1_usize);
+    ///
+    /// if x.is::<usize>() {
+    ///     // SAFETY: we just checked that `x` contains a `usize`.
+    ///     assert_eq!(unsafe { *x.downcast_ref_unchecked::<usize>() }, 1);
+    /// }
+    /// ```
+    #[unstable(feature = "downcast_unchecked", issue = "90850")]
+    #[inline]
+    pub unsafe fn downcast_ref_unchecked<T: Any>(&self) -> &T {
+        // SAFETY: guaranteed by caller
+        unsafe { <dyn Any>::downcast_ref_unchecked::<T>(self) }
+    }
+
+    /// Forwards to the method defined on the type `Any`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(downcast_unchecked)]
+    ///
+    /// use std::any::Any;
+    ///
+    /// let mut x: Box<dyn Any + Send + Sync> = Box::new(1_usize);
+    ///
+    /// if x.is::<usize>() {
+    ///     // SAFETY: we just checked that `x` contains a `usize`.
+    ///     unsafe { *x.downcast_mut_unchecked::<usize>() += 1 };
+    /// }
+    ///
+    /// assert_eq!(*x.downcast_ref::<usize>().unwrap(), 2);
+    /// ```
+    #[unstable(feature = "downcast_unchecked", issue = "90850")]
+    #[inline]
+    pub unsafe fn downcast_mut_unchecked<T: Any>(&mut self) -> &mut T {
+        // SAFETY: guaranteed by caller
+        unsafe { <dyn Any>::downcast_mut_unchecked::<T>(self) }
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -534,6 +700,19 @@ pub const fn type_name<T: ?Sized>() -> &'static str {
 /// let y = 1.0;
 /// println!("{}", type_name_of_val(&y));
 /// ```
+///
+/// It also works for values whose type can't be written down, such as closures.
+/// Only the absence of a panic is checked here, as the exact string is unspecified:
+///
+/// ```rust
+/// #![feature(type_name_of_val)]
+/// use std::any::type_name_of_val;
+///
+/// let add_one = |x: i32| x + 1;
+/// let name = type_name_of_val(&add_one);
+/// assert!(!name.is_empty());
+/// println!("{}", name);
+/// ```
 #[unstable(feature = "type_name_of_val", issue = "66359")]
 #[rustc_const_unstable(feature = "const_type_name", issue = "63084")]
 pub const fn type_name_of_val<T: ?Sized>(_val: &T) -> &'static str {
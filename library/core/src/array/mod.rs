@@ -5,6 +5,7 @@
 #![stable(feature = "core_array", since = "1.36.0")]
 
 use crate::borrow::{Borrow, BorrowMut};
+use crate::clone::SpecCloneFrom;
 use crate::cmp::Ordering;
 use crate::convert::{Infallible, TryFrom};
 use crate::fmt;
@@ -34,6 +35,95 @@
     unsafe { &mut *(s as *mut T).cast::<[T; 1]>() }
 }
 
+/// Creates an array `[T; N]` where each array element `T` is returned by the `cb` call.
+///
+/// The closure is called with indices in ascending order. If it panics, the
+/// elements created so far are dropped before the panic propagates.
+///
+/// # Arguments
+///
+/// * `cb`: Callback where the passed argument is the current array index.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(array_from_fn)]
+///
+/// let array = core::array::from_fn(|i| i);
+/// assert_eq!(array, [0, 1, 2, 3, 4]);
+/// ```
+///
+/// Building a lookup table of squares:
+///
+/// ```rust
+/// #![feature(array_from_fn)]
+///
+/// let squares: [u32; 8] = core::array::from_fn(|i| (i * i) as u32);
+/// assert_eq!(squares, [0, 1, 4, 9, 16, 25, 36, 49]);
+/// assert_eq!(squares[5], 25);
+/// ```
+#[inline]
+#[unstable(feature = "array_from_fn", issue = "89379")]
+pub fn from_fn<F, T, const N: usize>(cb: F) -> [T; N]
+where
+    F: FnMut(usize) -> T,
+{
+    let mut map = (0..N).map(cb);
+    // SAFETY: `0..N` yields exactly `N` items.
+    unsafe { collect_into_array_unchecked(&mut map) }
+}
+
+/// Creates an array `[T; N]` where each fallible array element `T` is returned by the `cb` call.
+///
+/// The closure is called with indices in ascending order. If it returns an `Err`, the
+/// construction stops, the elements created so far are dropped and the error is returned.
+///
+/// # Arguments
+///
+/// * `cb`: Callback where the passed argument is the current array index.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(array_try_from_fn)]
+///
+/// #[derive(Debug, PartialEq)]
+/// enum SomeError {
+///     Foo,
+/// }
+///
+/// let array = core::array::try_from_fn(|i| Ok::<_, SomeError>(i));
+/// assert_eq!(array, Ok([0, 1, 2, 3, 4]));
+///
+/// let another_array = core::array::try_from_fn::<SomeError, _, (), 2>(|_| Err(SomeError::Foo));
+/// assert_eq!(another_array, Err(SomeError::Foo));
+/// ```
+///
+/// Closures returning an `Option` can be adapted with [`Option::ok_or`]:
+///
+/// ```rust
+/// #![feature(array_try_from_fn)]
+///
+/// let digits = "1234".as_bytes();
+/// let array: Option<[u32; 4]> =
+///     core::array::try_from_fn(|i| (digits[i] as char).to_digit(10).ok_or(())).ok();
+/// assert_eq!(array, Some([1, 2, 3, 4]));
+/// ```
+#[inline]
+#[unstable(feature = "array_try_from_fn", issue = "89379")]
+pub fn try_from_fn<E, F, T, const N: usize>(cb: F) -> Result<[T; N], E>
+where
+    F: FnMut(usize) -> Result<T, E>,
+{
+    let mut map = (0..N).map(cb);
+    match try_collect_into_array(&mut map) {
+        Ok(result) => result,
+        // SAFETY: `0..N` yields exactly `N` items, so the array is always filled
+        // unless the callback returns an error.
+        Err(_) => unsafe { crate::hint::unreachable_unchecked() },
+    }
+}
+
 /// The error type returned when a conversion from a slice to an array fails.
 #[stable(feature = "try_from", since = "1.34.0")]
 #[derive(Debug, Copy, Clone)]
@@ -264,6 +354,23 @@ fn index_mut(&mut self, index: I) -> &mut Self::Output {
     }
 }
 
+/// Arrays get their `Clone` impl from the compiler, which doesn't override
+/// `clone_from`, so the default `Clone::clone_from` lets them go through
+/// `clone_from_slice`. That reuses the resources of the existing elements, and
+/// becomes a single `copy_from_slice` when `T: Copy`.
+impl<T: MarkerClone, const N: usize> SpecCloneFrom for [T; N] {
+    #[inline]
+    fn spec_clone_from(&mut self, source: &Self) {
+        self.clone_from_slice(source);
+    }
+}
+
+// Hack to allow specializing on `Clone` even though `Clone` has a method.
+#[rustc_unsafe_specialization_marker]
+trait MarkerClone: Clone {}
+
+impl<T: Clone> MarkerClone for T {}
+
 // The Default impls cannot be done with const generics because `[T; 0]` doesn't
 // require Default to be implemented, and having different impl blocks for
 // different numbers isn't supported yet.
@@ -413,6 +520,165 @@ pub fn as_mut_slice(&mut self) -> &mut [T] {
         // items.
         unsafe { collect_into_array_unchecked(&mut self.iter_mut()) }
     }
+
+    /// Divides one array reference into two at an index.
+    ///
+    /// The first will contain all indices from `[0, M)` (excluding
+    /// the index `M` itself) and the second will contain all
+    /// indices from `[M, N)` (excluding the index `N` itself).
+    ///
+    /// # Errors
+    ///
+    /// Fails to compile if `M > N`, at the point where the method is
+    /// instantiated with concrete lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(split_array)]
+    ///
+    /// let v = [1, 2, 3, 4, 5, 6];
+    ///
+    /// {
+    ///    let (left, right) = v.split_array_ref::<0>();
+    ///    assert_eq!(left, &[]);
+    ///    assert_eq!(right, &[1, 2, 3, 4, 5, 6]);
+    /// }
+    ///
+    /// {
+    ///     let (left, right) = v.split_array_ref::<2>();
+    ///     assert_eq!(left, &[1, 2]);
+    ///     assert_eq!(right, &[3, 4, 5, 6]);
+    /// }
+    ///
+    /// {
+    ///     let (left, right) = v.split_array_ref::<6>();
+    ///     assert_eq!(left, &[1, 2, 3, 4, 5, 6]);
+    ///     assert_eq!(right, &[]);
+    /// }
+    /// ```
+    #[unstable(feature = "split_array", reason = "new API", issue = "90091")]
+    #[inline]
+    pub fn split_array_ref<const M: usize>(&self) -> (&[T; M], &[T]) {
+        let () = SplitArrayCheck::<N, M>::OK;
+        (&self[..]).split_array_ref::<M>()
+    }
+
+    /// Divides one mutable array reference into two at an index.
+    ///
+    /// The first will contain all indices from `[0, M)` (excluding
+    /// the index `M` itself) and the second will contain all
+    /// indices from `[M, N)` (excluding the index `N` itself).
+    ///
+    /// # Errors
+    ///
+    /// Fails to compile if `M > N`, at the point where the method is
+    /// instantiated with concrete lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(split_array)]
+    ///
+    /// let mut v = [1, 0, 3, 0, 5, 6];
+    /// let (left, right) = v.split_array_mut::<2>();
+    /// assert_eq!(left, &mut [1, 0][..]);
+    /// assert_eq!(right, &mut [3, 0, 5, 6]);
+    /// left[1] = 2;
+    /// right[1] = 4;
+    /// assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[unstable(feature = "split_array", reason = "new API", issue = "90091")]
+    #[inline]
+    pub fn split_array_mut<const M: usize>(&mut self) -> (&mut [T; M], &mut [T]) {
+        let () = SplitArrayCheck::<N, M>::OK;
+        (&mut self[..]).split_array_mut::<M>()
+    }
+
+    /// Divides one array reference into two at an index from the end.
+    ///
+    /// The first will contain all indices from `[0, N - M)` (excluding
+    /// the index `N - M` itself) and the second will contain all
+    /// indices from `[N - M, N)` (excluding the index `N` itself).
+    ///
+    /// # Errors
+    ///
+    /// Fails to compile if `M > N`, at the point where the method is
+    /// instantiated with concrete lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(split_array)]
+    ///
+    /// let v = [1, 2, 3, 4, 5, 6];
+    ///
+    /// {
+    ///    let (left, right) = v.rsplit_array_ref::<0>();
+    ///    assert_eq!(left, &[1, 2, 3, 4, 5, 6]);
+    ///    assert_eq!(right, &[]);
+    /// }
+    ///
+    /// {
+    ///     let (left, right) = v.rsplit_array_ref::<2>();
+    ///     assert_eq!(left, &[1, 2, 3, 4]);
+    ///     assert_eq!(right, &[5, 6]);
+    /// }
+    ///
+    /// {
+    ///     let (left, right) = v.rsplit_array_ref::<6>();
+    ///     assert_eq!(left, &[]);
+    ///     assert_eq!(right, &[1, 2, 3, 4, 5, 6]);
+    /// }
+    /// ```
+    #[unstable(feature = "split_array", reason = "new API", issue = "90091")]
+    #[inline]
+    pub fn rsplit_array_ref<const M: usize>(&self) -> (&[T], &[T; M]) {
+        let () = SplitArrayCheck::<N, M>::OK;
+        (&self[..]).rsplit_array_ref::<M>()
+    }
+
+    /// Divides one mutable array reference into two at an index from the end.
+    ///
+    /// The first will contain all indices from `[0, N - M)` (excluding
+    /// the index `N - M` itself) and the second will contain all
+    /// indices from `[N - M, N)` (excluding the index `N` itself).
+    ///
+    /// # Errors
+    ///
+    /// Fails to compile if `M > N`, at the point where the method is
+    /// instantiated with concrete lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(split_array)]
+    ///
+    /// let mut v = [1, 0, 3, 0, 5, 6];
+    /// let (left, right) = v.rsplit_array_mut::<4>();
+    /// assert_eq!(left, &mut [1, 0]);
+    /// assert_eq!(right, &mut [3, 0, 5, 6][..]);
+    /// left[1] = 2;
+    /// right[1] = 4;
+    /// assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[unstable(feature = "split_array", reason = "new API", issue = "90091")]
+    #[inline]
+    pub fn rsplit_array_mut<const M: usize>(&mut self) -> (&mut [T], &mut [T; M]) {
+        let () = SplitArrayCheck::<N, M>::OK;
+        (&mut self[..]).rsplit_array_mut::<M>()
+    }
+}
+
+/// Compile-time bounds check for the `split_array` methods on arrays.
+///
+/// Evaluating `OK` fails const evaluation when a `[T; N]` is split at `M > N`,
+/// which turns what would otherwise be a guaranteed runtime panic into an
+/// error when the method is monomorphized.
+struct SplitArrayCheck<const N: usize, const M: usize>;
+
+impl<const N: usize, const M: usize> SplitArrayCheck<N, M> {
+    const OK: () = assert!(M <= N, "split index is out of bounds for the array length");
 }
 
 /// Pulls `N` items from `iter` and returns them as an array. If the iterator
@@ -437,15 +703,15 @@ pub fn as_mut_slice(&mut self) -> &mut [T] {
     debug_assert!(N <= iter.size_hint().0);
 
     match collect_into_array(iter) {
-        Some(array) => array,
+        Ok(array) => array,
         // SAFETY: covered by the function contract.
-        None => unsafe { crate::hint::unreachable_unchecked() },
+        Err(_) => unsafe { crate::hint::unreachable_unchecked() },
     }
 }
 
 /// Pulls `N` items from `iter` and returns them as an array. If the iterator
-/// yields fewer than `N` items, `None` is returned and all already yielded
-/// items are dropped.
+/// yields fewer than `N` items, `Err` is returned containing an iterator over
+/// the already yielded items.
 ///
 /// Since the iterator is passed as a mutable reference and this function calls
 /// `next` at most `N` times, the iterator can still be used afterwards to
@@ -453,13 +719,40 @@ pub fn as_mut_slice(&mut self) -> &mut [T] {
 ///
 /// If `iter.next()` panicks, all items already yielded by the iterator are
 /// dropped.
-fn collect_into_array<I, const N: usize>(iter: &mut I) -> Option<[I::Item; N]>
+fn collect_into_array<I, const N: usize>(
+    iter: &mut I,
+) -> Result<[I::Item; N], IntoIter<I::Item, N>>
 where
     I: Iterator,
+{
+    let mut map = iter.map(Ok::<_, Infallible>);
+    match try_collect_into_array(&mut map) {
+        Ok(Ok(array)) => Ok(array),
+        Ok(Err(infallible)) => match infallible {},
+        Err(partial) => Err(partial),
+    }
+}
+
+/// Pulls `N` items from `iter` and returns them as an array. If the iterator
+/// yields an `Err` item, all already yielded items are dropped and `Ok(Err(_))`
+/// is returned. If the iterator yields fewer than `N` items, `Err` is returned
+/// containing an iterator over the already yielded items.
+///
+/// Since the iterator is passed as a mutable reference and this function calls
+/// `next` at most `N` times, the iterator can still be used afterwards to
+/// retrieve the remaining items.
+///
+/// If `iter.next()` panicks, all items already yielded by the iterator are
+/// dropped.
+fn try_collect_into_array<I, T, E, const N: usize>(
+    iter: &mut I,
+) -> Result<Result<[T; N], E>, IntoIter<T, N>>
+where
+    I: Iterator<Item = Result<T, E>>,
 {
     if N == 0 {
         // SAFETY: An empty array is always inhabited and has no validity invariants.
-        return unsafe { Some(mem::zeroed()) };
+        return unsafe { Ok(Ok(mem::zeroed())) };
     }
 
     struct Guard<T, const N: usize> {
@@ -484,7 +777,16 @@ fn drop(&mut self) {
     let mut guard: Guard<_, N> =
         Guard { ptr: MaybeUninit::slice_as_mut_ptr(&mut array), initialized: 0 };
 
-    while let Some(item) = iter.next() {
+    while let Some(item_rslt) = iter.next() {
+        let item = match item_rslt {
+            Err(err) => {
+                // `guard` is dropped here, dropping all already initialized
+                // elements.
+                return Ok(Err(err));
+            }
+            Ok(elem) => elem,
+        };
+
         // SAFETY: `guard.initialized` starts at 0, is increased by one in the
         // loop and the loop is aborted once it reaches N (which is
         // `array.len()`).
@@ -500,12 +802,31 @@ fn drop(&mut self) {
             // SAFETY: the condition above asserts that all elements are
             // initialized.
             let out = unsafe { MaybeUninit::array_assume_init(array) };
-            return Some(out);
+            return Ok(Ok(out));
         }
     }
 
     // This is only reached if the iterator is exhausted before
-    // `guard.initialized` reaches `N`. Also note that `guard` is dropped here,
-    // dropping all already initialized elements.
-    None
+    // `guard.initialized` reaches `N`. Ownership of the already initialized
+    // elements is handed over to the returned iterator.
+    let initialized = guard.initialized;
+    mem::forget(guard);
+
+    // SAFETY: the elements in `array[..initialized]` have been written by the
+    // loop above, and `initialized < N`.
+    Err(unsafe { IntoIter::new_unchecked(array, 0..initialized) })
+}
+
+/// Pulls `N` items from `iter` and returns them as an array. If the iterator
+/// yields fewer than `N` items, `Err` is returned containing an iterator over
+/// the already yielded items.
+///
+/// This is the implementation of [`Iterator::next_chunk`].
+pub(crate) fn iter_next_chunk<I, const N: usize>(
+    iter: &mut I,
+) -> Result<[I::Item; N], IntoIter<I::Item, N>>
+where
+    I: Iterator,
+{
+    collect_into_array(iter)
 }
@@ -0,0 +1,404 @@
+//! Defines the `ascii::Char` type, kept in its own file because of the
+//! length of its variant list.
+
+use crate::fmt;
+use crate::mem::transmute;
+use crate::slice;
+
+/// One of the 128 Unicode characters from U+0000 through U+007F,
+/// often known as the [ASCII] subset.
+///
+/// Officially, this is the first [block] in Unicode, _Basic Latin_.
+/// For details, see the [*C0 Controls and Basic Latin*][chart] code chart.
+///
+/// # When to use this
+///
+/// The main advantage of this subset is that it's always valid UTF-8.  As such,
+/// the `&[ascii::Char]` -> `&str` conversion function (as well as other related
+/// ones) are O(1): *no* runtime checks are needed.
+///
+/// If you're consuming strings, you should usually handle Unicode and thus
+/// accept `str`s, not limit yourself to `ascii::Char`s.
+///
+/// However, certain formats are intentionally designed to produce ASCII-only
+/// output in order to be 8-bit-clean.  In those cases, it can be simpler and
+/// faster to generate `ascii::Char`s instead of dealing with the variable width
+/// properties of general UTF-8 encoded strings, while still allowing the result
+/// to be used freely with other Rust things that deal in general `str`s.
+///
+/// For example, a UUID library might offer a way to produce the string
+/// representation of a UUID as an `[ascii::Char; 36]` to avoid memory
+/// allocation yet still allow it to be used as UTF-8 via `as_str` without
+/// paying for validation (or needing `unsafe` code) the way it would if it
+/// were provided as a `[u8; 36]`.
+///
+/// # Layout
+///
+/// This type is guaranteed to have a size and alignment of 1 byte, and the
+/// byte values 128 through 255 are never valid for it, so
+/// `Option<ascii::Char>` is also a single byte.
+///
+/// # Names
+///
+/// The variants on this type are [Unicode names][NamesList] of the characters
+/// in upper camel case, with a few tweaks:
+/// - For `<control>` characters, the primary alias name is used.
+/// - `LATIN` is dropped, as this block has no non-latin letters.
+/// - `LETTER` is dropped, as `CAPITAL`/`SMALL` suffices in this block.
+/// - `DIGIT`s use a single digit rather than writing out `ZERO`, `ONE`, etc.
+///
+/// [ASCII]: https://www.unicode.org/glossary/index.html#ASCII
+/// [block]: https://www.unicode.org/glossary/index.html#block
+/// [chart]: https://www.unicode.org/charts/PDF/U0000.pdf
+/// [NamesList]: https://www.unicode.org/Public/15.0.0/ucd/NamesList.txt
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[unstable(feature = "ascii_char", issue = "110998")]
+#[repr(u8)]
+pub enum AsciiChar {
+    /// U+0000 (NUL)
+    Null = 0,
+    /// U+0001 (SOH)
+    StartOfHeading = 1,
+    /// U+0002 (STX)
+    StartOfText = 2,
+    /// U+0003 (ETX)
+    EndOfText = 3,
+    /// U+0004 (EOT)
+    EndOfTransmission = 4,
+    /// U+0005 (ENQ)
+    Enquiry = 5,
+    /// U+0006 (ACK)
+    Acknowledge = 6,
+    /// U+0007 (BEL)
+    Bell = 7,
+    /// U+0008 (BS)
+    Backspace = 8,
+    /// U+0009 (HT)
+    CharacterTabulation = 9,
+    /// U+000A (LF)
+    LineFeed = 10,
+    /// U+000B (VT)
+    LineTabulation = 11,
+    /// U+000C (FF)
+    FormFeed = 12,
+    /// U+000D (CR)
+    CarriageReturn = 13,
+    /// U+000E (SO)
+    ShiftOut = 14,
+    /// U+000F (SI)
+    ShiftIn = 15,
+    /// U+0010 (DLE)
+    DataLinkEscape = 16,
+    /// U+0011 (DC1)
+    DeviceControlOne = 17,
+    /// U+0012 (DC2)
+    DeviceControlTwo = 18,
+    /// U+0013 (DC3)
+    DeviceControlThree = 19,
+    /// U+0014 (DC4)
+    DeviceControlFour = 20,
+    /// U+0015 (NAK)
+    NegativeAcknowledge = 21,
+    /// U+0016 (SYN)
+    SynchronousIdle = 22,
+    /// U+0017 (ETB)
+    EndOfTransmissionBlock = 23,
+    /// U+0018 (CAN)
+    Cancel = 24,
+    /// U+0019 (EM)
+    EndOfMedium = 25,
+    /// U+001A (SUB)
+    Substitute = 26,
+    /// U+001B (ESC)
+    Escape = 27,
+    /// U+001C (FS)
+    InformationSeparatorFour = 28,
+    /// U+001D (GS)
+    InformationSeparatorThree = 29,
+    /// U+001E (RS)
+    InformationSeparatorTwo = 30,
+    /// U+001F (US)
+    InformationSeparatorOne = 31,
+    /// U+0020 (space)
+    Space = 32,
+    /// U+0021 (`!`)
+    ExclamationMark = 33,
+    /// U+0022 (`"`)
+    QuotationMark = 34,
+    /// U+0023 (`#`)
+    NumberSign = 35,
+    /// U+0024 (`$`)
+    DollarSign = 36,
+    /// U+0025 (`%`)
+    PercentSign = 37,
+    /// U+0026 (`&`)
+    Ampersand = 38,
+    /// U+0027 (`'`)
+    Apostrophe = 39,
+    /// U+0028 (`(`)
+    LeftParenthesis = 40,
+    /// U+0029 (`)`)
+    RightParenthesis = 41,
+    /// U+002A (`*`)
+    Asterisk = 42,
+    /// U+002B (`+`)
+    PlusSign = 43,
+    /// U+002C (`,`)
+    Comma = 44,
+    /// U+002D (`-`)
+    HyphenMinus = 45,
+    /// U+002E (`.`)
+    FullStop = 46,
+    /// U+002F (`/`)
+    Solidus = 47,
+    /// U+0030 (`0`)
+    Digit0 = 48,
+    /// U+0031 (`1`)
+    Digit1 = 49,
+    /// U+0032 (`2`)
+    Digit2 = 50,
+    /// U+0033 (`3`)
+    Digit3 = 51,
+    /// U+0034 (`4`)
+    Digit4 = 52,
+    /// U+0035 (`5`)
+    Digit5 = 53,
+    /// U+0036 (`6`)
+    Digit6 = 54,
+    /// U+0037 (`7`)
+    Digit7 = 55,
+    /// U+0038 (`8`)
+    Digit8 = 56,
+    /// U+0039 (`9`)
+    Digit9 = 57,
+    /// U+003A (`:`)
+    Colon = 58,
+    /// U+003B (`;`)
+    Semicolon = 59,
+    /// U+003C (`<`)
+    LessThanSign = 60,
+    /// U+003D (`=`)
+    EqualsSign = 61,
+    /// U+003E (`>`)
+    GreaterThanSign = 62,
+    /// U+003F (`?`)
+    QuestionMark = 63,
+    /// U+0040 (`@`)
+    CommercialAt = 64,
+    /// U+0041 (`A`)
+    CapitalA = 65,
+    /// U+0042 (`B`)
+    CapitalB = 66,
+    /// U+0043 (`C`)
+    CapitalC = 67,
+    /// U+0044 (`D`)
+    CapitalD = 68,
+    /// U+0045 (`E`)
+    CapitalE = 69,
+    /// U+0046 (`F`)
+    CapitalF = 70,
+    /// U+0047 (`G`)
+    CapitalG = 71,
+    /// U+0048 (`H`)
+    CapitalH = 72,
+    /// U+0049 (`I`)
+    CapitalI = 73,
+    /// U+004A (`J`)
+    CapitalJ = 74,
+    /// U+004B (`K`)
+    CapitalK = 75,
+    /// U+004C (`L`)
+    CapitalL = 76,
+    /// U+004D (`M`)
+    CapitalM = 77,
+    /// U+004E (`N`)
+    CapitalN = 78,
+    /// U+004F (`O`)
+    CapitalO = 79,
+    /// U+0050 (`P`)
+    CapitalP = 80,
+    /// U+0051 (`Q`)
+    CapitalQ = 81,
+    /// U+0052 (`R`)
+    CapitalR = 82,
+    /// U+0053 (`S`)
+    CapitalS = 83,
+    /// U+0054 (`T`)
+    CapitalT = 84,
+    /// U+0055 (`U`)
+    CapitalU = 85,
+    /// U+0056 (`V`)
+    CapitalV = 86,
+    /// U+0057 (`W`)
+    CapitalW = 87,
+    /// U+0058 (`X`)
+    CapitalX = 88,
+    /// U+0059 (`Y`)
+    CapitalY = 89,
+    /// U+005A (`Z`)
+    CapitalZ = 90,
+    /// U+005B (`[`)
+    LeftSquareBracket = 91,
+    /// U+005C (`\`)
+    ReverseSolidus = 92,
+    /// U+005D (`]`)
+    RightSquareBracket = 93,
+    /// U+005E (`^`)
+    CircumflexAccent = 94,
+    /// U+005F (`_`)
+    LowLine = 95,
+    /// U+0060 (grave accent)
+    GraveAccent = 96,
+    /// U+0061 (`a`)
+    Smalla = 97,
+    /// U+0062 (`b`)
+    Smallb = 98,
+    /// U+0063 (`c`)
+    Smallc = 99,
+    /// U+0064 (`d`)
+    Smalld = 100,
+    /// U+0065 (`e`)
+    Smalle = 101,
+    /// U+0066 (`f`)
+    Smallf = 102,
+    /// U+0067 (`g`)
+    Smallg = 103,
+    /// U+0068 (`h`)
+    Smallh = 104,
+    /// U+0069 (`i`)
+    Smalli = 105,
+    /// U+006A (`j`)
+    Smallj = 106,
+    /// U+006B (`k`)
+    Smallk = 107,
+    /// U+006C (`l`)
+    Smalll = 108,
+    /// U+006D (`m`)
+    Smallm = 109,
+    /// U+006E (`n`)
+    Smalln = 110,
+    /// U+006F (`o`)
+    Smallo = 111,
+    /// U+0070 (`p`)
+    Smallp = 112,
+    /// U+0071 (`q`)
+    Smallq = 113,
+    /// U+0072 (`r`)
+    Smallr = 114,
+    /// U+0073 (`s`)
+    Smalls = 115,
+    /// U+0074 (`t`)
+    Smallt = 116,
+    /// U+0075 (`u`)
+    Smallu = 117,
+    /// U+0076 (`v`)
+    Smallv = 118,
+    /// U+0077 (`w`)
+    Smallw = 119,
+    /// U+0078 (`x`)
+    Smallx = 120,
+    /// U+0079 (`y`)
+    Smally = 121,
+    /// U+007A (`z`)
+    Smallz = 122,
+    /// U+007B (`{`)
+    LeftCurlyBracket = 123,
+    /// U+007C (`|`)
+    VerticalLine = 124,
+    /// U+007D (`}`)
+    RightCurlyBracket = 125,
+    /// U+007E (`~`)
+    Tilde = 126,
+    /// U+007F (DEL)
+    Delete = 127,
+}
+
+impl AsciiChar {
+    /// Creates an ascii character from the byte `b`,
+    /// or returns `None` if it's too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char)]
+    /// use std::ascii;
+    ///
+    /// assert_eq!(ascii::Char::from_u8(b'a').map(ascii::Char::to_char), Some('a'));
+    /// assert_eq!(ascii::Char::from_u8(0x80), None);
+    /// ```
+    #[unstable(feature = "ascii_char", issue = "110998")]
+    #[inline]
+    pub const fn from_u8(b: u8) -> Option<Self> {
+        if b <= 127 {
+            // SAFETY: Just checked that `b` is in-range
+            Some(unsafe { Self::from_u8_unchecked(b) })
+        } else {
+            None
+        }
+    }
+
+    /// Creates an ASCII character from the byte `b`,
+    /// without checking whether it's valid.
+    ///
+    /// # Safety
+    ///
+    /// `b` must be in `0..=127`, or else this is UB.
+    #[unstable(feature = "ascii_char", issue = "110998")]
+    #[inline]
+    pub const unsafe fn from_u8_unchecked(b: u8) -> Self {
+        // SAFETY: Our safety precondition is that `b` is in-range.
+        unsafe { transmute(b) }
+    }
+
+    /// Gets this ASCII character as a byte.
+    #[unstable(feature = "ascii_char", issue = "110998")]
+    #[inline]
+    pub const fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Gets this ASCII character as a `char` Unicode Scalar Value.
+    #[unstable(feature = "ascii_char", issue = "110998")]
+    #[inline]
+    pub const fn to_char(self) -> char {
+        self as u8 as char
+    }
+
+    /// Views a slice of ASCII characters as a UTF-8 `str`.
+    ///
+    /// This cannot fail and does no validation: every ASCII character is
+    /// encoded in UTF-8 as the single byte with the same value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char)]
+    /// use std::ascii;
+    ///
+    /// let hex = [ascii::Char::Digit0, ascii::Char::SmallX, ascii::Char::CapitalF];
+    /// assert_eq!(ascii::Char::as_str(&hex), "0xF");
+    /// ```
+    #[unstable(feature = "ascii_char", issue = "110998")]
+    #[inline]
+    pub const fn as_str(chars: &[Self]) -> &str {
+        let ascii_ptr: *const [Self] = chars;
+        let str_ptr = ascii_ptr as *const str;
+        // SAFETY: Each ASCII codepoint in UTF-8 is encoded as one single-byte
+        // code unit having the same value as the ASCII byte.
+        unsafe { &*str_ptr }
+    }
+}
+
+#[unstable(feature = "ascii_char", issue = "110998")]
+impl fmt::Display for AsciiChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(Self::as_str(slice::from_ref(self)), f)
+    }
+}
+
+#[unstable(feature = "ascii_char", issue = "110998")]
+impl fmt::Debug for AsciiChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_char(), f)
+    }
+}
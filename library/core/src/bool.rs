@@ -4,6 +4,12 @@
 impl bool {
     /// Returns `Some(t)` if the `bool` is `true`, or `None` otherwise.
     ///
+    /// Arguments passed to `then_some` are eagerly evaluated; if you are
+    /// passing the result of a function call, it is recommended to use
+    /// [`then`], which is lazily evaluated.
+    ///
+    /// [`then`]: bool::then
+    ///
     /// # Examples
     ///
     /// ```
@@ -12,6 +18,24 @@ impl bool {
     /// assert_eq!(false.then_some(0), None);
     /// assert_eq!(true.then_some(0), Some(0));
     /// ```
+    ///
+    /// Unlike with [`then`], the value is computed even if the `bool` is
+    /// `false`:
+    ///
+    /// ```
+    /// #![feature(bool_to_option)]
+    ///
+    /// let mut a = 0;
+    /// let mut function_with_side_effects = || { a += 1; };
+    ///
+    /// true.then_some(function_with_side_effects());
+    /// false.then_some(function_with_side_effects());
+    /// false.then(|| function_with_side_effects());
+    ///
+    /// // `a` is incremented twice because the value passed to `then_some` is
+    /// // evaluated eagerly, but the closure passed to `then` is never called.
+    /// assert_eq!(a, 2);
+    /// ```
     #[unstable(feature = "bool_to_option", issue = "80967")]
     #[inline]
     pub fn then_some<T>(self, t: T) -> Option<T> {
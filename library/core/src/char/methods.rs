@@ -1,5 +1,7 @@
 //! impl char {}
 
+use crate::ascii;
+use crate::ptr;
 use crate::slice;
 use crate::str::from_utf8_unchecked_mut;
 use crate::unicode::printable::is_printable;
@@ -41,6 +43,41 @@ impl char {
     #[stable(feature = "assoc_char_consts", since = "1.52.0")]
     pub const UNICODE_VERSION: (u8, u8, u8) = crate::unicode::UNICODE_VERSION;
 
+    /// The maximum number of bytes required to [encode](char::encode_utf8) a `char` to
+    /// UTF-8 encoding.
+    ///
+    /// A buffer of this length is large enough for [`encode_utf8`](char::encode_utf8) to
+    /// encode any `char`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(char_max_len)]
+    ///
+    /// let mut buf = [0; char::MAX_LEN_UTF8];
+    /// assert_eq!('💣'.encode_utf8(&mut buf), "💣");
+    /// assert_eq!(char::MAX.len_utf8(), char::MAX_LEN_UTF8);
+    /// ```
+    #[unstable(feature = "char_max_len", issue = "121714")]
+    pub const MAX_LEN_UTF8: usize = 4;
+
+    /// The maximum number of two-byte units required to [encode](char::encode_utf16) a `char`
+    /// to UTF-16 encoding.
+    ///
+    /// A buffer of this length is large enough for [`encode_utf16`](char::encode_utf16) to
+    /// encode any `char`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(char_max_len)]
+    ///
+    /// let mut buf = [0; char::MAX_LEN_UTF16];
+    /// assert_eq!('💣'.encode_utf16(&mut buf).len(), char::MAX_LEN_UTF16);
+    /// ```
+    #[unstable(feature = "char_max_len", issue = "121714")]
+    pub const MAX_LEN_UTF16: usize = 2;
+
     /// Creates an iterator over the UTF-16 encoded code points in `iter`,
     /// returning unpaired surrogates as `Err`s.
     ///
@@ -630,9 +667,23 @@ pub const fn len_utf16(self) -> usize {
     /// // this panics
     /// 'ß'.encode_utf8(&mut b);
     /// ```
+    ///
+    /// Encoding at compile time:
+    ///
+    /// ```
+    /// #![feature(const_char_encode_utf8)]
+    ///
+    /// const CRAB: [u8; 4] = {
+    ///     let mut buf = [0; 4];
+    ///     '🦀'.encode_utf8(&mut buf);
+    ///     buf
+    /// };
+    /// assert_eq!(CRAB, *"🦀".as_bytes());
+    /// ```
     #[stable(feature = "unicode_encode_char", since = "1.15.0")]
+    #[rustc_const_unstable(feature = "const_char_encode_utf8", issue = "130512")]
     #[inline]
-    pub fn encode_utf8(self, dst: &mut [u8]) -> &mut str {
+    pub const fn encode_utf8(self, dst: &mut [u8]) -> &mut str {
         // SAFETY: `char` is not a surrogate, so this is valid UTF-8.
         unsafe { from_utf8_unchecked_mut(encode_utf8_raw(self as u32, dst)) }
     }
@@ -1045,6 +1096,41 @@ pub fn to_uppercase(self) -> ToUppercase {
         ToUppercase(CaseMappingIter::new(conversions::to_upper(self)))
     }
 
+    /// Returns the simple case folding of this `char`.
+    ///
+    /// Case folding maps characters that differ only in case to a common form, so that
+    /// comparing folded characters compares them case-insensitively. This uses the simple
+    /// (`C` + `S`) mappings of the Unicode [`CaseFolding.txt`] file, which always produce a
+    /// single `char`; full foldings that expand to several characters, like `'ß'` to `"ss"`,
+    /// are not applied. The mapping does not depend on the locale, so the Turkic dotted and
+    /// dotless `i` are not folded together.
+    ///
+    /// Characters without a folding map to themselves.
+    ///
+    /// [`CaseFolding.txt`]: https://www.unicode.org/Public/UCD/latest/ucd/CaseFolding.txt
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_eq_ignore_case)]
+    ///
+    /// assert_eq!('A'.to_simple_case_folded(), 'a');
+    /// assert_eq!('Σ'.to_simple_case_folded(), 'σ');
+    /// // Final sigma folds to the same form as the other lowercase sigma.
+    /// assert_eq!('ς'.to_simple_case_folded(), 'σ');
+    /// // The Kelvin sign folds to an ASCII letter.
+    /// assert_eq!('\u{212A}'.to_simple_case_folded(), 'k');
+    ///
+    /// assert_eq!('ß'.to_simple_case_folded(), 'ß');
+    /// assert_eq!('İ'.to_simple_case_folded(), 'İ');
+    /// assert_eq!('山'.to_simple_case_folded(), '山');
+    /// ```
+    #[unstable(feature = "str_eq_ignore_case", issue = "none")]
+    #[inline]
+    pub fn to_simple_case_folded(self) -> char {
+        conversions::to_simple_fold(self)
+    }
+
     /// Checks if the value is within the ASCII range.
     ///
     /// # Examples
@@ -1063,6 +1149,33 @@ pub const fn is_ascii(&self) -> bool {
         *self as u32 <= 0x7F
     }
 
+    /// Returns `Some` if the value is within the ASCII range,
+    /// or `None` if it's not.
+    ///
+    /// This is preferred to [`Self::is_ascii`] when you're passing the value
+    /// along to something else that can take [`ascii::Char`] rather than
+    /// needing to check again for itself whether the value is in ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char)]
+    /// use std::ascii;
+    ///
+    /// assert_eq!('~'.as_ascii(), Some(ascii::Char::Tilde));
+    /// assert_eq!('❤'.as_ascii(), None);
+    /// ```
+    #[unstable(feature = "ascii_char", issue = "110998")]
+    #[inline]
+    pub const fn as_ascii(&self) -> Option<ascii::Char> {
+        if self.is_ascii() {
+            // SAFETY: Just checked that this is ASCII.
+            Some(unsafe { ascii::Char::from_u8_unchecked(*self as u8) })
+        } else {
+            None
+        }
+    }
+
     /// Makes a copy of the value in its ASCII upper case equivalent.
     ///
     /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z',
@@ -1371,6 +1484,37 @@ pub const fn is_ascii_digit(&self) -> bool {
         matches!(*self, '0'..='9')
     }
 
+    /// Checks if the value is an ASCII octal digit:
+    /// U+0030 '0' ..= U+0037 '7'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(is_ascii_octdigit)]
+    ///
+    /// let uppercase_a = 'A';
+    /// let a = 'a';
+    /// let zero = '0';
+    /// let seven = '7';
+    /// let nine = '9';
+    /// let percent = '%';
+    /// let lf = '\n';
+    ///
+    /// assert!(!uppercase_a.is_ascii_octdigit());
+    /// assert!(!a.is_ascii_octdigit());
+    /// assert!(zero.is_ascii_octdigit());
+    /// assert!(seven.is_ascii_octdigit());
+    /// assert!(!nine.is_ascii_octdigit());
+    /// assert!(!percent.is_ascii_octdigit());
+    /// assert!(!lf.is_ascii_octdigit());
+    /// ```
+    #[unstable(feature = "is_ascii_octdigit", issue = "101288")]
+    #[rustc_const_unstable(feature = "is_ascii_octdigit", issue = "101288")]
+    #[inline]
+    pub const fn is_ascii_octdigit(&self) -> bool {
+        matches!(*self, '0'..='7')
+    }
+
     /// Checks if the value is an ASCII hexadecimal digit:
     ///
     /// - U+0030 '0' ..= U+0039 '9', or
@@ -1609,11 +1753,12 @@ const fn len_utf8(code: u32) -> usize {
 /// Panics if the buffer is not large enough.
 /// A buffer of length four is large enough to encode any `char`.
 #[unstable(feature = "char_internals", reason = "exposed only for libstd", issue = "none")]
+#[rustc_const_unstable(feature = "const_char_encode_utf8", issue = "130512")]
 #[doc(hidden)]
 #[inline]
-pub fn encode_utf8_raw(code: u32, dst: &mut [u8]) -> &mut [u8] {
+pub const fn encode_utf8_raw(code: u32, dst: &mut [u8]) -> &mut [u8] {
     let len = len_utf8(code);
-    match (len, &mut dst[..]) {
+    match (len, &mut *dst) {
         (1, [a, ..]) => {
             *a = code as u8;
         }
@@ -1632,14 +1777,12 @@ pub fn encode_utf8_raw(code: u32, dst: &mut [u8]) -> &mut [u8] {
             *c = (code >> 6 & 0x3F) as u8 | TAG_CONT;
             *d = (code & 0x3F) as u8 | TAG_CONT;
         }
-        _ => panic!(
-            "encode_utf8: need {} bytes to encode U+{:X}, but the buffer has {}",
-            len,
-            code,
-            dst.len(),
-        ),
+        // The buffer length and code point can't be formatted into the message in a
+        // const fn.
+        _ => panic!("encode_utf8: the buffer is too small to encode the character"),
     };
-    &mut dst[..len]
+    // SAFETY: the match above checked that `dst` holds at least `len` bytes.
+    unsafe { &mut *ptr::slice_from_raw_parts_mut(dst.as_mut_ptr(), len) }
 }
 
 /// Encodes a raw u32 value as UTF-16 into the provided `u16` buffer,
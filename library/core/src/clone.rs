@@ -128,6 +128,19 @@ pub trait Clone: Sized {
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
     fn clone_from(&mut self, source: &Self) {
+        SpecCloneFrom::spec_clone_from(self, source)
+    }
+}
+
+/// The default `Clone::clone_from`, specialized for types whose `Clone` impl
+/// the compiler provides, like arrays, and that can't override it themselves.
+pub(crate) trait SpecCloneFrom {
+    fn spec_clone_from(&mut self, source: &Self);
+}
+
+impl<T: Clone> SpecCloneFrom for T {
+    #[inline]
+    default fn spec_clone_from(&mut self, source: &Self) {
         *self = source.clone()
     }
 }
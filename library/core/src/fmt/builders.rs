@@ -940,3 +940,76 @@ fn is_pretty(&self) -> bool {
         self.fmt.alternate()
     }
 }
+
+/// Creates a value whose [`Display`] and [`Debug`] implementations call `f`.
+///
+/// This saves writing a one-off wrapper type just to format something in a
+/// particular way, e.g. a list joined by some separator. The closure is
+/// called each time the value is formatted.
+///
+/// [`Display`]: fmt::Display
+///
+/// # Examples
+///
+/// ```
+/// #![feature(debug_closure_helpers)]
+/// use std::fmt;
+///
+/// let names = ["apple", "banana", "cherry"];
+/// let joined = fmt::from_fn(|f| {
+///     for (i, name) in names.iter().enumerate() {
+///         if i != 0 {
+///             f.write_str(" | ")?;
+///         }
+///         f.write_str(name)?;
+///     }
+///     Ok(())
+/// });
+///
+/// assert_eq!(format!("{}", joined), "apple | banana | cherry");
+///
+/// // It composes with `format_args!` like any other `Display` value.
+/// let fruits = format!("{}", format_args!("fruits: [{}]", joined));
+/// assert_eq!(fruits, "fruits: [apple | banana | cherry]");
+///
+/// // It also implements `Debug`, e.g. to hide the value of a field.
+/// let secret = fmt::from_fn(|f| f.write_str("<redacted>"));
+/// assert_eq!(format!("{:?}", secret), "<redacted>");
+/// ```
+#[unstable(feature = "debug_closure_helpers", issue = "117729")]
+#[must_use = "returns a value implementing Debug and Display, which does nothing unless formatted"]
+pub fn from_fn<F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result>(f: F) -> FromFn<F> {
+    FromFn(f)
+}
+
+/// Implements [`Debug`] and [`Display`] by calling a closure.
+///
+/// This `struct` is created by [`from_fn`]. It is `Copy` and `Clone` whenever
+/// the closure is.
+///
+/// [`Display`]: fmt::Display
+#[unstable(feature = "debug_closure_helpers", issue = "117729")]
+#[derive(Clone, Copy)]
+pub struct FromFn<F>(F)
+where
+    F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result;
+
+#[unstable(feature = "debug_closure_helpers", issue = "117729")]
+impl<F> fmt::Debug for FromFn<F>
+where
+    F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+#[unstable(feature = "debug_closure_helpers", issue = "117729")]
+impl<F> fmt::Display for FromFn<F>
+where
+    F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
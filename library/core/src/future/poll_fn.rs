@@ -23,6 +23,36 @@
 /// assert_eq!(read_future.await, "Hello, World!".to_owned());
 /// # }
 /// ```
+///
+/// `poll_fn` is also the easiest way to turn a `poll_*`-style readiness
+/// check into something that can be `.await`ed:
+///
+/// ```
+/// #![feature(future_poll_fn)]
+/// # async fn run() {
+/// use core::future::poll_fn;
+/// use std::task::{Context, Poll};
+///
+/// struct Socket {
+///     readable: bool,
+/// }
+///
+/// impl Socket {
+///     fn poll_readable(&self, cx: &mut Context<'_>) -> Poll<()> {
+///         if self.readable {
+///             Poll::Ready(())
+///         } else {
+///             // A real socket would register the waker with the reactor here.
+///             cx.waker().wake_by_ref();
+///             Poll::Pending
+///         }
+///     }
+/// }
+///
+/// let socket = Socket { readable: true };
+/// poll_fn(|cx| socket.poll_readable(cx)).await;
+/// # }
+/// ```
 #[unstable(feature = "future_poll_fn", issue = "72302")]
 pub fn poll_fn<T, F>(f: F) -> PollFn<F>
 where
@@ -51,6 +51,54 @@
     unsafe { intrinsics::unreachable() }
 }
 
+/// Makes a *soundness* promise to the compiler that `cond` holds.
+///
+/// This may allow the optimizer to simplify things, for example by removing
+/// a bounds check that it can now prove always succeeds. It is the same as
+/// `if !cond { unreachable_unchecked() }`, but states the invariant directly
+/// instead of the branch that cannot happen.
+///
+/// # Safety
+///
+/// `cond` must be `true`. Calling `assert_unchecked(false)` is *undefined
+/// behavior*, exactly like reaching [`unreachable_unchecked`].
+///
+/// In const evaluation a `false` condition is detected and reported as a hard
+/// error instead of being assumed away.
+///
+/// Use this function only when you can prove that the condition always holds.
+/// Otherwise, consider using the [`assert!`] or [`debug_assert!`] macros,
+/// which check the condition and panic if it does not hold.
+///
+/// # Example
+///
+/// ```
+/// #![feature(hint_assert_unchecked)]
+///
+/// /// # Safety
+/// ///
+/// /// `index` must be less than `v.len()`.
+/// unsafe fn get_unchecked_by_hint(v: &[u32], index: usize) -> u32 {
+///     // SAFETY: the caller guarantees that `index` is in bounds, so the
+///     // bounds check in the indexing below can be optimized out.
+///     unsafe { std::hint::assert_unchecked(index < v.len()) };
+///     v[index]
+/// }
+///
+/// let v = [1, 2, 3];
+/// assert_eq!(unsafe { get_unchecked_by_hint(&v, 2) }, 3);
+/// ```
+#[inline(always)]
+#[unstable(feature = "hint_assert_unchecked", issue = "119131")]
+#[rustc_const_unstable(feature = "hint_assert_unchecked", issue = "119131")]
+pub const unsafe fn assert_unchecked(cond: bool) {
+    if !cond {
+        // SAFETY: the caller must uphold the safety contract for
+        // `assert_unchecked`, which makes this branch unreachable.
+        unsafe { unreachable_unchecked() }
+    }
+}
+
 /// Emits a machine instruction to signal the processor that it is running in
 /// a busy-wait spin-loop ("spin lock").
 ///
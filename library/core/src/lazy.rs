@@ -182,6 +182,10 @@ pub fn get_or_init<F>(&self, f: F) -> &T
     /// the cell was empty. If the cell was empty and `f` failed, an
     /// error is returned.
     ///
+    /// `f` is only called while the cell is empty, so an initialized value
+    /// is never overwritten. If `f` returns an error, the cell is left
+    /// empty and a later call may retry the initialization.
+    ///
     /// # Panics
     ///
     /// If `f` panics, the panic is propagated to the caller, and the cell
@@ -193,7 +197,7 @@ pub fn get_or_init<F>(&self, f: F) -> &T
     /// # Examples
     ///
     /// ```
-    /// #![feature(once_cell)]
+    /// #![feature(once_cell, once_cell_try)]
     ///
     /// use std::lazy::OnceCell;
     ///
@@ -206,7 +210,7 @@ pub fn get_or_init<F>(&self, f: F) -> &T
     /// assert_eq!(value, Ok(&92));
     /// assert_eq!(cell.get(), Some(&92))
     /// ```
-    #[unstable(feature = "once_cell", issue = "74465")]
+    #[unstable(feature = "once_cell_try", issue = "109737")]
     pub fn get_or_try_init<F, E>(&self, f: F) -> Result<&T, E>
     where
         F: FnOnce() -> Result<T, E>,
@@ -282,9 +286,9 @@ pub fn take(&mut self) -> Option<T> {
 /// ```
 /// #![feature(once_cell)]
 ///
-/// use std::lazy::Lazy;
+/// use std::lazy::LazyCell;
 ///
-/// let lazy: Lazy<i32> = Lazy::new(|| {
+/// let lazy: LazyCell<i32> = LazyCell::new(|| {
 ///     println!("initializing");
 ///     92
 /// });
@@ -299,19 +303,19 @@ pub fn take(&mut self) -> Option<T> {
 /// //   92
 /// ```
 #[unstable(feature = "once_cell", issue = "74465")]
-pub struct Lazy<T, F = fn() -> T> {
+pub struct LazyCell<T, F = fn() -> T> {
     cell: OnceCell<T>,
     init: Cell<Option<F>>,
 }
 
 #[unstable(feature = "once_cell", issue = "74465")]
-impl<T: fmt::Debug, F> fmt::Debug for Lazy<T, F> {
+impl<T: fmt::Debug, F> fmt::Debug for LazyCell<T, F> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Lazy").field("cell", &self.cell).field("init", &"..").finish()
+        f.debug_struct("LazyCell").field("cell", &self.cell).field("init", &"..").finish()
     }
 }
 
-impl<T, F> Lazy<T, F> {
+impl<T, F> LazyCell<T, F> {
     /// Creates a new lazy value with the given initializing function.
     ///
     /// # Examples
@@ -320,22 +324,22 @@ impl<T, F> Lazy<T, F> {
     /// #![feature(once_cell)]
     ///
     /// # fn main() {
-    /// use std::lazy::Lazy;
+    /// use std::lazy::LazyCell;
     ///
     /// let hello = "Hello, World!".to_string();
     ///
-    /// let lazy = Lazy::new(|| hello.to_uppercase());
+    /// let lazy = LazyCell::new(|| hello.to_uppercase());
     ///
     /// assert_eq!(&*lazy, "HELLO, WORLD!");
     /// # }
     /// ```
     #[unstable(feature = "once_cell", issue = "74465")]
-    pub const fn new(init: F) -> Lazy<T, F> {
-        Lazy { cell: OnceCell::new(), init: Cell::new(Some(init)) }
+    pub const fn new(init: F) -> LazyCell<T, F> {
+        LazyCell { cell: OnceCell::new(), init: Cell::new(Some(init)) }
     }
 }
 
-impl<T, F: FnOnce() -> T> Lazy<T, F> {
+impl<T, F: FnOnce() -> T> LazyCell<T, F> {
     /// Forces the evaluation of this lazy value and returns a reference to
     /// the result.
     ///
@@ -346,34 +350,78 @@ impl<T, F: FnOnce() -> T> Lazy<T, F> {
     /// ```
     /// #![feature(once_cell)]
     ///
-    /// use std::lazy::Lazy;
+    /// use std::lazy::LazyCell;
     ///
-    /// let lazy = Lazy::new(|| 92);
+    /// let lazy = LazyCell::new(|| 92);
     ///
-    /// assert_eq!(Lazy::force(&lazy), &92);
+    /// assert_eq!(LazyCell::force(&lazy), &92);
     /// assert_eq!(&*lazy, &92);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the initializing function panicked on an earlier call, which
+    /// leaves the cell poisoned, or if the initializing function tries to force
+    /// this same cell again instead of recursing forever.
     #[unstable(feature = "once_cell", issue = "74465")]
-    pub fn force(this: &Lazy<T, F>) -> &T {
+    pub fn force(this: &LazyCell<T, F>) -> &T {
         this.cell.get_or_init(|| match this.init.take() {
             Some(f) => f(),
-            None => panic!("`Lazy` instance has previously been poisoned"),
+            None => panic!(
+                "`LazyCell` instance was forced reentrantly from its own initializer, \
+                 or has previously been poisoned"
+            ),
         })
     }
+
+    /// Consumes this `LazyCell`, returning the stored value if it was
+    /// initialized, or the initializing function otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell is poisoned, i.e. its initializing function panicked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    ///
+    /// use std::lazy::LazyCell;
+    ///
+    /// let hello = "Hello, World!".to_string();
+    ///
+    /// let lazy = LazyCell::new(|| hello.to_uppercase());
+    ///
+    /// assert_eq!(&*lazy, "HELLO, WORLD!");
+    /// assert_eq!(LazyCell::into_inner(lazy).ok(), Some("HELLO, WORLD!".to_string()));
+    ///
+    /// let not_forced = LazyCell::new(|| 92);
+    /// let init = LazyCell::into_inner(not_forced).unwrap_err();
+    /// assert_eq!(init(), 92);
+    /// ```
+    #[unstable(feature = "once_cell", issue = "74465")]
+    pub fn into_inner(this: LazyCell<T, F>) -> Result<T, F> {
+        let LazyCell { cell, init } = this;
+        match (cell.into_inner(), init.into_inner()) {
+            (Some(value), _) => Ok(value),
+            (None, Some(f)) => Err(f),
+            (None, None) => panic!("`LazyCell` instance has previously been poisoned"),
+        }
+    }
 }
 
 #[unstable(feature = "once_cell", issue = "74465")]
-impl<T, F: FnOnce() -> T> Deref for Lazy<T, F> {
+impl<T, F: FnOnce() -> T> Deref for LazyCell<T, F> {
     type Target = T;
     fn deref(&self) -> &T {
-        Lazy::force(self)
+        LazyCell::force(self)
     }
 }
 
 #[unstable(feature = "once_cell", issue = "74465")]
-impl<T: Default> Default for Lazy<T> {
+impl<T: Default> Default for LazyCell<T> {
     /// Creates a new lazy value using `Default` as the initializing function.
-    fn default() -> Lazy<T> {
-        Lazy::new(T::default)
+    fn default() -> LazyCell<T> {
+        LazyCell::new(T::default)
     }
 }
@@ -76,6 +76,7 @@
 #![feature(const_assert_type)]
 #![feature(const_discriminant)]
 #![feature(const_cell_into_inner)]
+#![feature(const_char_encode_utf8)]
 #![feature(const_intrinsic_copy)]
 #![feature(const_intrinsic_forget)]
 #![feature(const_float_classify)]
@@ -100,6 +101,7 @@
 #![feature(const_raw_ptr_comparison)]
 #![feature(const_raw_ptr_deref)]
 #![feature(const_slice_from_raw_parts)]
+#![feature(const_str_from_utf8_unchecked_mut)]
 #![feature(const_slice_ptr_len)]
 #![feature(const_size_of_val)]
 #![feature(const_swap)]
@@ -916,6 +916,11 @@ pub unsafe fn assume_init_drop(&mut self) {
     /// It is up to the caller to guarantee that all elements of the array are
     /// in an initialized state.
     ///
+    /// The elements are moved into the returned array without being dropped
+    /// or copied twice, so each of them is dropped exactly once, when the
+    /// returned array is. This holds for any `T`, including zero-sized types;
+    /// an empty array may even be converted when `T` is uninhabited.
+    ///
     /// # Examples
     ///
     /// ```
@@ -1134,7 +1139,12 @@ fn drop(&mut self) {
             }
         }
 
-        assert_eq!(this.len(), src.len(), "destination and source slices have different lengths");
+        assert!(
+            this.len() == src.len(),
+            "source slice length ({}) does not match destination slice length ({})",
+            src.len(),
+            this.len(),
+        );
         // NOTE: We need to explicitly slice them to the same length
         // for bounds checking to be elided, and the optimizer will
         // generate memcpy for simple cases (for example T = u8).
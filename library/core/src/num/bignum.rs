@@ -19,14 +19,8 @@
 )]
 #![macro_use]
 
-use crate::intrinsics;
-
 /// Arithmetic operations required by bignums.
 pub trait FullOps: Sized {
-    /// Returns `(carry', v')` such that `carry' * 2^W + v' = self + other + carry`,
-    /// where `W` is the number of bits in `Self`.
-    fn full_add(self, other: Self, carry: bool) -> (bool /* carry */, Self);
-
     /// Returns `(carry', v')` such that `carry' * 2^W + v' = self * other + carry`,
     /// where `W` is the number of bits in `Self`.
     fn full_mul(self, other: Self, carry: Self) -> (Self /* carry */, Self);
@@ -42,17 +36,9 @@ fn full_div_rem(self, other: Self, borrow: Self)
 }
 
 macro_rules! impl_full_ops {
-    ($($ty:ty: add($addfn:path), mul/div($bigty:ident);)*) => (
+    ($($ty:ty: mul/div($bigty:ident);)*) => (
         $(
             impl FullOps for $ty {
-                fn full_add(self, other: $ty, carry: bool) -> (bool, $ty) {
-                    // This cannot overflow; the output is between `0` and `2 * 2^nbits - 1`.
-                    // FIXME: will LLVM optimize this into ADC or similar?
-                    let (v, carry1) = intrinsics::add_with_overflow(self, other);
-                    let (v, carry2) = intrinsics::add_with_overflow(v, if carry {1} else {0});
-                    (carry1 || carry2, v)
-                }
-
                 fn full_mul(self, other: $ty, carry: $ty) -> ($ty, $ty) {
                     // This cannot overflow;
                     // the output is between `0` and `2^nbits * (2^nbits - 1)`.
@@ -82,11 +68,11 @@ fn full_div_rem(self, other: $ty, borrow: $ty) -> ($ty, $ty) {
 }
 
 impl_full_ops! {
-    u8:  add(intrinsics::u8_add_with_overflow),  mul/div(u16);
-    u16: add(intrinsics::u16_add_with_overflow), mul/div(u32);
-    u32: add(intrinsics::u32_add_with_overflow), mul/div(u64);
+    u8:  mul/div(u16);
+    u16: mul/div(u32);
+    u32: mul/div(u64);
     // See RFC #521 for enabling this.
-    // u64: add(intrinsics::u64_add_with_overflow), mul/div(u128);
+    // u64: mul/div(u128);
 }
 
 /// Table of powers of 5 representable in digits. Specifically, the largest {u8, u16, u32} value
@@ -182,12 +168,11 @@ pub fn bit_length(&self) -> usize {
             pub fn add<'a>(&'a mut self, other: &$name) -> &'a mut $name {
                 use crate::cmp;
                 use crate::iter;
-                use crate::num::bignum::FullOps;
 
                 let mut sz = cmp::max(self.size, other.size);
                 let mut carry = false;
                 for (a, b) in iter::zip(&mut self.base[..sz], &other.base[..sz]) {
-                    let (c, v) = (*a).full_add(*b, carry);
+                    let (v, c) = (*a).carrying_add(*b, carry);
                     *a = v;
                     carry = c;
                 }
@@ -200,13 +185,11 @@ pub fn add<'a>(&'a mut self, other: &$name) -> &'a mut $name {
             }
 
             pub fn add_small(&mut self, other: $ty) -> &mut $name {
-                use crate::num::bignum::FullOps;
-
-                let (mut carry, v) = self.base[0].full_add(other, false);
+                let (v, mut carry) = self.base[0].carrying_add(other, false);
                 self.base[0] = v;
                 let mut i = 1;
                 while carry {
-                    let (c, v) = self.base[i].full_add(0, carry);
+                    let (v, c) = self.base[i].carrying_add(0, carry);
                     self.base[i] = v;
                     carry = c;
                     i += 1;
@@ -221,16 +204,15 @@ pub fn add_small(&mut self, other: $ty) -> &mut $name {
             pub fn sub<'a>(&'a mut self, other: &$name) -> &'a mut $name {
                 use crate::cmp;
                 use crate::iter;
-                use crate::num::bignum::FullOps;
 
                 let sz = cmp::max(self.size, other.size);
-                let mut noborrow = true;
+                let mut borrow = false;
                 for (a, b) in iter::zip(&mut self.base[..sz], &other.base[..sz]) {
-                    let (c, v) = (*a).full_add(!*b, noborrow);
+                    let (v, c) = (*a).borrowing_sub(*b, borrow);
                     *a = v;
-                    noborrow = c;
+                    borrow = c;
                 }
-                assert!(noborrow);
+                assert!(!borrow);
                 self.size = sz;
                 self
             }
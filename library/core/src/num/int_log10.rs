@@ -1,4 +1,4 @@
-mod unchecked {
+pub mod unchecked {
     // 0 < val <= u8::MAX
     pub const fn u8(val: u8) -> u32 {
         if val >= 100 {
@@ -88,6 +88,24 @@ pub const fn u128(mut val: u128) -> u32 {
         log + less_than_16(val as u64)
     }
 
+    // 0 < val <= usize::MAX
+    #[cfg(target_pointer_width = "16")]
+    pub const fn usize(val: usize) -> u32 {
+        u16(val as u16)
+    }
+
+    // 0 < val <= usize::MAX
+    #[cfg(target_pointer_width = "32")]
+    pub const fn usize(val: usize) -> u32 {
+        u32(val as u32)
+    }
+
+    // 0 < val <= usize::MAX
+    #[cfg(target_pointer_width = "64")]
+    pub const fn usize(val: usize) -> u32 {
+        u64(val as u64)
+    }
+
     // 0 < val <= i8::MAX
     pub const fn i8(val: i8) -> u32 {
         u8(val as u8)
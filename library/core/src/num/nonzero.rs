@@ -5,6 +5,7 @@
 use crate::str::FromStr;
 
 use super::from_str_radix;
+use super::int_log10;
 use super::{IntErrorKind, ParseIntError};
 use crate::intrinsics;
 
@@ -288,13 +289,19 @@ fn rem(self, other: $Ty) -> $Int {
 
 // A bunch of methods for unsigned nonzero types only.
 macro_rules! nonzero_unsigned_operations {
-    ( $( $Ty: ident($Int: ty); )+ ) => {
+    ( $( $Ty: ident($Int: ident); )+ ) => {
         $(
             impl $Ty {
                 /// Add an unsigned integer to a non-zero value.
                 /// Check for overflow and return [`None`] on overflow
                 /// As a consequence, the result cannot wrap to zero.
                 ///
+                /// Only unsigned non-zero types have this method: adding a
+                /// signed amount to a signed non-zero value can land on zero
+                /// without overflowing, so there is no way to preserve the
+                /// invariant by construction. Go through
+                #[doc = concat!("[`", stringify!($Int), "::checked_add`]")]
+                /// and `new` instead when working with signed values.
                 ///
                 /// # Examples
                 ///
@@ -420,6 +427,56 @@ pub const fn checked_next_power_of_two(self) -> Option<$Ty> {
                         None
                     }
                 }
+
+                /// Returns the base 2 logarithm of the number, rounded down.
+                ///
+                /// This is the same operation as
+                #[doc = concat!("[`", stringify!($Int), "::log2`],")]
+                /// except that it has no failure cases to worry about
+                /// since this value can never be zero.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// #![feature(nonzero_ops)]
+                #[doc = concat!("# use std::num::", stringify!($Ty), ";")]
+                ///
+                #[doc = concat!("assert_eq!(", stringify!($Ty), "::new(7).unwrap().log2(), 2);")]
+                #[doc = concat!("assert_eq!(", stringify!($Ty), "::new(8).unwrap().log2(), 3);")]
+                #[doc = concat!("assert_eq!(", stringify!($Ty), "::new(9).unwrap().log2(), 3);")]
+                /// ```
+                #[unstable(feature = "nonzero_ops", issue = "84186")]
+                #[must_use = "this returns the result of the operation, \
+                              without modifying the original"]
+                #[inline]
+                pub const fn log2(self) -> u32 {
+                    <$Int>::BITS - 1 - self.leading_zeros()
+                }
+
+                /// Returns the base 10 logarithm of the number, rounded down.
+                ///
+                /// This is the same operation as
+                #[doc = concat!("[`", stringify!($Int), "::log10`],")]
+                /// except that it has no failure cases to worry about
+                /// since this value can never be zero.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// #![feature(nonzero_ops)]
+                #[doc = concat!("# use std::num::", stringify!($Ty), ";")]
+                ///
+                #[doc = concat!("assert_eq!(", stringify!($Ty), "::new(99).unwrap().log10(), 1);")]
+                #[doc = concat!("assert_eq!(", stringify!($Ty), "::new(100).unwrap().log10(), 2);")]
+                #[doc = concat!("assert_eq!(", stringify!($Ty), "::new(101).unwrap().log10(), 2);")]
+                /// ```
+                #[unstable(feature = "nonzero_ops", issue = "84186")]
+                #[must_use = "this returns the result of the operation, \
+                              without modifying the original"]
+                #[inline]
+                pub const fn log10(self) -> u32 {
+                    int_log10::unchecked::$Int(self.get())
+                }
             }
         )+
     }
@@ -435,6 +492,11 @@ pub const fn checked_next_power_of_two(self) -> Option<$Ty> {
 }
 
 // A bunch of methods for signed nonzero types only.
+//
+// There is deliberately no `checked_add`/`saturating_add` here: adding any
+// amount of the same signedness may produce zero (e.g. `-1 + 1`), so the
+// result could not be a non-zero value without a runtime check that `new`
+// already provides.
 macro_rules! nonzero_signed_operations {
     ( $( $Ty: ident($Int: ty) -> $Uty: ident($Uint: ty); )+ ) => {
         $(
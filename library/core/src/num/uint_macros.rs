@@ -1377,6 +1377,50 @@ pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
             (a as Self, b)
         }
 
+        /// Calculates `self + rhs + carry` without the ability to overflow.
+        ///
+        /// Performs "ternary addition" which takes in an extra bit to add, and may return an
+        /// additional bit of overflow. This allows for chaining together multiple additions
+        /// to create "big integers" which represent larger values.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage
+        ///
+        /// ```
+        /// #![feature(bigint_helper_methods)]
+        #[doc = concat!("assert_eq!(5", stringify!($SelfT), ".carrying_add(2, false), (7, false));")]
+        #[doc = concat!("assert_eq!(5", stringify!($SelfT), ".carrying_add(2, true), (8, false));")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.carrying_add(1, false), (0, true));")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.carrying_add(0, true), (0, true));")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.carrying_add(", stringify!($SelfT), "::MAX, true), (", stringify!($SelfT), "::MAX, true));")]
+        /// ```
+        ///
+        /// Adding two two-limb numbers, least significant limb first:
+        ///
+        /// ```
+        /// #![feature(bigint_helper_methods)]
+        #[doc = concat!("let a = [", stringify!($SelfT), "::MAX, 1];")]
+        #[doc = concat!("let b = [1, 2];")]
+        /// let (lo, carry) = a[0].carrying_add(b[0], false);
+        /// let (hi, carry) = a[1].carrying_add(b[1], carry);
+        /// assert_eq!([lo, hi], [0, 4]);
+        /// assert!(!carry);
+        /// ```
+        #[unstable(feature = "bigint_helper_methods", issue = "85532")]
+        #[rustc_const_unstable(feature = "const_bigint_helper_methods", issue = "85532")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn carrying_add(self, rhs: Self, carry: bool) -> (Self, bool) {
+            // note: longer-term this should be done via an intrinsic, but this shape, with
+            // the flags combined by a non-short-circuiting `|`, lets LLVM turn a chain of
+            // these into add-with-carry instructions.
+            let (a, b) = self.overflowing_add(rhs);
+            let (c, d) = a.overflowing_add(carry as $SelfT);
+            (c, b | d)
+        }
+
         /// Calculates `self` - `rhs`
         ///
         /// Returns a tuple of the subtraction along with a boolean indicating
@@ -1402,6 +1446,38 @@ pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
             (a as Self, b)
         }
 
+        /// Calculates `self - rhs - borrow` without the ability to overflow.
+        ///
+        /// Performs "ternary subtraction" which takes in an extra bit to subtract, and may return
+        /// an additional bit of overflow. This allows for chaining together multiple subtractions
+        /// to create "big integers" which represent larger values.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage
+        ///
+        /// ```
+        /// #![feature(bigint_helper_methods)]
+        #[doc = concat!("assert_eq!(5", stringify!($SelfT), ".borrowing_sub(2, false), (3, false));")]
+        #[doc = concat!("assert_eq!(5", stringify!($SelfT), ".borrowing_sub(2, true), (2, false));")]
+        #[doc = concat!("assert_eq!(0", stringify!($SelfT), ".borrowing_sub(1, false), (", stringify!($SelfT), "::MAX, true));")]
+        #[doc = concat!("assert_eq!(0", stringify!($SelfT), ".borrowing_sub(1, true), (", stringify!($SelfT), "::MAX - 1, true));")]
+        #[doc = concat!("assert_eq!(0", stringify!($SelfT), ".borrowing_sub(", stringify!($SelfT), "::MAX, true), (0, true));")]
+        /// ```
+        #[unstable(feature = "bigint_helper_methods", issue = "85532")]
+        #[rustc_const_unstable(feature = "const_bigint_helper_methods", issue = "85532")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self, bool) {
+            // note: longer-term this should be done via an intrinsic, but this shape, with
+            // the flags combined by a non-short-circuiting `|`, lets LLVM turn a chain of
+            // these into subtract-with-borrow instructions.
+            let (a, b) = self.overflowing_sub(rhs);
+            let (c, d) = a.overflowing_sub(borrow as $SelfT);
+            (c, b | d)
+        }
+
         /// Calculates the multiplication of `self` and `rhs`.
         ///
         /// Returns a tuple of the multiplication along with a boolean
@@ -1429,6 +1505,135 @@ pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
             (a as Self, b)
         }
 
+        /// Calculates the complete product `self * rhs` without the possibility to overflow.
+        ///
+        /// This returns the low-order (wrapping) bits and the high-order (overflow) bits
+        /// of the result as two separate values, in that order: `(low, high)`. This is
+        /// the same least significant limb first order that [`carrying_add`] uses.
+        ///
+        /// [`carrying_add`]: Self::carrying_add
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// Please note that this example is shared between integer types.
+        /// Which explains why `u32` is used here.
+        ///
+        /// ```
+        /// #![feature(bigint_helper_methods)]
+        /// assert_eq!(5u32.widening_mul(2), (10, 0));
+        /// assert_eq!(1_000_000_000u32.widening_mul(10), (1410065408, 2));
+        /// ```
+        ///
+        /// `u128` has no wider type to cast to, but its full 256-bit product is still
+        /// available:
+        ///
+        /// ```
+        /// #![feature(bigint_helper_methods)]
+        /// let (low, high) = u128::MAX.widening_mul(u128::MAX);
+        /// // (2^128 - 1)^2 = 2^256 - 2^129 + 1
+        /// assert_eq!((low, high), (1, u128::MAX - 1));
+        /// ```
+        #[unstable(feature = "bigint_helper_methods", issue = "85532")]
+        #[rustc_const_unstable(feature = "const_bigint_helper_methods", issue = "85532")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn widening_mul(self, rhs: Self) -> (Self, Self) {
+            self.carrying_mul(rhs, 0)
+        }
+
+        /// Calculates the "full multiplication" `self * rhs + carry`
+        /// without the possibility to overflow.
+        ///
+        /// This returns the low-order (wrapping) bits and the high-order (overflow) bits
+        /// of the result as two separate values, in that order: `(low, high)`.
+        ///
+        /// Performs "long multiplication" which takes in an extra amount to add, and may
+        /// return an additional amount of overflow. This allows for chaining together
+        /// multiple multiplications to create "big integers" which represent larger values.
+        /// The result always fits: even `MAX * MAX + MAX` is `(0, MAX)`.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// Please note that this example is shared between integer types.
+        /// Which explains why `u32` is used here.
+        ///
+        /// ```
+        /// #![feature(bigint_helper_methods)]
+        /// assert_eq!(5u32.carrying_mul(2, 0), (10, 0));
+        /// assert_eq!(5u32.carrying_mul(2, 10), (20, 0));
+        /// assert_eq!(1_000_000_000u32.carrying_mul(10, 0), (1410065408, 2));
+        /// assert_eq!(1_000_000_000u32.carrying_mul(10, 10), (1410065418, 2));
+        /// assert_eq!(u32::MAX.carrying_mul(u32::MAX, u32::MAX), (0, u32::MAX));
+        /// ```
+        ///
+        /// Multiplying two 128-bit numbers, stored as two `u64` limbs each with the
+        /// least significant limb first, into a 256-bit product of four limbs:
+        ///
+        /// ```
+        /// #![feature(bigint_helper_methods)]
+        /// fn mul_128_by_128(a: [u64; 2], b: [u64; 2]) -> [u64; 4] {
+        ///     let mut product = [0; 4];
+        ///     for i in 0..2 {
+        ///         let mut carry = 0;
+        ///         for j in 0..2 {
+        ///             let (low, high) = a[i].carrying_mul(b[j], carry);
+        ///             let (sum, overflowed) = low.overflowing_add(product[i + j]);
+        ///             product[i + j] = sum;
+        ///             // `a * b + carry + product` is at most `2^128 - 1`, so this
+        ///             // can't overflow.
+        ///             carry = high + overflowed as u64;
+        ///         }
+        ///         product[i + 2] = carry;
+        ///     }
+        ///     product
+        /// }
+        ///
+        /// // (2^128 - 1)^2 = 2^256 - 2^129 + 1
+        /// let max = [u64::MAX, u64::MAX];
+        /// assert_eq!(mul_128_by_128(max, max), [1, 0, u64::MAX - 1, u64::MAX]);
+        ///
+        /// let a = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210_u128;
+        /// let b = 0x0f0f_0f0f_0f0f_0f0f_u128;
+        /// let product = mul_128_by_128([a as u64, (a >> 64) as u64], [b as u64, 0]);
+        /// assert_eq!(product[2..], [(a.widening_mul(b).1) as u64, 0]);
+        /// assert_eq!(product[0] as u128 | (product[1] as u128) << 64, a.wrapping_mul(b));
+        /// ```
+        #[unstable(feature = "bigint_helper_methods", issue = "85532")]
+        #[rustc_const_unstable(feature = "const_bigint_helper_methods", issue = "85532")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn carrying_mul(self, rhs: Self, carry: Self) -> (Self, Self) {
+            // Schoolbook multiplication on half-width digits, so that this also works
+            // for `u128`, which has no wider type to do the multiplication in. The
+            // product of two half-width digits always fits in `Self`.
+            const HALF: u32 = $BITS / 2;
+            const LOW_MASK: $SelfT = <$SelfT>::MAX >> HALF;
+
+            let (a_low, a_high) = (self & LOW_MASK, self >> HALF);
+            let (b_low, b_high) = (rhs & LOW_MASK, rhs >> HALF);
+
+            let low_low = a_low * b_low;
+            let low_high = a_low * b_high;
+            let high_low = a_high * b_low;
+            let high_high = a_high * b_high;
+
+            // The sum of three half-width values can't overflow `Self` either.
+            let middle = (low_low >> HALF) + (low_high & LOW_MASK) + (high_low & LOW_MASK);
+            let low = (low_low & LOW_MASK) | (middle << HALF);
+            let high = high_high + (low_high >> HALF) + (high_low >> HALF) + (middle >> HALF);
+
+            // `self * rhs + carry <= MAX * MAX + MAX < 2^(2 * BITS)`, so adding the
+            // carry never overflows `high`.
+            let (low, overflowed) = low.overflowing_add(carry);
+            (low, high + overflowed as $SelfT)
+        }
+
         /// Calculates the divisor when `self` is divided by `rhs`.
         ///
         /// Returns a tuple of the divisor along with a boolean indicating
@@ -1757,6 +1962,93 @@ pub const fn rem_euclid(self, rhs: Self) -> Self {
             self % rhs
         }
 
+        /// Calculates the smallest value greater than or equal to `self` that
+        /// is a multiple of `rhs`.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if `rhs` is 0.
+        ///
+        /// ## Overflow behavior
+        ///
+        /// On overflow, this function will panic if overflow checks are enabled (default in debug
+        /// mode) and wrap if overflow checks are disabled (default in release mode).
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(int_roundings)]
+        #[doc = concat!("assert_eq!(16_", stringify!($SelfT), ".next_multiple_of(8), 16);")]
+        #[doc = concat!("assert_eq!(23_", stringify!($SelfT), ".next_multiple_of(8), 24);")]
+        /// ```
+        #[unstable(feature = "int_roundings", issue = "88581")]
+        #[rustc_const_unstable(feature = "int_roundings", issue = "88581")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        #[rustc_inherit_overflow_checks]
+        pub const fn next_multiple_of(self, rhs: Self) -> Self {
+            match self % rhs {
+                0 => self,
+                r => self + (rhs - r),
+            }
+        }
+
+        /// Calculates the smallest value greater than or equal to `self` that
+        /// is a multiple of `rhs`. Returns `None` if `rhs` is zero or the
+        /// operation would result in overflow.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(int_roundings)]
+        #[doc = concat!("assert_eq!(16_", stringify!($SelfT), ".checked_next_multiple_of(8), Some(16));")]
+        #[doc = concat!("assert_eq!(23_", stringify!($SelfT), ".checked_next_multiple_of(8), Some(24));")]
+        #[doc = concat!("assert_eq!(1_", stringify!($SelfT), ".checked_next_multiple_of(0), None);")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.checked_next_multiple_of(2), None);")]
+        /// ```
+        #[unstable(feature = "int_roundings", issue = "88581")]
+        #[rustc_const_unstable(feature = "int_roundings", issue = "88581")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> {
+            match try_opt!(self.checked_rem(rhs)) {
+                0 => Some(self),
+                // `rhs - r` cannot overflow because `r` is smaller than `rhs`.
+                r => self.checked_add(rhs - r),
+            }
+        }
+
+        /// Calculates the middle point of `self` and `rhs`.
+        ///
+        /// `midpoint(a, b)` is `(a + b) >> 1` as if it were performed in a
+        /// sufficiently-large unsigned integral type. This implies that the result
+        /// is always rounded towards zero and that no overflow will ever occur.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(num_midpoint)]
+        #[doc = concat!("assert_eq!(0", stringify!($SelfT), ".midpoint(4), 2);")]
+        #[doc = concat!("assert_eq!(1", stringify!($SelfT), ".midpoint(4), 2);")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.midpoint(", stringify!($SelfT), "::MAX - 1), ", stringify!($SelfT), "::MAX - 1);")]
+        /// ```
+        #[unstable(feature = "num_midpoint", issue = "110840")]
+        #[rustc_const_unstable(feature = "num_midpoint", issue = "110840")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn midpoint(self, rhs: Self) -> Self {
+            // The bits both operands have in common contribute fully to the
+            // sum, the differing ones only with half their weight.
+            (self & rhs) + ((self ^ rhs) >> 1)
+        }
+
         /// Returns `true` if and only if `self == 2^k` for some `k`.
         ///
         /// # Examples
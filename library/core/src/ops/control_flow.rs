@@ -91,6 +91,11 @@ fn from_residual(residual: ControlFlow<B, convert::Infallible>) -> Self {
     }
 }
 
+#[unstable(feature = "try_trait_v2_residual", issue = "91285")]
+impl<B, C> ops::Residual<C> for ControlFlow<B, convert::Infallible> {
+    type TryType = ControlFlow<B, C>;
+}
+
 impl<B, C> ControlFlow<B, C> {
     /// Returns `true` if this is a `Break` variant.
     ///
@@ -147,8 +152,59 @@ pub fn break_value(self) -> Option<B> {
         }
     }
 
+    /// Converts the `ControlFlow` into an `Option` which is `Some` if the
+    /// `ControlFlow` was `Continue` and `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(control_flow_enum)]
+    /// use std::ops::ControlFlow;
+    ///
+    /// assert_eq!(ControlFlow::<i32, String>::Break(3).continue_value(), None);
+    /// assert_eq!(ControlFlow::<String, i32>::Continue(3).continue_value(), Some(3));
+    /// ```
+    #[inline]
+    #[unstable(feature = "control_flow_enum", reason = "new API", issue = "75744")]
+    pub fn continue_value(self) -> Option<C> {
+        match self {
+            ControlFlow::Continue(x) => Some(x),
+            ControlFlow::Break(..) => None,
+        }
+    }
+
     /// Maps `ControlFlow<B, C>` to `ControlFlow<T, C>` by applying a function
     /// to the break value in case it exists.
+    ///
+    /// This is the `ControlFlow` counterpart of [`Result::map_err`].
+    ///
+    /// # Examples
+    ///
+    /// Adapting the break type of a graph visitor to the one used by its caller:
+    ///
+    /// ```
+    /// #![feature(control_flow_enum)]
+    /// use std::ops::ControlFlow;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum SearchError {
+    ///     Cycle(usize),
+    /// }
+    ///
+    /// /// Visits the nodes reachable from `node`, breaking with the first node
+    /// /// that is visited twice.
+    /// fn visit(edges: &[usize], node: usize, seen: &mut Vec<bool>) -> ControlFlow<usize> {
+    ///     if seen[node] {
+    ///         return ControlFlow::Break(node);
+    ///     }
+    ///     seen[node] = true;
+    ///     visit(edges, edges[node], seen)
+    /// }
+    ///
+    /// let edges = [1, 2, 0];
+    /// let result = visit(&edges, 0, &mut vec![false; 3]).map_break(SearchError::Cycle);
+    /// assert_eq!(result, ControlFlow::Break(SearchError::Cycle(0)));
+    /// ```
     #[inline]
     #[unstable(feature = "control_flow_enum", reason = "new API", issue = "75744")]
     pub fn map_break<T, F>(self, f: F) -> ControlFlow<T, C>
@@ -160,6 +216,35 @@ pub fn map_break<T, F>(self, f: F) -> ControlFlow<T, C>
             ControlFlow::Break(x) => ControlFlow::Break(f(x)),
         }
     }
+
+    /// Maps `ControlFlow<B, C>` to `ControlFlow<B, T>` by applying a function
+    /// to the continue value in case it exists.
+    ///
+    /// This is the `ControlFlow` counterpart of [`Result::map`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(control_flow_enum)]
+    /// use std::ops::ControlFlow;
+    ///
+    /// let flow = ControlFlow::<&str, usize>::Continue(3);
+    /// assert_eq!(flow.map_continue(|depth| depth + 1), ControlFlow::Continue(4));
+    ///
+    /// let flow = ControlFlow::<&str, usize>::Break("found");
+    /// assert_eq!(flow.map_continue(|depth| depth + 1), ControlFlow::Break("found"));
+    /// ```
+    #[inline]
+    #[unstable(feature = "control_flow_enum", reason = "new API", issue = "75744")]
+    pub fn map_continue<T, F>(self, f: F) -> ControlFlow<B, T>
+    where
+        F: FnOnce(C) -> T,
+    {
+        match self {
+            ControlFlow::Continue(x) => ControlFlow::Continue(f(x)),
+            ControlFlow::Break(x) => ControlFlow::Break(x),
+        }
+    }
 }
 
 /// These are used only as part of implementing the iterator adapters.
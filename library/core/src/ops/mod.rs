@@ -187,6 +187,11 @@
 #[unstable(feature = "try_trait_v2", issue = "84277")]
 pub use self::try_trait::Try;
 
+#[unstable(feature = "try_trait_v2_residual", issue = "91285")]
+pub use self::try_trait::Residual;
+
+pub(crate) use self::try_trait::ChangeOutputType;
+
 #[unstable(feature = "try_trait_transition", reason = "for bootstrap", issue = "none")]
 pub(crate) use self::try_trait::Try as TryV2;
 
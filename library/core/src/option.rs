@@ -825,6 +825,44 @@ pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Option<U> {
         }
     }
 
+    /// Calls the provided closure with a reference to the contained value (if [`Some`]).
+    ///
+    /// Returns the option unchanged, which makes this useful for peeking at a
+    /// value in the middle of a chain of combinators, e.g. to log it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(result_option_inspect)]
+    ///
+    /// let v = vec![1, 2, 3, 4, 5];
+    ///
+    /// // prints "got: 4"
+    /// let x: Option<&usize> = v.get(3).inspect(|x| println!("got: {}", x));
+    /// assert_eq!(x, Some(&4));
+    ///
+    /// // prints nothing
+    /// let x: Option<&usize> = v.get(5).inspect(|x| println!("got: {}", x));
+    /// assert_eq!(x, None);
+    ///
+    /// let doubled = Some("42")
+    ///     .map(|s| s.trim())
+    ///     .inspect(|s| println!("parsing {:?}", s))
+    ///     .and_then(|s| s.parse::<u32>().ok())
+    ///     .map(|n| n * 2);
+    /// assert_eq!(doubled, Some(84));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "result_option_inspect", issue = "91345")]
+    pub fn inspect<F: FnOnce(&T)>(self, f: F) -> Self {
+        if let Some(ref x) = self {
+            f(x);
+        }
+
+        self
+    }
+
     /// Returns the provided default result (if none),
     /// or applies a function to the contained value (if any).
     ///
@@ -1354,11 +1392,26 @@ pub fn zip<U>(self, other: Option<U>) -> Option<(T, U)> {
     /// If `self` is `Some(s)` and `other` is `Some(o)`, this method returns `Some(f(s, o))`.
     /// Otherwise, `None` is returned.
     ///
+    /// This is the same as `self.zip(other).map(|(s, o)| f(s, o))`, without the
+    /// intermediate tuple. It short-circuits: `f` is only called if both options
+    /// are `Some`.
+    ///
     /// # Examples
     ///
     /// ```
     /// #![feature(option_zip)]
     ///
+    /// let width = "20".parse::<u32>().ok();
+    /// let height = "15".parse::<u32>().ok();
+    /// assert_eq!(width.zip_with(height, |w, h| w * h), Some(300));
+    ///
+    /// let height = "fifteen".parse::<u32>().ok();
+    /// assert_eq!(width.zip_with(height, |w, h| w * h), None);
+    /// ```
+    ///
+    /// ```
+    /// #![feature(option_zip)]
+    ///
     /// #[derive(Debug, PartialEq)]
     /// struct Point {
     ///     x: f64,
@@ -2000,6 +2053,11 @@ fn from_residual(residual: Option<convert::Infallible>) -> Self {
     }
 }
 
+#[unstable(feature = "try_trait_v2_residual", issue = "91285")]
+impl<T> ops::Residual<T> for Option<convert::Infallible> {
+    type TryType = Option<T>;
+}
+
 impl<T> Option<Option<T>> {
     /// Converts from `Option<Option<T>>` to `Option<T>`
     ///
@@ -68,6 +68,7 @@ pub struct PanicInfo<'a> {
     payload: &'a (dyn Any + Send),
     message: Option<&'a fmt::Arguments<'a>>,
     location: &'a Location<'a>,
+    can_unwind: bool,
 }
 
 impl<'a> PanicInfo<'a> {
@@ -81,9 +82,10 @@ impl<'a> PanicInfo<'a> {
     pub fn internal_constructor(
         message: Option<&'a fmt::Arguments<'a>>,
         location: &'a Location<'a>,
+        can_unwind: bool,
     ) -> Self {
         struct NoPayload;
-        PanicInfo { location, message, payload: &NoPayload }
+        PanicInfo { location, message, payload: &NoPayload, can_unwind }
     }
 
     #[unstable(
@@ -123,14 +125,75 @@ pub fn payload(&self) -> &(dyn Any + Send) {
         self.payload
     }
 
-    /// If the `panic!` macro from the `core` crate (not from `std`)
-    /// was used with a formatting string and some additional arguments,
-    /// returns that message ready to be used for example with [`fmt::write`]
+    /// If the panic was started with a formatted message, e.g. by
+    /// `panic!("x {}", 1)`, returns that message ready to be used for example
+    /// with [`fmt::write`].
+    ///
+    /// Unlike [`payload`](PanicInfo::payload), this does not require the
+    /// message to be formatted into an allocated `String` first, which makes
+    /// it the way to get at the message in a `#[panic_handler]` in `no_std`
+    /// code.
+    ///
+    /// # Examples
+    ///
+    /// A panic handler that writes the message to a serial port:
+    ///
+    /// ```ignore (the panic handler conflicts with the one in std)
+    /// #![no_std]
+    /// #![feature(panic_info_message)]
+    ///
+    /// use core::fmt::Write;
+    /// use core::panic::PanicInfo;
+    ///
+    /// # struct SerialPort;
+    /// # impl Write for SerialPort {
+    /// #     fn write_str(&mut self, _: &str) -> core::fmt::Result { Ok(()) }
+    /// # }
+    /// # fn serial_port() -> SerialPort { SerialPort }
+    /// #[panic_handler]
+    /// fn panic(info: &PanicInfo<'_>) -> ! {
+    ///     let mut port = serial_port();
+    ///     if let Some(message) = info.message() {
+    ///         let _ = port.write_fmt(*message);
+    ///     }
+    ///     if let Some(location) = info.location() {
+    ///         let _ = write!(port, " at {}", location);
+    ///     }
+    ///     loop {}
+    /// }
+    /// ```
     #[unstable(feature = "panic_info_message", issue = "66745")]
     pub fn message(&self) -> Option<&fmt::Arguments<'_>> {
         self.message
     }
 
+    /// Returns whether the panic handler is allowed to unwind the stack from
+    /// the point where the panic occurred.
+    ///
+    /// This is `true` for most panics. It is `false` when the panic is
+    /// required to abort, for example when a thread panics again while it is
+    /// already unwinding from an earlier panic. A panic handler must not
+    /// unwind if this returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// #![feature(panic_can_unwind)]
+    /// use std::panic;
+    ///
+    /// panic::set_hook(Box::new(|panic_info| {
+    ///     if !panic_info.can_unwind() {
+    ///         println!("this panic will abort the process");
+    ///     }
+    /// }));
+    ///
+    /// panic!("Normal panic");
+    /// ```
+    #[unstable(feature = "panic_can_unwind", issue = "92988")]
+    pub fn can_unwind(&self) -> bool {
+        self.can_unwind
+    }
+
     /// Returns information about the location from which the panic originated,
     /// if available.
     ///
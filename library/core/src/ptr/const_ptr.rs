@@ -48,6 +48,88 @@ pub const fn cast<U>(self) -> *const U {
         self as _
     }
 
+    /// Gets the address of the pointer, discarding its
+    /// [provenance](crate::ptr#provenance).
+    ///
+    /// Unlike `self as usize`, this does not make the allocation the pointer
+    /// points to accessible through pointers created from integers. To get a
+    /// pointer with a different address back, use [`with_addr`] or
+    /// [`map_addr`] on a pointer that still has the provenance.
+    ///
+    /// [`with_addr`]: #method.with_addr
+    /// [`map_addr`]: #method.map_addr
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "strict_provenance", issue = "95228")]
+    pub fn addr(self) -> usize
+    where
+        T: Sized,
+    {
+        self as usize
+    }
+
+    /// Creates a new pointer with the given address and the
+    /// [provenance](crate::ptr#provenance) of `self`.
+    ///
+    /// This behaves like a [`wrapping_offset`] of `self` by the difference
+    /// between the two addresses, which is also how it is implemented: the
+    /// new pointer may be used to access the same allocated object as `self`,
+    /// if its address is inside of it.
+    ///
+    /// [`wrapping_offset`]: #method.wrapping_offset
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(strict_provenance)]
+    /// let array = [1u16, 2, 3];
+    /// let first: *const u16 = array.as_ptr();
+    /// let third = first.with_addr(first.addr() + 4);
+    /// assert_eq!(unsafe { *third }, 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "strict_provenance", issue = "95228")]
+    pub fn with_addr(self, addr: usize) -> Self
+    where
+        T: Sized,
+    {
+        // Offsetting from `self` instead of casting `addr` to a pointer keeps
+        // the provenance of `self`.
+        let offset = (addr as isize).wrapping_sub(self.addr() as isize);
+        self.cast::<u8>().wrapping_offset(offset).cast::<T>()
+    }
+
+    /// Creates a new pointer by mapping the address of `self` with `f`,
+    /// keeping the [provenance](crate::ptr#provenance) of `self`.
+    ///
+    /// This is a convenience for `self.with_addr(f(self.addr()))`, and the
+    /// natural way to set or clear tag bits in an aligned pointer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(strict_provenance)]
+    /// let value = 17u32;
+    /// let ptr: *const u32 = &value;
+    ///
+    /// // `u32` is aligned to 4 bytes, so the lowest bit is free for a tag.
+    /// let tagged = ptr.map_addr(|addr| addr | 1);
+    /// assert_eq!(tagged.addr() & 1, 1);
+    ///
+    /// let untagged = tagged.map_addr(|addr| addr & !1);
+    /// assert_eq!(unsafe { *untagged }, 17);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "strict_provenance", issue = "95228")]
+    pub fn map_addr(self, f: impl FnOnce(usize) -> usize) -> Self
+    where
+        T: Sized,
+    {
+        self.with_addr(f(self.addr()))
+    }
+
     /// Decompose a (possibly wide) pointer into is address and metadata components.
     ///
     /// The pointer can be later reconstructed with [`from_raw_parts`].
@@ -63,6 +63,36 @@
 //! separate allocated object), heap allocations (each allocation created by the global allocator is
 //! a separate allocated object), and `static` variables.
 //!
+//! ## Provenance
+//!
+//! A pointer is more than an address: it also carries *provenance*, which
+//! records which allocated object(s) the pointer is allowed to access. A
+//! pointer that was derived from a reference or from an allocation (for
+//! example by [`offset`] or a field projection) keeps the provenance of what
+//! it was derived from. Two pointers with the same address may still differ in
+//! what they are allowed to access, and the compiler is allowed to optimize
+//! based on that.
+//!
+//! Casting a pointer to an integer and back (`ptr as usize as *const T`)
+//! forces the compiler and tools like Miri to guess which provenance the
+//! resulting pointer should have. Code that only needs to inspect or change
+//! the address of a pointer, e.g. to pack a tag into the low bits of an
+//! aligned pointer, can instead use the following APIs, which never lose
+//! track of the provenance (unstable, `strict_provenance`):
+//!
+//! * [`addr`] returns the address of a pointer, without exposing its
+//!   provenance.
+//! * [`with_addr`] creates a pointer with a different address but the
+//!   provenance of `self`, and [`map_addr`] does the same based on the old
+//!   address.
+//! * [`invalid`] and [`invalid_mut`] create a pointer *without* provenance.
+//!   Such a pointer can never be used to access memory, but it is still
+//!   useful as a sentinel value or for zero-sized accesses.
+//!
+//! [`addr`]: pointer::addr
+//! [`with_addr`]: pointer::with_addr
+//! [`map_addr`]: pointer::map_addr
+//!
 //! [aliasing]: ../../nomicon/aliasing.html
 //! [book]: ../../book/ch19-01-unsafe-rust.html#dereferencing-a-raw-pointer
 //! [ub]: ../../reference/behavior-considered-undefined.html
@@ -235,6 +265,52 @@ pub const fn null_mut<T>() -> *mut T {
     0 as *mut T
 }
 
+/// Creates an invalid pointer with the given address.
+///
+/// The returned pointer has no [provenance](crate::ptr#provenance), so it can
+/// never be used to read or write memory other than through zero-sized
+/// accesses. This is useful for sentinel values and for the dangling
+/// pointers of zero-sized types, and unlike `addr as *const T` it makes that
+/// intent explicit.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(strict_provenance)]
+/// use std::ptr;
+///
+/// let sentinel: *const u32 = ptr::invalid(usize::MAX);
+/// assert_eq!(sentinel.addr(), usize::MAX);
+/// ```
+#[inline(always)]
+#[must_use]
+#[unstable(feature = "strict_provenance", issue = "95228")]
+#[rustc_const_unstable(feature = "strict_provenance", issue = "95228")]
+pub const fn invalid<T>(addr: usize) -> *const T {
+    addr as *const T
+}
+
+/// Creates an invalid mutable pointer with the given address.
+///
+/// This is the `*mut T` counterpart of [`invalid`].
+///
+/// # Examples
+///
+/// ```
+/// #![feature(strict_provenance)]
+/// use std::ptr;
+///
+/// let sentinel: *mut u32 = ptr::invalid_mut(usize::MAX);
+/// assert_eq!(sentinel.addr(), usize::MAX);
+/// ```
+#[inline(always)]
+#[must_use]
+#[unstable(feature = "strict_provenance", issue = "95228")]
+#[rustc_const_unstable(feature = "strict_provenance", issue = "95228")]
+pub const fn invalid_mut<T>(addr: usize) -> *mut T {
+    addr as *mut T
+}
+
 /// Forms a raw slice from a pointer and a length.
 ///
 /// The `len` argument is the number of **elements**, not the number of bytes.
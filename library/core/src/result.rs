@@ -836,6 +836,61 @@ pub fn map_err<F, O: FnOnce(E) -> F>(self, op: O) -> Result<T, F> {
         }
     }
 
+    /// Calls the provided closure with a reference to the contained value (if [`Ok`]).
+    ///
+    /// Returns the result unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(result_option_inspect)]
+    ///
+    /// let x: u8 = "4"
+    ///     .parse::<u8>()
+    ///     .inspect(|x| println!("original: {}", x))
+    ///     .map(|x| x.pow(3))
+    ///     .expect("failed to parse number");
+    /// assert_eq!(x, 64);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "result_option_inspect", issue = "91345")]
+    pub fn inspect<F: FnOnce(&T)>(self, f: F) -> Self {
+        if let Ok(ref t) = self {
+            f(t);
+        }
+
+        self
+    }
+
+    /// Calls the provided closure with a reference to the contained error (if [`Err`]).
+    ///
+    /// Returns the result unchanged. This is handy for reporting an error
+    /// right before propagating it with `?`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(result_option_inspect)]
+    ///
+    /// use std::{fs, io};
+    ///
+    /// fn read() -> io::Result<String> {
+    ///     fs::read_to_string("address.txt")
+    ///         .inspect_err(|e| eprintln!("failed to read file: {}", e))
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "result_option_inspect", issue = "91345")]
+    pub fn inspect_err<F: FnOnce(&E)>(self, f: F) -> Self {
+        if let Err(ref e) = self {
+            f(e);
+        }
+
+        self
+    }
+
     /////////////////////////////////////////////////////////////////////////
     // Iterator constructors
     /////////////////////////////////////////////////////////////////////////
@@ -1550,10 +1605,35 @@ impl<T, E> Result<Result<T, E>, E> {
     /// assert_eq!(Ok(Ok("hello")), x.flatten());
     /// assert_eq!(Ok("hello"), x.flatten().flatten());
     /// ```
+    ///
+    /// This is handy when a fallible operation is applied to the value of
+    /// another one with the same error type, which would otherwise need an
+    /// `and_then(|r| r)`:
+    ///
+    /// ```
+    /// #![feature(result_flattening)]
+    /// fn setting(name: &str) -> Result<&'static str, String> {
+    ///     match name {
+    ///         "port" => Ok("8080"),
+    ///         "timeout" => Ok("soon"),
+    ///         _ => Err(format!("no setting named `{}`", name)),
+    ///     }
+    /// }
+    ///
+    /// let parse = |name| setting(name).map(|s| s.parse::<u16>().map_err(|e| e.to_string()));
+    ///
+    /// assert_eq!(parse("port").flatten(), Ok(8080));
+    /// assert_eq!(parse("timeout").flatten(), Err("invalid digit found in string".to_string()));
+    /// assert_eq!(parse("retries").flatten(), Err("no setting named `retries`".to_string()));
+    /// ```
     #[inline]
     #[unstable(feature = "result_flattening", issue = "70142")]
-    pub fn flatten(self) -> Result<T, E> {
-        self.and_then(convert::identity)
+    #[rustc_const_unstable(feature = "const_result", issue = "82814")]
+    pub const fn flatten(self) -> Result<T, E> {
+        match self {
+            Ok(inner) => inner,
+            Err(e) => Err(e),
+        }
     }
 }
 
@@ -1898,3 +1978,8 @@ fn from_residual(residual: Result<convert::Infallible, E>) -> Self {
         }
     }
 }
+
+#[unstable(feature = "try_trait_v2_residual", issue = "91285")]
+impl<T, E> ops::Residual<T> for Result<convert::Infallible, E> {
+    type TryType = Result<T, E>;
+}
@@ -60,7 +60,7 @@
 pub use iter::ArrayWindows;
 
 #[unstable(feature = "slice_group_by", issue = "80552")]
-pub use iter::{GroupBy, GroupByMut};
+pub use iter::{ChunkBy, ChunkByMut};
 
 #[stable(feature = "split_inclusive", since = "1.51.0")]
 pub use iter::{SplitInclusive, SplitInclusiveMut};
@@ -542,6 +542,70 @@ pub const fn as_mut_ptr_range(&mut self) -> Range<*mut T> {
         start..end
     }
 
+    /// Returns the range of indices that a subslice points to.
+    ///
+    /// Returns `None` if `inner` does not point within `self`, that is, if
+    /// its elements are not a part of the same memory as the elements of
+    /// `self`. This is purely a comparison of addresses: a slice that merely
+    /// compares equal to a part of `self` but lives in a different allocation
+    /// is *not* considered a subslice, while an empty slice that happens to
+    /// point at the start or one past the end of `self` is.
+    ///
+    /// This is useful to recover the position of a subslice, for example one
+    /// obtained from [`split`] or from a parser, without resorting to raw
+    /// pointer arithmetic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is zero-sized, since the elements of a zero-sized slice
+    /// all have the same address and their indices cannot be recovered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(substr_range)]
+    ///
+    /// let nums = &[0, 5, 10, 0, 0, 5];
+    ///
+    /// let mut iter = nums
+    ///     .split(|t| *t == 0)
+    ///     .map(|n| nums.subslice_range(n).unwrap());
+    ///
+    /// assert_eq!(iter.next(), Some(0..0));
+    /// assert_eq!(iter.next(), Some(1..3));
+    /// assert_eq!(iter.next(), Some(4..4));
+    /// assert_eq!(iter.next(), Some(5..6));
+    ///
+    /// // Equal contents are not enough, the subslice must share memory.
+    /// let copy = [5, 10];
+    /// assert_eq!(nums.subslice_range(&copy), None);
+    /// ```
+    ///
+    /// [`split`]: slice::split
+    #[unstable(feature = "substr_range", issue = "126769")]
+    pub fn subslice_range(&self, inner: &[T]) -> Option<Range<usize>> {
+        let elem_size = mem::size_of::<T>();
+        if elem_size == 0 {
+            panic!("elements are zero-sized");
+        }
+
+        let self_start = self.as_ptr() as usize;
+        let inner_start = inner.as_ptr() as usize;
+        // If `inner` starts before `self` this wraps around to a huge offset,
+        // which the bounds check below rejects.
+        let byte_offset = inner_start.wrapping_sub(self_start);
+        if byte_offset % elem_size != 0 {
+            return None;
+        }
+
+        let start = byte_offset / elem_size;
+        if start <= self.len() && inner.len() <= self.len() - start {
+            Some(start..start + inner.len())
+        } else {
+            None
+        }
+    }
+
     /// Swaps two elements in the slice.
     ///
     /// # Arguments
@@ -1379,7 +1443,10 @@ pub fn rchunks_exact_mut(&mut self, chunk_size: usize) -> RChunksExactMut<'_, T>
     }
 
     /// Returns an iterator over the slice producing non-overlapping runs
-    /// of elements using the predicate to separate them.
+    /// of elements using the predicate to separate them. Each run is as
+    /// long as possible: the predicate holds for every pair of adjacent
+    /// elements within it, and fails between the last element of a run and
+    /// the first element of the next one.
     ///
     /// The predicate is called on two elements following themselves,
     /// it means the predicate is called on `slice[0]` and `slice[1]`
@@ -1392,7 +1459,7 @@ pub fn rchunks_exact_mut(&mut self, chunk_size: usize) -> RChunksExactMut<'_, T>
     ///
     /// let slice = &[1, 1, 1, 3, 3, 2, 2, 2];
     ///
-    /// let mut iter = slice.group_by(|a, b| a == b);
+    /// let mut iter = slice.chunk_by(|a, b| a == b);
     ///
     /// assert_eq!(iter.next(), Some(&[1, 1, 1][..]));
     /// assert_eq!(iter.next(), Some(&[3, 3][..]));
@@ -1407,7 +1474,7 @@ pub fn rchunks_exact_mut(&mut self, chunk_size: usize) -> RChunksExactMut<'_, T>
     ///
     /// let slice = &[1, 1, 2, 3, 2, 3, 2, 3, 4];
     ///
-    /// let mut iter = slice.group_by(|a, b| a <= b);
+    /// let mut iter = slice.chunk_by(|a, b| a <= b);
     ///
     /// assert_eq!(iter.next(), Some(&[1, 1, 2, 3][..]));
     /// assert_eq!(iter.next(), Some(&[2, 3][..]));
@@ -1416,15 +1483,18 @@ pub fn rchunks_exact_mut(&mut self, chunk_size: usize) -> RChunksExactMut<'_, T>
     /// ```
     #[unstable(feature = "slice_group_by", issue = "80552")]
     #[inline]
-    pub fn group_by<F>(&self, pred: F) -> GroupBy<'_, T, F>
+    pub fn chunk_by<F>(&self, pred: F) -> ChunkBy<'_, T, F>
     where
         F: FnMut(&T, &T) -> bool,
     {
-        GroupBy::new(self, pred)
+        ChunkBy::new(self, pred)
     }
 
     /// Returns an iterator over the slice producing non-overlapping mutable
-    /// runs of elements using the predicate to separate them.
+    /// runs of elements using the predicate to separate them. Each run is as
+    /// long as possible: the predicate holds for every pair of adjacent
+    /// elements within it, and fails between the last element of a run and
+    /// the first element of the next one.
     ///
     /// The predicate is called on two elements following themselves,
     /// it means the predicate is called on `slice[0]` and `slice[1]`
@@ -1437,7 +1507,7 @@ pub fn group_by<F>(&self, pred: F) -> GroupBy<'_, T, F>
     ///
     /// let slice = &mut [1, 1, 1, 3, 3, 2, 2, 2];
     ///
-    /// let mut iter = slice.group_by_mut(|a, b| a == b);
+    /// let mut iter = slice.chunk_by_mut(|a, b| a == b);
     ///
     /// assert_eq!(iter.next(), Some(&mut [1, 1, 1][..]));
     /// assert_eq!(iter.next(), Some(&mut [3, 3][..]));
@@ -1452,7 +1522,7 @@ pub fn group_by<F>(&self, pred: F) -> GroupBy<'_, T, F>
     ///
     /// let slice = &mut [1, 1, 2, 3, 2, 3, 2, 3, 4];
     ///
-    /// let mut iter = slice.group_by_mut(|a, b| a <= b);
+    /// let mut iter = slice.chunk_by_mut(|a, b| a <= b);
     ///
     /// assert_eq!(iter.next(), Some(&mut [1, 1, 2, 3][..]));
     /// assert_eq!(iter.next(), Some(&mut [2, 3][..]));
@@ -1461,11 +1531,11 @@ pub fn group_by<F>(&self, pred: F) -> GroupBy<'_, T, F>
     /// ```
     #[unstable(feature = "slice_group_by", issue = "80552")]
     #[inline]
-    pub fn group_by_mut<F>(&mut self, pred: F) -> GroupByMut<'_, T, F>
+    pub fn chunk_by_mut<F>(&mut self, pred: F) -> ChunkByMut<'_, T, F>
     where
         F: FnMut(&T, &T) -> bool,
     {
-        GroupByMut::new(self, pred)
+        ChunkByMut::new(self, pred)
     }
 
     /// Divides one slice into two at an index.
@@ -1635,6 +1705,171 @@ unsafe fn split_at_mut_unchecked(&mut self, mid: usize) -> (&mut [T], &mut [T])
         unsafe { (from_raw_parts_mut(ptr, mid), from_raw_parts_mut(ptr.add(mid), len - mid)) }
     }
 
+    /// Divides one slice into an array and a remainder slice at an index.
+    ///
+    /// The array will contain all indices from `[0, N)` (excluding
+    /// the index `N` itself) and the slice will contain all
+    /// indices from `[N, len)` (excluding the index `len` itself).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(split_array)]
+    ///
+    /// let v = &[1, 2, 3, 4, 5, 6][..];
+    ///
+    /// {
+    ///    let (left, right) = v.split_array_ref::<0>();
+    ///    assert_eq!(left, &[]);
+    ///    assert_eq!(right, [1, 2, 3, 4, 5, 6]);
+    /// }
+    ///
+    /// {
+    ///     let (left, right) = v.split_array_ref::<2>();
+    ///     assert_eq!(left, &[1, 2]);
+    ///     assert_eq!(right, [3, 4, 5, 6]);
+    /// }
+    ///
+    /// {
+    ///     let (left, right) = v.split_array_ref::<6>();
+    ///     assert_eq!(left, &[1, 2, 3, 4, 5, 6]);
+    ///     assert_eq!(right, []);
+    /// }
+    /// ```
+    ///
+    /// Parsing a buffer that starts with a big-endian length prefix:
+    ///
+    /// ```
+    /// #![feature(split_array)]
+    ///
+    /// let buf = [0, 0, 0, 3, b'a', b'b', b'c', 0xff];
+    /// let (len, rest) = buf[..].split_array_ref::<4>();
+    /// let len = u32::from_be_bytes(*len) as usize;
+    /// let (payload, trailer) = rest.split_at(len);
+    /// assert_eq!(payload, b"abc");
+    /// assert_eq!(trailer, [0xff]);
+    /// ```
+    #[unstable(feature = "split_array", reason = "new API", issue = "90091")]
+    #[inline]
+    pub fn split_array_ref<const N: usize>(&self) -> (&[T; N], &[T]) {
+        let (a, b) = self.split_at(N);
+        // SAFETY: a points to [T; N]? Yes it's [T] of length N (checked by split_at)
+        unsafe { (&*(a.as_ptr() as *const [T; N]), b) }
+    }
+
+    /// Divides one mutable slice into an array and a remainder slice at an index.
+    ///
+    /// The array will contain all indices from `[0, N)` (excluding
+    /// the index `N` itself) and the slice will contain all
+    /// indices from `[N, len)` (excluding the index `len` itself).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(split_array)]
+    ///
+    /// let mut v = &mut [1, 0, 3, 0, 5, 6][..];
+    /// let (left, right) = v.split_array_mut::<2>();
+    /// assert_eq!(left, &mut [1, 0]);
+    /// assert_eq!(right, [3, 0, 5, 6]);
+    /// left[1] = 2;
+    /// right[1] = 4;
+    /// assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[unstable(feature = "split_array", reason = "new API", issue = "90091")]
+    #[inline]
+    pub fn split_array_mut<const N: usize>(&mut self) -> (&mut [T; N], &mut [T]) {
+        let (a, b) = self.split_at_mut(N);
+        // SAFETY: a points to [T; N]? Yes it's [T] of length N (checked by split_at_mut)
+        unsafe { (&mut *(a.as_mut_ptr() as *mut [T; N]), b) }
+    }
+
+    /// Divides one slice into an array and a remainder slice at an index from
+    /// the end.
+    ///
+    /// The slice will contain all indices from `[0, len - N)` (excluding
+    /// the index `len - N` itself) and the array will contain all
+    /// indices from `[len - N, len)` (excluding the index `len` itself).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(split_array)]
+    ///
+    /// let v = &[1, 2, 3, 4, 5, 6][..];
+    ///
+    /// {
+    ///    let (left, right) = v.rsplit_array_ref::<0>();
+    ///    assert_eq!(left, [1, 2, 3, 4, 5, 6]);
+    ///    assert_eq!(right, &[]);
+    /// }
+    ///
+    /// {
+    ///     let (left, right) = v.rsplit_array_ref::<2>();
+    ///     assert_eq!(left, [1, 2, 3, 4]);
+    ///     assert_eq!(right, &[5, 6]);
+    /// }
+    ///
+    /// {
+    ///     let (left, right) = v.rsplit_array_ref::<6>();
+    ///     assert_eq!(left, []);
+    ///     assert_eq!(right, &[1, 2, 3, 4, 5, 6]);
+    /// }
+    /// ```
+    #[unstable(feature = "split_array", reason = "new API", issue = "90091")]
+    #[inline]
+    pub fn rsplit_array_ref<const N: usize>(&self) -> (&[T], &[T; N]) {
+        assert!(N <= self.len());
+        let (a, b) = self.split_at(self.len() - N);
+        // SAFETY: b points to [T; N]? Yes it's [T] of length N (checked by split_at)
+        unsafe { (a, &*(b.as_ptr() as *const [T; N])) }
+    }
+
+    /// Divides one mutable slice into an array and a remainder slice at an
+    /// index from the end.
+    ///
+    /// The slice will contain all indices from `[0, len - N)` (excluding
+    /// the index `len - N` itself) and the array will contain all
+    /// indices from `[len - N, len)` (excluding the index `len` itself).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(split_array)]
+    ///
+    /// let mut v = &mut [1, 0, 3, 0, 5, 6][..];
+    /// let (left, right) = v.rsplit_array_mut::<4>();
+    /// assert_eq!(left, [1, 0]);
+    /// assert_eq!(right, &mut [3, 0, 5, 6]);
+    /// left[1] = 2;
+    /// right[1] = 4;
+    /// assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[unstable(feature = "split_array", reason = "new API", issue = "90091")]
+    #[inline]
+    pub fn rsplit_array_mut<const N: usize>(&mut self) -> (&mut [T], &mut [T; N]) {
+        assert!(N <= self.len());
+        let (a, b) = self.split_at_mut(self.len() - N);
+        // SAFETY: b points to [T; N]? Yes it's [T] of length N (checked by split_at_mut)
+        unsafe { (a, &mut *(b.as_mut_ptr() as *mut [T; N])) }
+    }
+
     /// Returns an iterator over subslices separated by elements that match
     /// `pred`. The matched element is not contained in the subslices.
     ///
@@ -2601,8 +2836,9 @@ pub fn select_nth_unstable_by_key<K, F>(
     /// Moves all consecutive repeated elements to the end of the slice according to the
     /// [`PartialEq`] trait implementation.
     ///
-    /// Returns two slices. The first contains no consecutive repeated elements.
-    /// The second contains all the duplicates in no specified order.
+    /// Returns two slices. The first contains no consecutive repeated elements,
+    /// in the same relative order as in the original slice. The second contains
+    /// all the duplicates in no specified order.
     ///
     /// If the slice is sorted, the first returned slice contains no duplicates.
     ///
@@ -2630,8 +2866,9 @@ pub fn partition_dedup(&mut self) -> (&mut [T], &mut [T])
     /// Moves all but the first of consecutive elements to the end of the slice satisfying
     /// a given equality relation.
     ///
-    /// Returns two slices. The first contains no consecutive repeated elements.
-    /// The second contains all the duplicates in no specified order.
+    /// Returns two slices. The first contains no consecutive repeated elements,
+    /// in the same relative order as in the original slice. The second contains
+    /// all the duplicates in no specified order.
     ///
     /// The `same_bucket` function is passed references to two elements from the slice and
     /// must determine if the elements compare equal. The elements are passed in opposite order
@@ -2761,8 +2998,9 @@ pub fn partition_dedup_by<F>(&mut self, mut same_bucket: F) -> (&mut [T], &mut [
     /// Moves all but the first of consecutive elements to the end of the slice that resolve
     /// to the same key.
     ///
-    /// Returns two slices. The first contains no consecutive repeated elements.
-    /// The second contains all the duplicates in no specified order.
+    /// Returns two slices. The first contains no consecutive repeated elements,
+    /// in the same relative order as in the original slice. The second contains
+    /// all the duplicates in no specified order.
     ///
     /// If the slice is sorted, the first returned slice contains no duplicates.
     ///
@@ -1400,7 +1400,7 @@ fn next(&mut self) -> Option<u16> {
             return Some(tmp);
         }
 
-        let mut buf = [0; 2];
+        let mut buf = [0; char::MAX_LEN_UTF16];
         self.chars.next().map(|ch| {
             let n = ch.encode_utf16(&mut buf).len();
             if n == 2 {
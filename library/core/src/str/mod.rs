@@ -17,6 +17,7 @@
 
 use crate::char::{self, EscapeDebugExtArgs};
 use crate::mem;
+use crate::ops::Range;
 use crate::slice::{self, SliceIndex};
 
 pub mod pattern;
@@ -216,6 +217,98 @@ pub fn is_char_boundary(&self, index: usize) -> bool {
         }
     }
 
+    /// Finds the closest `x` not exceeding `index` where `is_char_boundary(x)` is `true`.
+    ///
+    /// This method can help you truncate a string so that it's still valid UTF-8, but doesn't
+    /// exceed a given number of bytes. Note that this is done purely at the character level
+    /// and can still visually split graphemes, even though the underlying characters aren't
+    /// split. For example, the emoji 🧑‍🔬 (scientist) could be split so that the string only
+    /// includes 🧑 (person) instead.
+    ///
+    /// If `index` is greater than the length of the string, this returns the length of the
+    /// string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(round_char_boundary)]
+    /// let s = "❤️🧡💛💚💙💜";
+    /// assert_eq!(s.len(), 26);
+    /// assert!(!s.is_char_boundary(13));
+    ///
+    /// let closest = s.floor_char_boundary(13);
+    /// assert_eq!(closest, 10);
+    /// assert_eq!(&s[..closest], "❤️🧡");
+    /// ```
+    ///
+    /// Truncating a string to fit into a byte budget:
+    ///
+    /// ```
+    /// #![feature(round_char_boundary)]
+    /// let name = "Zoë Straße";
+    /// let budget = 10;
+    /// // byte 10 is in the middle of `ß`, so it gets cut off entirely
+    /// let truncated = &name[..name.floor_char_boundary(budget)];
+    /// assert_eq!(truncated, "Zoë Stra");
+    /// assert!(truncated.len() <= budget);
+    /// ```
+    #[unstable(feature = "round_char_boundary", issue = "93743")]
+    #[inline]
+    pub fn floor_char_boundary(&self, index: usize) -> usize {
+        if index >= self.len() {
+            self.len()
+        } else {
+            // A UTF-8 sequence is at most four bytes long, so a boundary is
+            // always found among `index` and the three bytes before it.
+            let lower_bound = index.saturating_sub(3);
+            let new_index = self.as_bytes()[lower_bound..=index]
+                .iter()
+                .rposition(|&b| !validations::utf8_is_cont_byte(b));
+
+            // SAFETY: we know that the character boundary will be within four bytes
+            unsafe { lower_bound + new_index.unwrap_unchecked() }
+        }
+    }
+
+    /// Finds the closest `x` not below `index` where `is_char_boundary(x)` is `true`.
+    ///
+    /// This method is the natural complement to [`floor_char_boundary`]. See that method
+    /// for more details.
+    ///
+    /// If `index` is greater than the length of the string, this returns the length of the
+    /// string, the same as [`floor_char_boundary`] does.
+    ///
+    /// [`floor_char_boundary`]: str::floor_char_boundary
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(round_char_boundary)]
+    /// let s = "❤️🧡💛💚💙💜";
+    /// assert_eq!(s.len(), 26);
+    /// assert!(!s.is_char_boundary(13));
+    ///
+    /// let closest = s.ceil_char_boundary(13);
+    /// assert_eq!(closest, 14);
+    /// assert_eq!(&s[..closest], "❤️🧡💛");
+    /// ```
+    #[unstable(feature = "round_char_boundary", issue = "93743")]
+    #[inline]
+    pub fn ceil_char_boundary(&self, index: usize) -> usize {
+        if index >= self.len() {
+            self.len()
+        } else {
+            // The end of the string counts as a boundary, so if none of the
+            // (at most four) bytes starting at `index` begins a character, the
+            // sequence must run to the end of the string.
+            let upper_bound = Ord::min(index + 4, self.len());
+            self.as_bytes()[index..upper_bound]
+                .iter()
+                .position(|&b| !validations::utf8_is_cont_byte(b))
+                .map_or(upper_bound, |pos| pos + index)
+        }
+    }
+
     /// Converts a string slice to a byte slice. To convert the byte slice back
     /// into a string slice, use the [`from_utf8`] function.
     ///
@@ -323,6 +416,41 @@ pub fn as_mut_ptr(&mut self) -> *mut u8 {
         self as *mut str as *mut u8
     }
 
+    /// Returns the range of byte offsets that a substring points to.
+    ///
+    /// Returns `None` if `inner` does not point within `self`, that is, if
+    /// its bytes are not a part of the same memory as the bytes of `self`.
+    /// This is purely a comparison of addresses: a string that merely
+    /// compares equal to a part of `self` but lives in a different allocation
+    /// is *not* considered a substring.
+    ///
+    /// This is useful to recover the position of a substring obtained from
+    /// [`split`], [`lines`] or a parser, for example for error reporting.
+    ///
+    /// [`split`]: str::split
+    /// [`lines`]: str::lines
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(substr_range)]
+    ///
+    /// let data = "a, b, b, a";
+    /// let mut iter = data.split(", ").map(|s| data.substr_range(s).unwrap());
+    ///
+    /// assert_eq!(iter.next(), Some(0..1));
+    /// assert_eq!(iter.next(), Some(3..4));
+    /// assert_eq!(iter.next(), Some(6..7));
+    /// assert_eq!(iter.next(), Some(9..10));
+    ///
+    /// // Equal contents are not enough, the substring must share memory.
+    /// assert_eq!(data.substr_range(&String::from("b")), None);
+    /// ```
+    #[unstable(feature = "substr_range", issue = "126769")]
+    pub fn substr_range(&self, inner: &str) -> Option<Range<usize>> {
+        self.as_bytes().subslice_range(inner.as_bytes())
+    }
+
     /// Returns a subslice of `str`.
     ///
     /// This is the non-panicking alternative to indexing the `str`. Returns
@@ -2259,6 +2387,39 @@ pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
         self.as_bytes().eq_ignore_ascii_case(other.as_bytes())
     }
 
+    /// Checks that two strings are a Unicode case-insensitive match.
+    ///
+    /// Both strings are compared `char` by `char` after applying
+    /// [`char::to_simple_case_folded`], without allocating. Because this uses
+    /// simple case folding, every `char` folds to exactly one `char`: foldings
+    /// that expand to several characters are not applied, so `"ß"` does not
+    /// match `"SS"`. The comparison is independent of the locale, so the Turkic
+    /// dotted and dotless `i` never match `I` and `i` respectively. It also
+    /// does not normalize its inputs.
+    ///
+    /// Strings can match even if their lengths in bytes differ, since a `char`
+    /// and its folding may have UTF-8 encodings of different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_eq_ignore_case)]
+    ///
+    /// assert!("Ferris".eq_ignore_case("FERRIS"));
+    /// assert!("Ferrös".eq_ignore_case("FERRÖS"));
+    /// assert!("ΣΊΣΥΦΟΣ".eq_ignore_case("σίσυφος"));
+    /// // 'K' (the Kelvin sign) is three bytes long, 'k' is one.
+    /// assert!("\u{212A}elvin".eq_ignore_case("kelvin"));
+    ///
+    /// assert!(!"straße".eq_ignore_case("STRASSE"));
+    /// ```
+    #[unstable(feature = "str_eq_ignore_case", issue = "none")]
+    pub fn eq_ignore_case(&self, other: &str) -> bool {
+        self.chars()
+            .map(char::to_simple_case_folded)
+            .eq(other.chars().map(char::to_simple_case_folded))
+    }
+
     /// Converts this string to its ASCII upper case equivalent in-place.
     ///
     /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z',
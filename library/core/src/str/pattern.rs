@@ -533,7 +533,7 @@ impl<'a> Pattern<'a> for char {
 
     #[inline]
     fn into_searcher(self, haystack: &'a str) -> Self::Searcher {
-        let mut utf8_encoded = [0; 4];
+        let mut utf8_encoded = [0; char::MAX_LEN_UTF8];
         let utf8_size = self.encode_utf8(&mut utf8_encoded).len();
         CharSearcher {
             haystack,
@@ -550,19 +550,19 @@ fn is_contained_in(self, haystack: &'a str) -> bool {
         if (self as u32) < 128 {
             haystack.as_bytes().contains(&(self as u8))
         } else {
-            let mut buffer = [0u8; 4];
+            let mut buffer = [0u8; char::MAX_LEN_UTF8];
             self.encode_utf8(&mut buffer).is_contained_in(haystack)
         }
     }
 
     #[inline]
     fn is_prefix_of(self, haystack: &'a str) -> bool {
-        self.encode_utf8(&mut [0u8; 4]).is_prefix_of(haystack)
+        self.encode_utf8(&mut [0u8; char::MAX_LEN_UTF8]).is_prefix_of(haystack)
     }
 
     #[inline]
     fn strip_prefix_of(self, haystack: &'a str) -> Option<&'a str> {
-        self.encode_utf8(&mut [0u8; 4]).strip_prefix_of(haystack)
+        self.encode_utf8(&mut [0u8; char::MAX_LEN_UTF8]).strip_prefix_of(haystack)
     }
 
     #[inline]
@@ -570,7 +570,7 @@ fn is_suffix_of(self, haystack: &'a str) -> bool
     where
         Self::Searcher: ReverseSearcher<'a>,
     {
-        self.encode_utf8(&mut [0u8; 4]).is_suffix_of(haystack)
+        self.encode_utf8(&mut [0u8; char::MAX_LEN_UTF8]).is_suffix_of(haystack)
     }
 
     #[inline]
@@ -578,7 +578,7 @@ fn strip_suffix_of(self, haystack: &'a str) -> Option<&'a str>
     where
         Self::Searcher: ReverseSearcher<'a>,
     {
-        self.encode_utf8(&mut [0u8; 4]).strip_suffix_of(haystack)
+        self.encode_utf8(&mut [0u8; char::MAX_LEN_UTF8]).strip_suffix_of(haystack)
     }
 }
 
@@ -122,6 +122,8 @@
 //! warning: unused result that must be used: streams do nothing unless polled
 //! ```
 
+mod next;
 mod stream;
 
+pub use next::{next, Next};
 pub use stream::Stream;
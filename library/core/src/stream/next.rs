@@ -0,0 +1,69 @@
+use crate::future::Future;
+use crate::pin::Pin;
+use crate::stream::Stream;
+use crate::task::{Context, Poll};
+
+/// Returns a future that resolves to the next item of `stream`, or to `None`
+/// once the stream is exhausted.
+///
+/// This is the asynchronous equivalent of [`Iterator::next`]. It needs the
+/// stream to be [`Unpin`]; to use it with a stream that isn't, pin the stream
+/// first and pass the resulting `Pin<&mut S>`, which is itself a stream.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_stream)]
+/// # async fn run() {
+/// use core::pin::Pin;
+/// use core::stream::{self, Stream};
+/// use core::task::{Context, Poll};
+///
+/// struct Countdown(u32);
+///
+/// impl Stream for Countdown {
+///     type Item = u32;
+///
+///     fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<u32>> {
+///         if self.0 == 0 {
+///             Poll::Ready(None)
+///         } else {
+///             self.0 -= 1;
+///             Poll::Ready(Some(self.0 + 1))
+///         }
+///     }
+/// }
+///
+/// let mut countdown = Countdown(2);
+/// assert_eq!(stream::next(&mut countdown).await, Some(2));
+/// assert_eq!(stream::next(&mut countdown).await, Some(1));
+/// assert_eq!(stream::next(&mut countdown).await, None);
+/// # }
+/// ```
+#[unstable(feature = "async_stream", issue = "79024")]
+pub fn next<S>(stream: &mut S) -> Next<'_, S>
+where
+    S: ?Sized + Stream + Unpin,
+{
+    Next { stream }
+}
+
+/// A future that resolves to the next item of a stream.
+///
+/// This `struct` is created by the [`next()`] function. See its
+/// documentation for more.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[unstable(feature = "async_stream", issue = "79024")]
+pub struct Next<'a, S: ?Sized> {
+    stream: &'a mut S,
+}
+
+#[unstable(feature = "async_stream", issue = "79024")]
+impl<S: ?Sized + Stream + Unpin> Future for Next<'_, S> {
+    type Output = Option<S::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.stream).poll_next(cx)
+    }
+}
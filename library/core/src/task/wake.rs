@@ -42,6 +42,17 @@ impl RawWaker {
     pub const fn new(data: *const (), vtable: &'static RawWakerVTable) -> RawWaker {
         RawWaker { data, vtable }
     }
+
+    /// A `RawWaker` whose vtable functions do nothing.
+    const NOOP: RawWaker = {
+        unsafe fn clone(_: *const ()) -> RawWaker {
+            RawWaker::NOOP
+        }
+        unsafe fn noop(_: *const ()) {}
+
+        const VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        RawWaker::new(crate::ptr::null(), &VTABLE)
+    };
 }
 
 /// A virtual function pointer table (vtable) that specifies the behavior
@@ -239,10 +250,51 @@ pub fn wake_by_ref(&self) {
     /// returns `true`, it is guaranteed that the `Waker`s will awaken the same task.
     ///
     /// This function is primarily used for optimization purposes.
+    ///
+    /// Two `Waker`s whose [`RawWaker`]s have the same data pointer and equal
+    /// vtables always compare as waking the same task; this holds in particular
+    /// for a `Waker` and its clones when the vtable's `clone` function returns
+    /// the data pointer and vtable it was given. Executors may rely on this to
+    /// skip re-registering a waker that [`will_wake`] the one they already
+    /// hold. The check only compares pointers in the common case, so it is
+    /// cheap to perform on every poll.
+    ///
+    /// [`will_wake`]: Waker::will_wake
     #[inline]
     #[stable(feature = "futures_api", since = "1.36.0")]
     pub fn will_wake(&self, other: &Waker) -> bool {
-        self.waker == other.waker
+        let RawWaker { data: a_data, vtable: a_vtable } = self.waker;
+        let RawWaker { data: b_data, vtable: b_vtable } = other.waker;
+        a_data == b_data && (crate::ptr::eq(a_vtable, b_vtable) || a_vtable == b_vtable)
+    }
+
+    /// Returns a reference to a `Waker` that does nothing when used.
+    ///
+    /// This is mostly useful for writing tests that need a [`Context`] to poll
+    /// some futures, but are not expecting those futures to wake the waker or
+    /// do not need to do anything specific if it happens.
+    ///
+    /// If an owned `Waker` is needed, `clone()` this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(noop_waker)]
+    ///
+    /// use std::future::Future;
+    /// use std::task;
+    ///
+    /// let mut cx = task::Context::from_waker(task::Waker::noop());
+    ///
+    /// let mut future = Box::pin(async { 10 });
+    /// assert_eq!(future.as_mut().poll(&mut cx), task::Poll::Ready(10));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "noop_waker", issue = "98286")]
+    pub const fn noop() -> &'static Waker {
+        const WAKER: &Waker = &Waker { waker: RawWaker::NOOP };
+        WAKER
     }
 
     /// Creates a new `Waker` from [`RawWaker`].
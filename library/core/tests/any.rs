@@ -127,3 +127,52 @@ fn type_name_of_val<T>(_: T) -> &'static str {
 
     assert_ne!(type_name_of_val(Velocity), type_name_of_val(Velocity(0.0, -9.8)),);
 }
+
+#[test]
+fn any_unchecked_downcast_ref() {
+    let a = &5_usize as &dyn Any;
+    let b = &Test as &(dyn Any + Send);
+    let c = &TEST as &(dyn Any + Send + Sync);
+
+    assert!(a.is::<usize>() && b.is::<Test>() && c.is::<&'static str>());
+    // SAFETY: the types were just checked above.
+    unsafe {
+        assert_eq!(*a.downcast_ref_unchecked::<usize>(), 5);
+        assert_eq!(*b.downcast_ref_unchecked::<Test>(), Test);
+        assert_eq!(*c.downcast_ref_unchecked::<&'static str>(), "Test");
+    }
+}
+
+#[test]
+fn any_unchecked_downcast_mut() {
+    let mut a = 5_usize;
+    let mut b: Box<dyn Any + Send> = box 7_usize;
+    let mut c: Box<dyn Any + Send + Sync> = box String::from("Test");
+
+    {
+        let a_r = &mut a as &mut dyn Any;
+        assert!(a_r.is::<usize>());
+        // SAFETY: `a_r` was just checked to point to a `usize`.
+        unsafe { *a_r.downcast_mut_unchecked::<usize>() += 1 };
+    }
+    assert!(b.is::<usize>() && c.is::<String>());
+    // SAFETY: the types were just checked above.
+    unsafe {
+        *b.downcast_mut_unchecked::<usize>() *= 2;
+        c.downcast_mut_unchecked::<String>().push_str("ing");
+    }
+
+    assert_eq!(a, 6);
+    assert_eq!(b.downcast_ref::<usize>(), Some(&14));
+    assert_eq!(c.downcast_ref::<String>().map(String::as_str), Some("Testing"));
+}
+
+#[test]
+fn type_name_of_closure_val() {
+    let closure = |x: u8| x;
+    let name = type_name_of_val(&closure);
+    assert!(!name.is_empty());
+    assert_eq!(name, type_name_of_val(&closure));
+    assert_eq!(type_name_of_val(&0u8), type_name::<u8>());
+    assert_eq!(type_name_of_val("str"), type_name::<str>());
+}
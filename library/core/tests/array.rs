@@ -356,3 +356,229 @@ fn new() -> B<'a> {
     b3.a[0].set(Some(&b1));
     b3.a[1].set(Some(&b2));
 }
+
+#[test]
+fn array_from_fn() {
+    let array: [usize; 5] = core::array::from_fn(|i| i * 2);
+    assert_eq!(array, [0, 2, 4, 6, 8]);
+
+    let empty: [u8; 0] = core::array::from_fn(|_| unreachable!());
+    assert_eq!(empty, []);
+
+    let mut calls = Vec::new();
+    let _: [(); 4] = core::array::from_fn(|i| calls.push(i));
+    assert_eq!(calls, [0, 1, 2, 3]);
+}
+
+#[test]
+#[cfg(not(panic = "abort"))]
+fn array_from_fn_drops_inserted_elements_on_panic() {
+    use core::cell::Cell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    struct CountDrop<'a>(&'a Cell<usize>);
+
+    impl Drop for CountDrop<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    for panic_at in 0..5 {
+        let drop_counter = Cell::new(0);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let _: [CountDrop<'_>; 5] = core::array::from_fn(|idx| {
+                if idx == panic_at {
+                    panic!("peek a boo");
+                }
+                CountDrop(&drop_counter)
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(drop_counter.get(), panic_at);
+    }
+
+    let drop_counter = Cell::new(0);
+    let array: [CountDrop<'_>; 5] = core::array::from_fn(|_| CountDrop(&drop_counter));
+    assert_eq!(drop_counter.get(), 0);
+    drop(array);
+    assert_eq!(drop_counter.get(), 5);
+}
+
+#[test]
+fn array_try_from_fn() {
+    #[derive(Debug, PartialEq)]
+    enum SomeError {
+        Foo,
+    }
+
+    let array = core::array::try_from_fn(|i| Ok::<_, SomeError>(i));
+    assert_eq!(array, Ok([0, 1, 2, 3, 4]));
+
+    let another_array = core::array::try_from_fn::<_, _, (), 2>(|_| Err(SomeError::Foo));
+    assert_eq!(another_array, Err(SomeError::Foo));
+
+    let empty = core::array::try_from_fn::<(), _, u8, 0>(|_| unreachable!());
+    assert_eq!(empty, Ok([]));
+}
+
+#[test]
+fn array_try_from_fn_drops_inserted_elements_on_err() {
+    use core::cell::Cell;
+
+    struct CountDrop<'a>(&'a Cell<usize>);
+
+    impl Drop for CountDrop<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    for fail_at in 0..6 {
+        let drop_counter = Cell::new(0);
+        let result = core::array::try_from_fn::<(), _, _, 5>(|idx| {
+            if idx == fail_at { Err(()) } else { Ok(CountDrop(&drop_counter)) }
+        });
+        if fail_at < 5 {
+            assert!(result.is_err());
+            assert_eq!(drop_counter.get(), fail_at);
+        } else {
+            assert!(result.is_ok());
+            assert_eq!(drop_counter.get(), 0);
+            drop(result);
+            assert_eq!(drop_counter.get(), 5);
+        }
+    }
+}
+
+#[test]
+#[cfg(not(panic = "abort"))]
+fn array_try_from_fn_drops_inserted_elements_on_panic() {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    static DROP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    struct CountDrop;
+    impl Drop for CountDrop {
+        fn drop(&mut self) {
+            DROP_COUNTER.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let _ = std::panic::catch_unwind(move || {
+        let _: Result<[CountDrop; 4], ()> = core::array::try_from_fn(|idx| {
+            if idx == 2 {
+                panic!("peek a boo");
+            }
+            Ok(CountDrop)
+        });
+    });
+    assert_eq!(DROP_COUNTER.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn array_split_array_ref() {
+    let v = [1, 2, 3, 4, 5, 6];
+
+    let (left, right) = v.split_array_ref::<0>();
+    assert_eq!(left, &[]);
+    assert_eq!(right, &[1, 2, 3, 4, 5, 6]);
+
+    let (left, right) = v.split_array_ref::<6>();
+    assert_eq!(left, &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(right, &[]);
+
+    let (left, right) = v.rsplit_array_ref::<0>();
+    assert_eq!(left, &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(right, &[]);
+
+    let (left, right) = v.rsplit_array_ref::<6>();
+    assert_eq!(left, &[]);
+    assert_eq!(right, &[1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn array_split_array_mut() {
+    let mut v = [1, 2, 3, 4, 5, 6];
+
+    {
+        let (left, right) = v.split_array_mut::<3>();
+        left[2] = 30;
+        right[0] = 40;
+    }
+    {
+        let (left, right) = v.rsplit_array_mut::<1>();
+        left[0] = 10;
+        right[0] = 60;
+    }
+    assert_eq!(v, [10, 2, 30, 40, 5, 60]);
+}
+
+#[test]
+fn array_clone_from_matches_clone() {
+    use core::cell::Cell;
+
+    // Counts how often it is cloned and keeps track of where it came from, so
+    // that `clone_from` can be checked to go through the element-wise path.
+    #[derive(Debug, PartialEq)]
+    struct Counted<'a> {
+        value: u32,
+        clones: &'a Cell<usize>,
+        clone_froms: &'a Cell<usize>,
+    }
+
+    impl Clone for Counted<'_> {
+        fn clone(&self) -> Self {
+            self.clones.set(self.clones.get() + 1);
+            Counted { ..*self }
+        }
+
+        fn clone_from(&mut self, source: &Self) {
+            self.clone_froms.set(self.clone_froms.get() + 1);
+            self.value = source.value;
+        }
+    }
+
+    let clones = Cell::new(0);
+    let clone_froms = Cell::new(0);
+    let make = |value| Counted { value, clones: &clones, clone_froms: &clone_froms };
+
+    let src = [make(1), make(2), make(3)];
+    let cloned = src.clone();
+    assert_eq!(cloned, src);
+    assert_eq!(clones.get(), 3);
+    assert_eq!(clone_froms.get(), 0);
+
+    let mut dst = [make(7), make(8), make(9)];
+    dst.clone_from(&src);
+    assert_eq!(dst, src);
+    assert_eq!(dst, cloned);
+    // Every element was updated in place instead of being cloned anew.
+    assert_eq!(clones.get(), 3);
+    assert_eq!(clone_froms.get(), 3);
+}
+
+#[test]
+fn array_clone_from_reuses_allocations() {
+    let src = [String::from("hello"), String::from("world")];
+    let mut dst = [String::with_capacity(64), String::with_capacity(64)];
+    let ptrs = [dst[0].as_ptr(), dst[1].as_ptr()];
+
+    dst.clone_from(&src);
+    assert_eq!(dst, src);
+    assert_eq!([dst[0].as_ptr(), dst[1].as_ptr()], ptrs);
+}
+
+#[test]
+fn array_clone_from_copy() {
+    let src = [1u8, 2, 3, 4];
+    let mut dst = [0u8; 4];
+    dst.clone_from(&src);
+    assert_eq!(dst, src);
+    assert_eq!(src.clone(), src);
+
+    let src: [u8; 0] = [];
+    let mut dst: [u8; 0] = [];
+    dst.clone_from(&src);
+    assert_eq!(dst, src);
+}
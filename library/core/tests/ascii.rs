@@ -251,6 +251,24 @@ fn test_is_ascii_digit() {
     );
 }
 
+#[test]
+fn test_is_ascii_octdigit() {
+    assert_all!(is_ascii_octdigit, "", "01234567",);
+    assert_none!(
+        is_ascii_octdigit,
+        "89",
+        "abcdefghijklmnopqrstuvwxyz",
+        "ABCDEFGHIJKLMNOQPRSTUVWXYZ",
+        "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~",
+        " \t\n\x0c\r",
+        "\x00\x01\x02\x03\x04\x05\x06\x07",
+        "\x08\x09\x0a\x0b\x0c\x0d\x0e\x0f",
+        "\x10\x11\x12\x13\x14\x15\x16\x17",
+        "\x18\x19\x1a\x1b\x1c\x1d\x1e\x1f",
+        "\x7f",
+    );
+}
+
 #[test]
 fn test_is_ascii_hexdigit() {
     assert_all!(is_ascii_hexdigit, "", "0123456789", "abcdefABCDEF",);
@@ -268,6 +286,25 @@ fn test_is_ascii_hexdigit() {
     );
 }
 
+#[test]
+fn test_is_ascii_digit_family_full_range() {
+    // Every byte and every scalar value must agree on the digit predicates,
+    // and the octal digits must be a subset of the decimal ones.
+    for b in 0..=255u8 {
+        let c = b as char;
+        assert_eq!(b.is_ascii_digit(), c.is_ascii_digit());
+        assert_eq!(b.is_ascii_octdigit(), c.is_ascii_octdigit());
+        assert_eq!(b.is_ascii_hexdigit(), c.is_ascii_hexdigit());
+        assert_eq!(b.is_ascii_octdigit(), (b'0'..=b'7').contains(&b));
+        assert!(!b.is_ascii_octdigit() || b.is_ascii_digit());
+    }
+    for c in (0..=char::MAX as u32).filter_map(from_u32) {
+        assert_eq!(c.is_ascii_octdigit(), ('0'..='7').contains(&c));
+        assert!(!c.is_ascii_octdigit() || c.is_ascii_digit());
+        assert!(!c.is_ascii_digit() || c.is_ascii_hexdigit());
+    }
+}
+
 #[test]
 fn test_is_ascii_punctuation() {
     assert_all!(is_ascii_punctuation, "", "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~",);
@@ -454,6 +491,7 @@ pub fn run() {
         is_ascii_lowercase    => [true,  false, false, false, false];
         is_ascii_alphanumeric => [true,  true,  true,  false, false];
         is_ascii_digit        => [false, false, true,  false, false];
+        is_ascii_octdigit     => [false, false, false, false, false];
         is_ascii_hexdigit     => [true,  true,  true,  false, false];
         is_ascii_punctuation  => [false, false, false, true,  false];
         is_ascii_graphic      => [true,  true,  true,  true,  false];
@@ -461,3 +499,45 @@ pub fn run() {
         is_ascii_control      => [false, false, false, false, false];
     }
 }
+
+#[test]
+fn ascii_char_from_u8_covers_exactly_ascii() {
+    for b in 0..=255u8 {
+        let c = core::ascii::Char::from_u8(b);
+        assert_eq!(c.is_some(), b.is_ascii());
+        assert_eq!(b.as_ascii(), c);
+        assert_eq!((b as char).as_ascii(), c);
+        if let Some(c) = c {
+            assert_eq!(c.to_u8(), b);
+            assert_eq!(c.to_char(), b as char);
+        }
+    }
+    assert_eq!('\u{80}'.as_ascii(), None);
+    assert_eq!('❤'.as_ascii(), None);
+}
+
+#[test]
+fn ascii_char_slice_as_str() {
+    let all: Vec<core::ascii::Char> = (0..=127u8).map(|b| b.as_ascii().unwrap()).collect();
+    let s = core::ascii::Char::as_str(&all);
+    assert_eq!(s.len(), 128);
+    assert!(s.chars().map(|c| c as u32).eq(0..128));
+    assert_eq!(core::ascii::Char::as_str(&[]), "");
+}
+
+#[test]
+fn ascii_char_layout_has_niche() {
+    use core::mem::{align_of, size_of};
+    assert_eq!(size_of::<core::ascii::Char>(), 1);
+    assert_eq!(align_of::<core::ascii::Char>(), 1);
+    assert_eq!(size_of::<Option<core::ascii::Char>>(), 1);
+}
+
+#[test]
+fn ascii_char_fmt() {
+    use core::ascii::Char;
+    assert_eq!(format!("{}", Char::CapitalZ), "Z");
+    assert_eq!(format!("{:>3}", Char::Digit7), "  7");
+    assert_eq!(format!("{:?}", Char::Apostrophe), "'\\''");
+    assert_eq!(format!("{:?}", Char::LineFeed), "'\\n'");
+}
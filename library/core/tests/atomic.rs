@@ -220,3 +220,47 @@ fn atomic_compare_exchange() {
     ATOMIC.compare_exchange_weak(0, 1, SeqCst, Acquire).ok();
     ATOMIC.compare_exchange_weak(0, 1, SeqCst, SeqCst).ok();
 }
+
+#[test]
+#[cfg(all(
+    target_has_atomic_equal_alignment = "8",
+    target_has_atomic_equal_alignment = "32",
+    target_has_atomic_equal_alignment = "ptr"
+))]
+fn atomic_from_mut_slice() {
+    let mut plain = [0u32; 8];
+    let atomic = &*AtomicU32::from_mut_slice(&mut plain);
+    for (n, a) in atomic.iter().enumerate() {
+        a.fetch_add(n as u32 * 2, SeqCst);
+    }
+    atomic[7].store(100, SeqCst);
+    assert_eq!(plain, [0, 2, 4, 6, 8, 10, 12, 100]);
+
+    let mut flags = [false; 3];
+    AtomicBool::from_mut_slice(&mut flags)[1].store(true, SeqCst);
+    assert_eq!(flags, [false, true, false]);
+
+    let mut x = 1;
+    let mut ptrs = [core::ptr::null_mut::<i32>(); 2];
+    AtomicPtr::from_mut_slice(&mut ptrs)[0].store(&mut x, SeqCst);
+    assert_eq!(ptrs[0], &mut x as *mut i32);
+    assert!(ptrs[1].is_null());
+
+    assert!(AtomicU32::from_mut_slice(&mut []).is_empty());
+}
+
+#[test]
+fn atomic_get_mut_slice() {
+    let mut atomics = [AtomicUsize::new(1), AtomicUsize::new(2), AtomicUsize::new(3)];
+    let plain = AtomicUsize::get_mut_slice(&mut atomics);
+    plain[1] *= 10;
+    plain.swap(0, 2);
+    assert_eq!(atomics[0].load(SeqCst), 3);
+    assert_eq!(atomics[1].load(SeqCst), 20);
+    assert_eq!(atomics[2].load(SeqCst), 1);
+
+    let mut flags = [AtomicBool::new(false), AtomicBool::new(false)];
+    AtomicBool::get_mut_slice(&mut flags)[0] = true;
+    assert!(flags[0].load(SeqCst));
+    assert!(!flags[1].load(SeqCst));
+}
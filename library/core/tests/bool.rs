@@ -84,8 +84,19 @@ pub fn test_bool_not() {
 
 #[test]
 fn test_bool_to_option() {
+    use std::rc::Rc;
+
     assert_eq!(false.then_some(0), None);
     assert_eq!(true.then_some(0), Some(0));
     assert_eq!(false.then(|| 0), None);
     assert_eq!(true.then(|| 0), Some(0));
+
+    // The value passed to `then_some` is dropped when the `bool` is `false`.
+    let rc = Rc::new(());
+    assert_eq!(false.then_some(rc.clone()), None);
+    assert_eq!(Rc::strong_count(&rc), 1);
+    let kept = true.then_some(rc.clone());
+    assert_eq!(Rc::strong_count(&rc), 2);
+    drop(kept);
+    assert_eq!(Rc::strong_count(&rc), 1);
 }
@@ -27,6 +27,31 @@ fn cell_update() {
     assert_eq!(x.get(), 5);
 }
 
+#[test]
+fn cell_as_array_of_cells() {
+    let mut array = [1, 2, 3, 4];
+    let cells = Cell::from_mut(&mut array).as_array_of_cells();
+    cells[0].set(10);
+    cells[3].swap(&cells[1]);
+    assert_eq!(cells.len(), 4);
+    assert_eq!(array, [10, 4, 3, 2]);
+
+    // `N` is inferred from the context the array is used in.
+    fn sum<const N: usize>(cells: &[Cell<u8>; N]) -> u32 {
+        cells.iter().map(|c| c.get() as u32).sum()
+    }
+    let cell = Cell::new([1u8; 7]);
+    assert_eq!(sum(cell.as_array_of_cells()), 7);
+    let first: &Cell<u8> = &cell.as_array_of_cells()[0];
+    first.set(8);
+    assert_eq!(cell.get(), [8, 1, 1, 1, 1, 1, 1]);
+
+    // ... or from the annotated type of the result.
+    let empty = Cell::new([]);
+    let empty_cells: &[Cell<String>; 0] = empty.as_array_of_cells();
+    assert!(empty_cells.is_empty());
+}
+
 #[test]
 fn cell_has_sensible_show() {
     let x = Cell::new("foo bar");
@@ -138,6 +138,37 @@ fn upper(c: char) -> String {
     assert_eq!(upper('ᾀ'), "ἈΙ");
 }
 
+#[test]
+fn test_to_simple_case_folded() {
+    assert_eq!('a'.to_simple_case_folded(), 'a');
+    assert_eq!('A'.to_simple_case_folded(), 'a');
+    assert_eq!('Ö'.to_simple_case_folded(), 'ö');
+    assert_eq!('Ж'.to_simple_case_folded(), 'ж');
+    assert_eq!('ж'.to_simple_case_folded(), 'ж');
+    assert_eq!('Σ'.to_simple_case_folded(), 'σ');
+    assert_eq!('ς'.to_simple_case_folded(), 'σ');
+    assert_eq!('ϐ'.to_simple_case_folded(), 'β');
+    assert_eq!('\u{212A}'.to_simple_case_folded(), 'k');
+    assert_eq!('ſ'.to_simple_case_folded(), 's');
+    assert_eq!('ẞ'.to_simple_case_folded(), 'ß');
+    assert_eq!('ᾈ'.to_simple_case_folded(), 'ᾀ');
+    // Cherokee folds to the uppercase letters, which were encoded first.
+    assert_eq!('ꭰ'.to_simple_case_folded(), 'Ꭰ');
+
+    assert_eq!('ß'.to_simple_case_folded(), 'ß');
+    assert_eq!('İ'.to_simple_case_folded(), 'İ');
+    assert_eq!('💩'.to_simple_case_folded(), '💩');
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri is too slow
+fn test_to_simple_case_folded_is_idempotent() {
+    for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+        let folded = c.to_simple_case_folded();
+        assert_eq!(folded.to_simple_case_folded(), folded, "{:?}", c);
+    }
+}
+
 #[test]
 fn test_is_control() {
     assert!('\u{0}'.is_control());
@@ -270,6 +301,51 @@ fn check(input: char, expect: &[u16]) {
     check('\u{1f4a9}', &[0xd83d, 0xdca9]);
 }
 
+#[test]
+fn test_encode_utf8_const() {
+    const fn encode(c: char) -> ([u8; char::MAX_LEN_UTF8], usize) {
+        let mut buf = [0; char::MAX_LEN_UTF8];
+        let len = c.encode_utf8(&mut buf).len();
+        (buf, len)
+    }
+
+    const ASCII: ([u8; 4], usize) = encode('x');
+    const TWO: ([u8; 4], usize) = encode('\u{e9}');
+    const THREE: ([u8; 4], usize) = encode('\u{a66e}');
+    const FOUR: ([u8; 4], usize) = encode('\u{1f4a9}');
+
+    assert_eq!(ASCII, ([0x78, 0, 0, 0], 1));
+    assert_eq!(TWO, ([0xc3, 0xa9, 0, 0], 2));
+    assert_eq!(THREE, ([0xea, 0x99, 0xae, 0], 3));
+    assert_eq!(FOUR, ([0xf0, 0x9f, 0x92, 0xa9], 4));
+
+    // The const encoder writes exactly what the runtime one does.
+    for c in ['x', '\u{e9}', '\u{a66e}', '\u{1f4a9}', '\0', char::MAX] {
+        let (buf, len) = encode(c);
+        assert_eq!(&buf[..len], c.encode_utf8(&mut [0; 4]).as_bytes());
+        assert_eq!(len, c.len_utf8());
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri is too slow
+fn test_max_len() {
+    assert_eq!(char::MAX_LEN_UTF8, 4);
+    assert_eq!(char::MAX_LEN_UTF16, 2);
+
+    let max_utf8 = ('\0'..=char::MAX).map(char::len_utf8).max();
+    assert_eq!(max_utf8, Some(char::MAX_LEN_UTF8));
+    let max_utf16 = ('\0'..=char::MAX).map(char::len_utf16).max();
+    assert_eq!(max_utf16, Some(char::MAX_LEN_UTF16));
+}
+
+#[test]
+#[should_panic]
+fn test_encode_utf8_buffer_too_small() {
+    let mut buf = [0; 3];
+    '\u{1f4a9}'.encode_utf8(&mut buf);
+}
+
 #[test]
 fn test_len_utf16() {
     assert!('x'.len_utf16() == 1);
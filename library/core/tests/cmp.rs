@@ -61,6 +61,65 @@ fn test_ord_min_max_by_key() {
     assert_eq!(cmp::max_by_key(2, -1, f), 2);
 }
 
+/// Compares by `key` only, so that equal values can be told apart by `id`.
+#[derive(Debug)]
+struct Tracked {
+    key: i32,
+    id: u32,
+}
+
+impl PartialEq for Tracked {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Tracked {}
+
+impl PartialOrd for Tracked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Tracked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+#[test]
+fn test_minmax() {
+    assert_eq!(cmp::minmax(1, 2), [1, 2]);
+    assert_eq!(cmp::minmax(2, 1), [1, 2]);
+
+    let f = |x: &i32, y: &i32| x.abs().cmp(&y.abs());
+    assert_eq!(cmp::minmax_by(1, -2, f), [1, -2]);
+    assert_eq!(cmp::minmax_by(2, -1, f), [-1, 2]);
+    assert_eq!(cmp::minmax_by(1, -1, f), [1, -1]);
+
+    let f = |x: &i32| x.abs();
+    assert_eq!(cmp::minmax_by_key(1, -2, f), [1, -2]);
+    assert_eq!(cmp::minmax_by_key(2, -1, f), [-1, 2]);
+    assert_eq!(cmp::minmax_by_key(1, -1, f), [1, -1]);
+}
+
+#[test]
+fn test_minmax_equal_elements_keep_argument_order() {
+    let ids = |[a, b]: [Tracked; 2]| [a.id, b.id];
+    let t = |key, id| Tracked { key, id };
+
+    assert_eq!(ids(cmp::minmax(t(1, 0), t(1, 1))), [0, 1]);
+    assert_eq!(ids(cmp::minmax(t(1, 1), t(1, 0))), [1, 0]);
+    assert_eq!(ids(cmp::minmax_by(t(1, 0), t(1, 1), Tracked::cmp)), [0, 1]);
+    assert_eq!(ids(cmp::minmax_by(t(1, 1), t(1, 0), Tracked::cmp)), [1, 0]);
+    assert_eq!(ids(cmp::minmax_by_key(t(1, 0), t(-1, 1), |t| t.key.abs())), [0, 1]);
+    assert_eq!(ids(cmp::minmax_by_key(t(-1, 1), t(1, 0), |t| t.key.abs())), [1, 0]);
+
+    // Unequal elements are still sorted.
+    assert_eq!(ids(cmp::minmax(t(2, 0), t(1, 1))), [1, 0]);
+}
+
 #[test]
 fn test_ordering_reverse() {
     assert_eq!(Less.reverse(), Greater);
@@ -649,6 +649,65 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+mod from_fn {
+    use std::fmt;
+
+    #[test]
+    fn test_display_and_debug() {
+        let value = 'a';
+        let wrapped = fmt::from_fn(|f| write!(f, "{:?}", value));
+
+        assert_eq!("'a'", format!("{}", wrapped));
+        assert_eq!("'a'", format!("{:?}", wrapped));
+        assert_eq!("<'a'>", format!("{}", format_args!("<{}>", wrapped)));
+    }
+
+    #[test]
+    fn test_copy_and_clone() {
+        let wrapped = fmt::from_fn(|f| f.write_str("x"));
+        let copied = wrapped;
+        let cloned = wrapped.clone();
+
+        assert_eq!("x", format!("{}", wrapped));
+        assert_eq!("x", format!("{}", copied));
+        assert_eq!("x", format!("{}", cloned));
+    }
+
+    #[test]
+    fn test_nested_in_debug_struct() {
+        struct Foo;
+
+        impl fmt::Debug for Foo {
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt.debug_struct("Foo")
+                    .field("name", &"foo")
+                    .field("password", &fmt::from_fn(|f| f.write_str("<redacted>")))
+                    .field(
+                        "list",
+                        &fmt::from_fn(|f| f.debug_list().entries(&[1, 2]).finish()),
+                    )
+                    .finish()
+            }
+        }
+
+        assert_eq!(
+            r#"Foo { name: "foo", password: <redacted>, list: [1, 2] }"#,
+            format!("{:?}", Foo)
+        );
+        assert_eq!(
+            r#"Foo {
+    name: "foo",
+    password: <redacted>,
+    list: [
+        1,
+        2,
+    ],
+}"#,
+            format!("{:#?}", Foo)
+        );
+    }
+}
+
 #[test]
 fn test_formatting_parameters_are_forwarded() {
     use std::collections::{BTreeMap, BTreeSet};
@@ -152,3 +152,70 @@ fn test_try_fold_specialization_intersperse_err() {
     iter.try_for_each(|item| if item == "b" { None } else { Some(()) });
     assert_eq!(iter.next(), None);
 }
+
+#[test]
+fn test_intersperse_empty_and_single() {
+    let mut iter = std::iter::empty::<i32>().intersperse(0);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+
+    let mut iter = std::iter::once(1).intersperse(0);
+    assert_eq!(iter.size_hint(), (1, Some(1)));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+
+    let mut ctr = 0;
+    let v = std::iter::once(1)
+        .intersperse_with(|| {
+            ctr += 1;
+            0
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(v, [1]);
+    assert_eq!(ctr, 0);
+}
+
+#[test]
+fn test_intersperse_peekable() {
+    let mut iter = (1..4).intersperse(0).peekable();
+    assert_eq!(iter.peek(), Some(&1));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.peek(), Some(&0));
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(iter.collect::<Vec<_>>(), [0, 2, 0, 3]);
+
+    let mut inner = (1..4).peekable();
+    assert_eq!(inner.peek(), Some(&1));
+    let v = inner.intersperse(0).collect::<Vec<_>>();
+    assert_eq!(v, [1, 0, 2, 0, 3]);
+}
+
+#[test]
+fn test_intersperse_non_fused() {
+    // Yields `Some(1)`, `None`, `Some(2)`, `None`, ...
+    struct Flaky(i32);
+    impl Iterator for Flaky {
+        type Item = i32;
+        fn next(&mut self) -> Option<i32> {
+            self.0 += 1;
+            if self.0 % 2 == 1 { Some(self.0 / 2 + 1) } else { None }
+        }
+    }
+
+    let mut iter = Flaky(0).intersperse(0);
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+    let mut iter = Flaky(0).intersperse_with(|| 0);
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+
+    let mut iter = super::NonFused::new(1..3).intersperse(0);
+    assert_eq!(iter.by_ref().collect::<Vec<_>>(), [1, 0, 2]);
+    assert_eq!(iter.next(), None);
+}
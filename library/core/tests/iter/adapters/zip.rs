@@ -313,3 +313,27 @@ fn test_issue_82291() {
     zip.next();
     assert_eq!(called.get(), 1);
 }
+
+#[test]
+fn test_zip_fn_matches_method() {
+    let xs = [1, 2, 3, 4, 5];
+    let ys = [10, 20, 30];
+
+    let mut free = zip(&xs, &ys);
+    let mut method = xs.iter().zip(&ys);
+    loop {
+        assert_eq!(free.size_hint(), method.size_hint());
+        let (a, b) = (free.next(), method.next());
+        assert_eq!(a, b);
+        if a.is_none() {
+            break;
+        }
+    }
+
+    let unbounded = zip(repeat(0), &ys);
+    assert_eq!(unbounded.size_hint(), repeat(0).zip(&ys).size_hint());
+    assert_eq!(unbounded.size_hint(), (3, Some(3)));
+
+    let nested: Vec<_> = zip(zip(&xs, &ys), 0..).collect();
+    assert_eq!(nested, [((&1, &10), 0), ((&2, &20), 1), ((&3, &30), 2)]);
+}
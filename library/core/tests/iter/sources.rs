@@ -29,6 +29,75 @@ fn test_repeat_take_collect() {
     assert_eq!(v, vec![42, 42, 42]);
 }
 
+#[test]
+fn test_repeat_n() {
+    let mut it = repeat_n(42, 3);
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.next(), Some(42));
+    assert_eq!(it.len(), 2);
+    assert_eq!(it.next_back(), Some(42));
+    assert_eq!(it.len(), 1);
+    assert_eq!(it.next(), Some(42));
+    assert_eq!(it.len(), 0);
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+    is_trusted_len(repeat_n(42, 3));
+
+    let mut it = repeat_n(42, 10);
+    assert_eq!(it.nth(3), Some(42));
+    assert_eq!(it.len(), 6);
+    assert_eq!(it.nth_back(5), Some(42));
+    assert_eq!(it.len(), 0);
+    assert_eq!(it.nth(0), None);
+}
+
+#[test]
+fn test_repeat_n_clone_count() {
+    use core::cell::Cell;
+
+    #[derive(Debug)]
+    struct Counted<'a> {
+        clones: &'a Cell<usize>,
+        drops: &'a Cell<usize>,
+    }
+    impl Clone for Counted<'_> {
+        fn clone(&self) -> Self {
+            self.clones.set(self.clones.get() + 1);
+            Counted { clones: self.clones, drops: self.drops }
+        }
+    }
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    let (clones, drops) = (Cell::new(0), Cell::new(0));
+    let counted = || Counted { clones: &clones, drops: &drops };
+
+    // The last element is moved out instead of cloned.
+    assert_eq!(repeat_n(counted(), 3).count(), 3);
+    assert_eq!(clones.get(), 0);
+    assert_eq!(drops.get(), 1);
+    drops.set(0);
+    repeat_n(counted(), 3).for_each(drop);
+    assert_eq!(clones.get(), 2);
+    assert_eq!(drops.get(), 3);
+
+    // With a count of zero, the element is dropped right away.
+    let (clones, drops) = (Cell::new(0), Cell::new(0));
+    let mut it = repeat_n(Counted { clones: &clones, drops: &drops }, 0);
+    assert_eq!(drops.get(), 1);
+    assert!(it.next().is_none());
+    assert_eq!(clones.get(), 0);
+
+    // Skipping to the end drops the element without cloning it.
+    let (clones, drops) = (Cell::new(0), Cell::new(0));
+    let mut it = repeat_n(Counted { clones: &clones, drops: &drops }, 5);
+    assert!(it.nth(5).is_none());
+    assert_eq!((clones.get(), drops.get()), (0, 1));
+}
+
 #[test]
 fn test_repeat_with() {
     #[derive(PartialEq, Debug)]
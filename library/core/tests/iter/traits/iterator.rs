@@ -318,6 +318,50 @@ fn test_try_find_api_usability() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn test_try_reduce() {
+    let v: Vec<usize> = Vec::new();
+    assert_eq!(v.into_iter().try_reduce(|x, y| x.checked_add(y)), Some(None));
+
+    let v = vec![7usize];
+    assert_eq!(v.into_iter().try_reduce(|x, y| x.checked_add(y)), Some(Some(7)));
+
+    let v = vec![1, 2, 3, 4, 5];
+    assert_eq!(v.into_iter().try_reduce(|x, y| x.checked_add(y)), Some(Some(15)));
+
+    let v = vec![1, 2, 3, 4, 5];
+    let r: Result<Option<i32>, i32> =
+        v.into_iter().try_reduce(|x, y| if y == 3 { Err(x) } else { Ok(x + y) });
+    assert_eq!(r, Err(3));
+}
+
+#[test]
+fn test_try_reduce_stops_at_failure() {
+    let v = [1usize, 2, usize::MAX, 4, 5];
+    let mut iter = v.iter().copied();
+    assert_eq!(iter.try_reduce(|x, y| x.checked_add(y)), None);
+    // The iterator is left right after the element that caused the failure.
+    assert_eq!(iter.next(), Some(4));
+    assert_eq!(iter.try_reduce(|x, y| x.checked_add(y)), Some(Some(5)));
+    assert_eq!(iter.try_reduce(|x, y| x.checked_add(y)), Some(None));
+}
+
+#[test]
+fn test_try_reduce_api_usability() -> Result<(), std::num::ParseIntError> {
+    let larger = |x: &&str, y: &&str| -> Result<&str, std::num::ParseIntError> {
+        Ok(if x.parse::<i32>()? >= y.parse::<i32>()? { *x } else { *y })
+    };
+
+    let a = ["1", "300", "20"];
+    let max = a.iter().copied().try_reduce(|x, y| larger(&x, &y))?;
+    assert_eq!(max, Some("300"));
+
+    let a = ["1", "twenty", "300"];
+    assert!(a.iter().copied().try_reduce(|x, y| larger(&x, &y)).is_err());
+
+    Ok(())
+}
+
 #[test]
 fn test_position() {
     let v = &[1, 3, 9, 27, 103, 14, 11];
@@ -468,3 +512,34 @@ fn test_collect() {
     let b: Vec<isize> = a.iter().cloned().collect();
     assert!(a == b);
 }
+
+#[test]
+fn test_next_chunk() {
+    let mut it = 0..12;
+    assert_eq!(it.next_chunk().unwrap(), [0, 1, 2, 3]);
+    assert_eq!(it.next_chunk().unwrap(), []);
+    assert_eq!(it.next_chunk().unwrap(), [4, 5, 6, 7, 8, 9]);
+    assert_eq!(it.next_chunk::<4>().unwrap_err().as_slice(), &[10, 11]);
+    assert_eq!(it.next_chunk::<4>().unwrap_err().as_slice(), &[]);
+}
+
+#[test]
+fn test_next_chunk_drops_partial_items() {
+    use core::cell::Cell;
+
+    struct DropCounter<'a>(&'a Cell<usize>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let counter = Cell::new(0);
+    let mut it = (0..3).map(|_| DropCounter(&counter));
+    let rest = it.next_chunk::<5>().unwrap_err();
+    assert_eq!(rest.len(), 3);
+    assert_eq!(counter.get(), 0);
+    drop(rest);
+    assert_eq!(counter.get(), 3);
+}
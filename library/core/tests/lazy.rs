@@ -1,6 +1,6 @@
 use core::{
     cell::Cell,
-    lazy::{Lazy, OnceCell},
+    lazy::{LazyCell, OnceCell},
     sync::atomic::{AtomicUsize, Ordering::SeqCst},
 };
 
@@ -15,6 +15,36 @@ fn once_cell() {
     assert_eq!(c.get(), Some(&92));
 }
 
+#[test]
+fn once_cell_get_or_try_init() {
+    let cell: OnceCell<String> = OnceCell::new();
+    let calls = Cell::new(0);
+
+    // A failed attempt leaves the cell empty, so the next call retries.
+    let res: Result<&String, &str> = cell.get_or_try_init(|| {
+        calls.set(calls.get() + 1);
+        Err("not yet")
+    });
+    assert_eq!(res, Err("not yet"));
+    assert!(cell.get().is_none());
+
+    let res = cell.get_or_try_init(|| {
+        calls.set(calls.get() + 1);
+        Ok::<_, ()>("hello".to_string())
+    });
+    assert_eq!(res, Ok(&"hello".to_string()));
+    assert_eq!(calls.get(), 2);
+
+    // Once set, the closure isn't called again and the value is kept.
+    let res = cell.get_or_try_init(|| -> Result<String, ()> {
+        calls.set(calls.get() + 1);
+        Ok("world".to_string())
+    });
+    assert_eq!(res, Ok(&"hello".to_string()));
+    assert_eq!(cell.get_or_try_init(|| Err(())), Ok(&"hello".to_string()));
+    assert_eq!(calls.get(), 2);
+}
+
 #[test]
 fn once_cell_get_mut() {
     let mut c = OnceCell::new();
@@ -85,7 +115,7 @@ fn into_inner() {
 #[test]
 fn lazy_new() {
     let called = Cell::new(0);
-    let x = Lazy::new(|| {
+    let x = LazyCell::new(|| {
         called.set(called.get() + 1);
         92
     });
@@ -101,6 +131,31 @@ fn lazy_new() {
     assert_eq!(called.get(), 1);
 }
 
+#[test]
+fn lazy_into_inner() {
+    let called = Cell::new(0);
+    let x = LazyCell::new(|| {
+        called.set(called.get() + 1);
+        92
+    });
+    assert_eq!(LazyCell::force(&x), &92);
+    assert_eq!(LazyCell::into_inner(x).ok(), Some(92));
+    assert_eq!(called.get(), 1);
+
+    let x = LazyCell::new(|| 92);
+    let init = LazyCell::into_inner(x).unwrap_err();
+    assert_eq!(init(), 92);
+}
+
+#[test]
+#[should_panic(expected = "forced reentrantly")]
+fn lazy_reentrant_init() {
+    thread_local! {
+        static LAZY: LazyCell<i32> = LazyCell::new(|| LAZY.with(|lazy| **lazy + 1));
+    }
+    LAZY.with(|lazy| **lazy);
+}
+
 #[test]
 fn aliasing_in_get() {
     let x = OnceCell::new();
@@ -1,14 +1,25 @@
 #![feature(alloc_layout_extra)]
 #![feature(array_chunks)]
+#![feature(array_from_fn)]
 #![feature(array_methods)]
+#![feature(array_try_from_fn)]
 #![feature(array_windows)]
+#![feature(as_array_of_cells)]
+#![feature(ascii_char)]
+#![feature(async_stream)]
+#![feature(atomic_from_mut)]
+#![feature(bigint_helper_methods)]
 #![feature(bool_to_option)]
 #![feature(box_syntax)]
 #![feature(cell_update)]
+#![feature(char_max_len)]
+#![feature(cmp_minmax)]
+#![feature(control_flow_enum)]
 #![feature(cfg_panic)]
 #![feature(cfg_target_has_atomic)]
 #![feature(const_assume)]
 #![feature(const_cell_into_inner)]
+#![feature(const_char_encode_utf8)]
 #![feature(const_maybe_uninit_assume_init)]
 #![feature(const_ptr_read)]
 #![feature(const_ptr_write)]
@@ -16,52 +27,87 @@
 #![feature(core_intrinsics)]
 #![feature(core_private_bignum)]
 #![feature(core_private_diy_float)]
+#![feature(debug_closure_helpers)]
 #![feature(dec2flt)]
 #![feature(div_duration)]
+#![feature(downcast_unchecked)]
 #![feature(duration_consts_2)]
 #![feature(duration_constants)]
 #![feature(exact_size_is_empty)]
 #![feature(extern_types)]
+#![feature(float_minimum_maximum)]
+#![feature(float_next_up_down)]
 #![feature(flt2dec)]
 #![feature(fmt_internals)]
+#![feature(future_poll_fn)]
 #![feature(hashmap_internals)]
 #![feature(try_find)]
 #![feature(is_sorted)]
+#![feature(is_ascii_octdigit)]
 #![feature(pattern)]
+#![feature(panic_can_unwind)]
+#![feature(panic_info_message)]
 #![feature(sort_internals)]
+#![feature(slice_as_chunks)]
 #![feature(slice_partition_at_index)]
+#![feature(split_array)]
 #![feature(maybe_uninit_uninit_array)]
 #![feature(maybe_uninit_array_assume_init)]
 #![feature(maybe_uninit_extra)]
 #![feature(maybe_uninit_write_slice)]
 #![feature(min_specialization)]
+#![feature(nonnull_slice_from_raw_parts)]
+#![feature(nonzero_ops)]
+#![feature(noop_waker)]
+#![feature(num_midpoint)]
 #![feature(numfmt)]
 #![feature(step_trait)]
+#![feature(str_eq_ignore_case)]
 #![feature(str_internals)]
+#![feature(substr_range)]
+#![feature(strict_provenance)]
 #![feature(test)]
+#![feature(total_cmp)]
 #![feature(trusted_len)]
 #![feature(try_trait_v2)]
+#![feature(type_name_of_val)]
 #![feature(slice_internals)]
 #![feature(slice_partition_dedup)]
 #![feature(int_log)]
+#![feature(int_roundings)]
 #![feature(iter_advance_by)]
+#![feature(iterator_try_reduce)]
+#![feature(iter_next_chunk)]
 #![feature(iter_partition_in_place)]
+#![feature(iter_repeat_n)]
 #![feature(iter_intersperse)]
 #![feature(iter_is_partitioned)]
+#![feature(iter_zip)]
 #![feature(iter_order_by)]
 #![feature(iter_map_while)]
+#![feature(iter_map_windows)]
 #![feature(const_mut_refs)]
 #![feature(const_pin)]
 #![feature(const_slice_from_raw_parts)]
+#![feature(const_nonnull_slice_from_raw_parts)]
+#![feature(const_slice_ptr_len)]
 #![feature(const_raw_ptr_deref)]
 #![feature(never_type)]
+#![feature(slice_ptr_get)]
+#![feature(slice_ptr_len)]
 #![feature(unwrap_infallible)]
 #![feature(option_result_unwrap_unchecked)]
+#![feature(option_zip)]
+#![feature(ready_macro)]
+#![feature(result_flattening)]
 #![feature(result_into_ok_or_err)]
+#![feature(result_option_inspect)]
 #![feature(ptr_metadata)]
 #![feature(once_cell)]
+#![feature(once_cell_try)]
 #![feature(unsized_tuple_coercion)]
 #![feature(const_option)]
+#![feature(const_result)]
 #![feature(integer_atomics)]
 #![feature(slice_group_by)]
 #![feature(trusted_random_access)]
@@ -93,6 +139,7 @@
 mod num;
 mod ops;
 mod option;
+mod panic;
 mod pattern;
 mod pin;
 mod ptr;
@@ -100,6 +147,7 @@
 mod slice;
 mod str;
 mod str_lossy;
+mod stream;
 mod task;
 mod time;
 mod tuple;
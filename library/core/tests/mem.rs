@@ -137,6 +137,53 @@ fn uninit_array_assume_init() {
     let [] = unsafe { MaybeUninit::<!>::array_assume_init([]) };
 }
 
+#[test]
+fn uninit_array_assume_init_drops_each_element_once() {
+    use core::cell::Cell;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Counted<'a>(&'a Cell<usize>);
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let mut array: [MaybeUninit<Counted<'_>>; 4] = MaybeUninit::uninit_array();
+    for elem in &mut array {
+        elem.write(Counted(&drops));
+    }
+    assert_eq!(drops.get(), 0);
+
+    let array = unsafe { MaybeUninit::array_assume_init(array) };
+    assert_eq!(drops.get(), 0);
+
+    drop(array);
+    assert_eq!(drops.get(), 4);
+
+    // Zero-sized elements are counted all the same.
+    static ZST_DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Zst;
+
+    impl Drop for Zst {
+        fn drop(&mut self) {
+            ZST_DROPS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut array: [MaybeUninit<Zst>; 3] = MaybeUninit::uninit_array();
+    for elem in &mut array {
+        elem.write(Zst);
+    }
+    let array = unsafe { MaybeUninit::array_assume_init(array) };
+    assert_eq!(ZST_DROPS.load(Ordering::Relaxed), 0);
+    drop(array);
+    assert_eq!(ZST_DROPS.load(Ordering::Relaxed), 3);
+}
+
 #[test]
 fn uninit_write_slice() {
     let mut dst = [MaybeUninit::new(255); 64];
@@ -163,6 +210,15 @@ fn uninit_write_slice_panic_gt() {
     MaybeUninit::write_slice(&mut dst, &src);
 }
 
+#[test]
+fn uninit_write_slice_empty() {
+    let mut dst: [MaybeUninit<u8>; 0] = [];
+    assert_eq!(MaybeUninit::write_slice(&mut dst, &[]), &[]);
+
+    let mut dst = [MaybeUninit::<u8>::uninit(); 4];
+    assert_eq!(MaybeUninit::write_slice(&mut dst[2..2], &[]), &[]);
+}
+
 #[test]
 fn uninit_clone_from_slice() {
     let mut dst = [MaybeUninit::new(255); 64];
@@ -172,7 +228,7 @@ fn uninit_clone_from_slice() {
 }
 
 #[test]
-#[should_panic(expected = "destination and source slices have different lengths")]
+#[should_panic(expected = "source slice length (32) does not match destination slice length (64)")]
 fn uninit_write_slice_cloned_panic_lt() {
     let mut dst = [MaybeUninit::uninit(); 64];
     let src = [0; 32];
@@ -181,7 +237,7 @@ fn uninit_write_slice_cloned_panic_lt() {
 }
 
 #[test]
-#[should_panic(expected = "destination and source slices have different lengths")]
+#[should_panic(expected = "source slice length (128) does not match destination slice length (64)")]
 fn uninit_write_slice_cloned_panic_gt() {
     let mut dst = [MaybeUninit::uninit(); 64];
     let src = [0; 128];
@@ -245,6 +301,19 @@ fn clone(&self) -> Self {
     }
 }
 
+#[test]
+fn uninit_write_slice_cloned_empty() {
+    #[derive(Clone)]
+    struct Token;
+
+    let mut dst: [MaybeUninit<Token>; 0] = [];
+    let src: [Token; 0] = [];
+    assert!(MaybeUninit::write_slice_cloned(&mut dst, &src).is_empty());
+
+    let mut dst = [MaybeUninit::<String>::uninit(), MaybeUninit::uninit()];
+    assert!(MaybeUninit::write_slice_cloned(&mut dst[1..1], &[]).is_empty());
+}
+
 #[test]
 fn uninit_write_slice_cloned_no_drop() {
     #[derive(Clone)]
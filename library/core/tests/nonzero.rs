@@ -328,3 +328,81 @@ fn test_nonzero_uint_rem() {
     let x: u32 = 42u32 % nz;
     assert_eq!(x, 2u32);
 }
+
+#[test]
+fn nonzero_checked_add_at_max() {
+    let one = NonZeroU8::new(1).unwrap();
+    let max = NonZeroU8::new(u8::MAX).unwrap();
+    assert_eq!(one.checked_add(0), Some(one));
+    assert_eq!(one.checked_add(u8::MAX - 1), Some(max));
+    assert_eq!(one.checked_add(u8::MAX), None);
+    assert_eq!(max.checked_add(1), None);
+    assert_eq!(max.saturating_add(1), max);
+    assert_eq!(max.saturating_add(u8::MAX), max);
+
+    let max = NonZeroU128::new(u128::MAX).unwrap();
+    assert_eq!(max.checked_add(1), None);
+    assert_eq!(max.saturating_add(1), max);
+
+    let max = NonZeroUsize::new(usize::MAX).unwrap();
+    assert_eq!(max.checked_add(0), Some(max));
+    assert_eq!(max.checked_add(1), None);
+}
+
+#[test]
+fn nonzero_checked_mul_and_pow() {
+    let two = NonZeroU32::new(2).unwrap();
+    let max = NonZeroU32::new(u32::MAX).unwrap();
+    assert_eq!(two.checked_mul(two), NonZeroU32::new(4));
+    assert_eq!(max.checked_mul(two), None);
+    assert_eq!(max.saturating_mul(two), max);
+    assert_eq!(two.checked_pow(31), NonZeroU32::new(1 << 31));
+    assert_eq!(two.checked_pow(32), None);
+    assert_eq!(two.saturating_pow(32), max);
+
+    let min_two = NonZeroI8::new(-2).unwrap();
+    assert_eq!(min_two.checked_mul(min_two), NonZeroI8::new(4));
+    assert_eq!(min_two.checked_pow(7), NonZeroI8::new(i8::MIN));
+    assert_eq!(min_two.checked_pow(8), None);
+    assert_eq!(min_two.saturating_pow(8), NonZeroI8::new(i8::MAX).unwrap());
+    assert_eq!(min_two.saturating_pow(9), NonZeroI8::new(i8::MIN).unwrap());
+    assert_eq!(NonZeroI8::new(i8::MIN).unwrap().checked_mul(min_two), None);
+}
+
+#[test]
+fn nonzero_signed_sum_can_be_zero() {
+    // Signed non-zero types have no `checked_add`: the sum of two non-zero
+    // values may be zero, which only `new` can reject.
+    let minus_one = NonZeroI32::new(-1).unwrap();
+    let one = NonZeroI32::new(1).unwrap();
+    assert_eq!(minus_one.get().checked_add(one.get()).and_then(NonZeroI32::new), None);
+    assert_eq!(NonZeroI32::new(i32::MAX).unwrap().get().checked_add(1), None);
+    assert_eq!(one.get().checked_add(one.get()).and_then(NonZeroI32::new), NonZeroI32::new(2));
+}
+
+#[test]
+fn nonzero_log2_log10() {
+    for n in 1..=u8::MAX {
+        let nz = NonZeroU8::new(n).unwrap();
+        assert_eq!(nz.log2(), n.log2() as u32);
+        assert_eq!(nz.log10(), n.log10() as u32);
+    }
+    for n in 1..=u16::MAX {
+        let nz = NonZeroU16::new(n).unwrap();
+        assert_eq!(nz.log2(), n.log2() as u32);
+        assert_eq!(nz.log10(), n.log10() as u32);
+    }
+
+    assert_eq!(NonZeroU32::new(u32::MAX).unwrap().log2(), 31);
+    assert_eq!(NonZeroU32::new(u32::MAX).unwrap().log10(), 9);
+    assert_eq!(NonZeroU64::new(u64::MAX).unwrap().log2(), 63);
+    assert_eq!(NonZeroU64::new(u64::MAX).unwrap().log10(), 19);
+    assert_eq!(NonZeroU128::new(u128::MAX).unwrap().log2(), 127);
+    assert_eq!(NonZeroU128::new(u128::MAX).unwrap().log10(), 38);
+    assert_eq!(NonZeroUsize::new(usize::MAX).unwrap().log2(), usize::BITS - 1);
+    assert_eq!(NonZeroUsize::new(1).unwrap().log10(), 0);
+    assert_eq!(NonZeroUsize::new(usize::MAX).unwrap().log10(), usize::MAX.log10() as u32);
+
+    const LOG2: u32 = NonZeroU32::new(1024).unwrap().log2();
+    assert_eq!(LOG2, 10);
+}
@@ -289,6 +289,34 @@ fn test_pow() {
                 assert_eq!(r.saturating_pow(3), -8 as $T);
                 assert_eq!(r.saturating_pow(0), 1 as $T);
             }
+
+            #[test]
+            fn test_carrying_add() {
+                assert_eq!($T::MAX.carrying_add(1, false), ($T::MIN, true));
+                assert_eq!($T::MAX.carrying_add(0, true), ($T::MIN, true));
+                assert_eq!($T::MAX.carrying_add(1, true), ($T::MIN + 1, true));
+                assert_eq!($T::MAX.carrying_add(-1, false), ($T::MAX - 1, false));
+                assert_eq!($T::MAX.carrying_add(-1, true), ($T::MAX, false));
+                assert_eq!($T::MIN.carrying_add(-1, false), ($T::MAX, true));
+                // The addition wraps downwards and the carry-in wraps it back.
+                assert_eq!($T::MIN.carrying_add(-1, true), ($T::MIN, false));
+                assert_eq!($T::MIN.carrying_add($T::MIN, true), (1, true));
+                assert_eq!((0 as $T).carrying_add(0, true), (1, false));
+                assert_eq!((-1 as $T).carrying_add(0, true), (0, false));
+            }
+
+            #[test]
+            fn test_borrowing_sub() {
+                assert_eq!($T::MIN.borrowing_sub(1, false), ($T::MAX, true));
+                assert_eq!($T::MIN.borrowing_sub(0, true), ($T::MAX, true));
+                assert_eq!($T::MIN.borrowing_sub(1, true), ($T::MAX - 1, true));
+                assert_eq!($T::MIN.borrowing_sub(-1, false), ($T::MIN + 1, false));
+                assert_eq!($T::MIN.borrowing_sub(-1, true), ($T::MIN, false));
+                assert_eq!($T::MAX.borrowing_sub(-1, false), ($T::MIN, true));
+                // The subtraction wraps upwards and the borrow-in wraps it back.
+                assert_eq!($T::MAX.borrowing_sub(-1, true), ($T::MAX, false));
+                assert_eq!((0 as $T).borrowing_sub(0, true), (-1, false));
+            }
         }
     };
 }
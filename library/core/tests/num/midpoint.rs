@@ -0,0 +1,153 @@
+//! Tests for the `midpoint` methods. The 8-bit types are checked exhaustively
+//! against a widened reference implementation, the wider ones at their edges.
+//! `f32` is checked against `f64` on a grid of interesting values.
+
+#[test]
+fn midpoint_u8_exhaustive() {
+    for a in u8::MIN..=u8::MAX {
+        for b in u8::MIN..=u8::MAX {
+            let expected = ((a as u16 + b as u16) / 2) as u8;
+            assert_eq!(a.midpoint(b), expected, "{}.midpoint({})", a, b);
+            assert_eq!(a.midpoint(b), b.midpoint(a));
+        }
+    }
+}
+
+#[test]
+fn midpoint_i8_exhaustive() {
+    for a in i8::MIN..=i8::MAX {
+        for b in i8::MIN..=i8::MAX {
+            // `>> 1` rounds towards negative infinity, unlike `/ 2`.
+            let expected = ((a as i16 + b as i16) >> 1) as i8;
+            assert_eq!(a.midpoint(b), expected, "{}.midpoint({})", a, b);
+            assert_eq!(a.midpoint(b), b.midpoint(a));
+        }
+    }
+}
+
+#[test]
+fn midpoint_rounds_towards_negative_infinity() {
+    assert_eq!(0i32.midpoint(1), 0);
+    assert_eq!(0i32.midpoint(-1), -1);
+    assert_eq!((-3i32).midpoint(0), -2);
+    assert_eq!(3i32.midpoint(0), 1);
+    assert_eq!(0u32.midpoint(1), 0);
+    assert_eq!(3u32.midpoint(0), 1);
+}
+
+macro_rules! midpoint_edges {
+    ($($name:ident: $T:ty),*) => {
+        $(
+            #[test]
+            fn $name() {
+                assert_eq!(<$T>::MAX.midpoint(<$T>::MAX), <$T>::MAX);
+                assert_eq!(<$T>::MIN.midpoint(<$T>::MIN), <$T>::MIN);
+                assert_eq!(<$T>::MAX.midpoint(<$T>::MAX - 1), <$T>::MAX - 1);
+                assert_eq!(<$T>::MIN.midpoint(<$T>::MIN + 2), <$T>::MIN + 1);
+                assert_eq!(<$T>::MIN.midpoint(<$T>::MAX), <$T>::MIN / 2 + <$T>::MAX / 2);
+                assert_eq!((0 as $T).midpoint(10), 5);
+            }
+        )*
+    };
+}
+
+midpoint_edges! {
+    midpoint_u16: u16, midpoint_u32: u32, midpoint_u64: u64, midpoint_u128: u128,
+    midpoint_usize: usize, midpoint_i16: i16, midpoint_i32: i32, midpoint_i64: i64,
+    midpoint_i128: i128, midpoint_isize: isize
+}
+
+#[test]
+fn midpoint_const() {
+    const U: u8 = 200u8.midpoint(100);
+    const I: i8 = (-128i8).midpoint(127);
+    assert_eq!(U, 150);
+    assert_eq!(I, -1);
+}
+
+/// Interesting `f32` values: the extremes of the finite range, the boundaries
+/// of the subnormals, values around one and their negations.
+fn f32_grid() -> Vec<f32> {
+    let positive = [
+        0.0,
+        f32::from_bits(1),
+        f32::from_bits(2),
+        f32::from_bits(3),
+        f32::MIN_POSITIVE / 2.0,
+        f32::MIN_POSITIVE - f32::from_bits(1),
+        f32::MIN_POSITIVE,
+        f32::MIN_POSITIVE * 2.0,
+        f32::EPSILON,
+        0.5,
+        1.0,
+        1.0 + f32::EPSILON,
+        3.0,
+        1e10,
+        f32::MAX / 2.0,
+        f32::MAX / 2.0 + 1e30,
+        f32::MAX - 1e32,
+        f32::MAX,
+        f32::INFINITY,
+    ];
+    positive.iter().flat_map(|&x| [x, -x]).collect()
+}
+
+#[test]
+fn midpoint_f32_matches_widened_reference() {
+    let grid = f32_grid();
+    for &a in &grid {
+        for &b in &grid {
+            let actual = a.midpoint(b);
+            // The sum of two `f32`s is exact in `f64`, and so is halving it,
+            // so this rounds only once.
+            let expected = ((a as f64 + b as f64) / 2.0) as f32;
+            if expected.is_nan() {
+                assert!(actual.is_nan(), "{:e}.midpoint({:e}) = {:e}", a, b, actual);
+            } else {
+                assert_eq!(
+                    actual.to_bits(),
+                    expected.to_bits(),
+                    "{:e}.midpoint({:e}) = {:e}, expected {:e}",
+                    a,
+                    b,
+                    actual,
+                    expected
+                );
+            }
+            assert_eq!(actual.to_bits(), b.midpoint(a).to_bits());
+        }
+    }
+}
+
+#[test]
+fn midpoint_float_nan_and_zeros() {
+    assert!(f32::NAN.midpoint(1.0).is_nan());
+    assert!(1f32.midpoint(f32::NAN).is_nan());
+    assert!(f32::INFINITY.midpoint(f32::NEG_INFINITY).is_nan());
+    assert!(f64::NAN.midpoint(f64::MAX).is_nan());
+    assert!(f64::NEG_INFINITY.midpoint(f64::INFINITY).is_nan());
+
+    assert_eq!(f32::INFINITY.midpoint(f32::MAX), f32::INFINITY);
+    assert_eq!(f64::NEG_INFINITY.midpoint(f64::MIN), f64::NEG_INFINITY);
+
+    assert!(0f32.midpoint(-0.0).is_sign_positive());
+    assert!((-0f32).midpoint(0.0).is_sign_positive());
+    assert!((-0f32).midpoint(-0.0).is_sign_negative());
+    assert!(0f64.midpoint(-0.0).is_sign_positive());
+    assert!((-0f64).midpoint(-0.0).is_sign_negative());
+    // A subnormal midpoint that rounds to zero keeps the sign of the sum.
+    assert!(f32::from_bits(1).midpoint(0.0).is_sign_positive());
+    assert!((-f32::from_bits(1)).midpoint(-0.0).is_sign_negative());
+}
+
+#[test]
+fn midpoint_f64_extremes() {
+    assert_eq!(f64::MAX.midpoint(f64::MAX), f64::MAX);
+    assert_eq!(f64::MIN.midpoint(f64::MIN), f64::MIN);
+    assert_eq!(f64::MAX.midpoint(f64::MIN), 0.0);
+    assert_eq!(f64::MAX.midpoint(f64::MAX / 2.0), f64::MAX * 0.75);
+    let tiny = f64::from_bits(1);
+    assert_eq!(tiny.midpoint(tiny), tiny);
+    assert_eq!(f64::MAX.midpoint(tiny), f64::MAX / 2.0);
+    assert_eq!(f64::MIN_POSITIVE.midpoint(f64::MIN_POSITIVE * 3.0), f64::MIN_POSITIVE * 2.0);
+}
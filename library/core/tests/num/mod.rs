@@ -30,6 +30,8 @@
 mod dec2flt;
 mod flt2dec;
 mod int_log;
+mod midpoint;
+mod next_multiple_of;
 mod ops;
 mod wrapping;
 
@@ -713,6 +715,202 @@ fn max() {
                 assert!(($nan as $fty).max($nan).is_nan());
             }
             #[test]
+            fn maximum() {
+                assert_eq!((0.0 as $fty).maximum(0.0), 0.0);
+                assert!((0.0 as $fty).maximum(0.0).is_sign_positive());
+                assert_eq!((-0.0 as $fty).maximum(0.0), 0.0);
+                assert!((-0.0 as $fty).maximum(0.0).is_sign_positive());
+                assert_eq!((0.0 as $fty).maximum(-0.0), 0.0);
+                assert!((0.0 as $fty).maximum(-0.0).is_sign_positive());
+                assert_eq!((-0.0 as $fty).maximum(-0.0), -0.0);
+                assert!((-0.0 as $fty).maximum(-0.0).is_sign_negative());
+                assert_eq!((9.0 as $fty).maximum(9.0), 9.0);
+                assert_eq!((-9.0 as $fty).maximum(0.0), 0.0);
+                assert_eq!((0.0 as $fty).maximum(9.0), 9.0);
+                assert_eq!((-0.0 as $fty).maximum(-9.0), -0.0);
+                assert_eq!(($inf as $fty).maximum(9.0), $inf);
+                assert_eq!((9.0 as $fty).maximum($inf), $inf);
+                assert_eq!(($neginf as $fty).maximum(-9.0), -9.0);
+                assert_eq!((-9.0 as $fty).maximum($neginf), -9.0);
+                assert!(($nan as $fty).maximum(9.0).is_nan());
+                assert!(($nan as $fty).maximum(-9.0).is_nan());
+                assert!((9.0 as $fty).maximum($nan).is_nan());
+                assert!((-9.0 as $fty).maximum($nan).is_nan());
+                assert!(($inf as $fty).maximum($nan).is_nan());
+                assert!(($nan as $fty).maximum($nan).is_nan());
+            }
+            #[test]
+            fn minimum() {
+                assert_eq!((0.0 as $fty).minimum(0.0), 0.0);
+                assert!((0.0 as $fty).minimum(0.0).is_sign_positive());
+                assert_eq!((-0.0 as $fty).minimum(0.0), -0.0);
+                assert!((-0.0 as $fty).minimum(0.0).is_sign_negative());
+                assert_eq!((0.0 as $fty).minimum(-0.0), -0.0);
+                assert!((0.0 as $fty).minimum(-0.0).is_sign_negative());
+                assert_eq!((-0.0 as $fty).minimum(-0.0), -0.0);
+                assert!((-0.0 as $fty).minimum(-0.0).is_sign_negative());
+                assert_eq!((9.0 as $fty).minimum(9.0), 9.0);
+                assert_eq!((-9.0 as $fty).minimum(0.0), -9.0);
+                assert_eq!((0.0 as $fty).minimum(9.0), 0.0);
+                assert_eq!((-0.0 as $fty).minimum(9.0), -0.0);
+                assert_eq!(($inf as $fty).minimum(9.0), 9.0);
+                assert_eq!((9.0 as $fty).minimum($inf), 9.0);
+                assert_eq!(($neginf as $fty).minimum(-9.0), $neginf);
+                assert_eq!((-9.0 as $fty).minimum($neginf), $neginf);
+                assert!(($nan as $fty).minimum(9.0).is_nan());
+                assert!(($nan as $fty).minimum(-9.0).is_nan());
+                assert!((9.0 as $fty).minimum($nan).is_nan());
+                assert!((-9.0 as $fty).minimum($nan).is_nan());
+                assert!(($neginf as $fty).minimum($nan).is_nan());
+                assert!(($nan as $fty).minimum($nan).is_nan());
+            }
+            #[test]
+            fn minimum_maximum_agree_with_total_cmp() {
+                use core::cmp::Ordering;
+                let values = [
+                    $neginf,
+                    <$fty>::MIN,
+                    -1.0,
+                    -<$fty>::MIN_POSITIVE,
+                    -0.0,
+                    0.0,
+                    <$fty>::MIN_POSITIVE,
+                    1.0,
+                    <$fty>::MAX,
+                    $inf,
+                ];
+                for &a in values.iter() {
+                    for &b in values.iter() {
+                        let (lo, hi) = match a.total_cmp(&b) {
+                            Ordering::Greater => (b, a),
+                            _ => (a, b),
+                        };
+                        assert_eq!(a.minimum(b).to_bits(), lo.to_bits());
+                        assert_eq!(a.maximum(b).to_bits(), hi.to_bits());
+                    }
+                }
+            }
+            #[test]
+            fn midpoint() {
+                assert_eq!((0.5 as $fty).midpoint(0.5), 0.5);
+                assert_eq!((0.5 as $fty).midpoint(2.5), 1.5);
+                assert_eq!((3.0 as $fty).midpoint(4.0), 3.5);
+                assert_eq!((-3.0 as $fty).midpoint(4.0), 0.5);
+                assert_eq!((3.0 as $fty).midpoint(-4.0), -0.5);
+                assert_eq!((-3.0 as $fty).midpoint(-4.0), -3.5);
+                assert_eq!((0.0 as $fty).midpoint(0.0), 0.0);
+                assert_eq!((-0.0 as $fty).midpoint(-0.0), -0.0);
+                assert_eq!((-5.0 as $fty).midpoint(5.0), 0.0);
+                // Values near the extremes must not overflow to infinity.
+                assert_eq!(<$fty>::MAX.midpoint(<$fty>::MAX), <$fty>::MAX);
+                assert_eq!(<$fty>::MIN.midpoint(<$fty>::MIN), <$fty>::MIN);
+                assert_eq!(<$fty>::MAX.midpoint(<$fty>::MIN), 0.0);
+                assert_eq!(<$fty>::MAX.midpoint(0.0), <$fty>::MAX / 2.0);
+                assert_eq!(<$fty>::MAX.midpoint(<$fty>::MIN_POSITIVE), <$fty>::MAX / 2.0);
+                // ... nor lose tiny values by halving them first.
+                assert_eq!(
+                    <$fty>::MIN_POSITIVE.midpoint(<$fty>::MIN_POSITIVE),
+                    <$fty>::MIN_POSITIVE
+                );
+                assert_eq!(<$fty>::MIN_POSITIVE.midpoint(0.0), <$fty>::MIN_POSITIVE / 2.0);
+                assert_eq!(($inf as $fty).midpoint($inf), $inf);
+                assert_eq!(($neginf as $fty).midpoint($neginf), $neginf);
+                assert_eq!(($inf as $fty).midpoint(<$fty>::MAX), $inf);
+                assert!(($inf as $fty).midpoint($neginf).is_nan());
+                assert!(($nan as $fty).midpoint(1.0).is_nan());
+                assert!((1.0 as $fty).midpoint($nan).is_nan());
+                assert!(($nan as $fty).midpoint($nan).is_nan());
+                assert!(($nan as $fty).midpoint($inf).is_nan());
+            }
+            #[test]
+            fn next_up() {
+                let tiny = <$fty>::from_bits(1);
+                let tiny_up = <$fty>::from_bits(2);
+                let max_down = <$fty>::from_bits(<$fty>::MAX.to_bits() - 1);
+                let largest_subnormal = <$fty>::from_bits(<$fty>::MIN_POSITIVE.to_bits() - 1);
+                let smallest_normal = <$fty>::MIN_POSITIVE;
+                let one = 1.0 as $fty;
+                assert_eq!(($neginf as $fty).next_up(), <$fty>::MIN);
+                assert_eq!(<$fty>::MIN.next_up(), -max_down);
+                assert_eq!((-one - <$fty>::EPSILON).next_up(), -one);
+                assert_eq!((-smallest_normal).next_up(), -largest_subnormal);
+                assert_eq!((-tiny_up).next_up(), -tiny);
+                assert_eq!((-tiny).next_up().to_bits(), (-0.0 as $fty).to_bits());
+                assert_eq!((-0.0 as $fty).next_up(), tiny);
+                assert_eq!((0.0 as $fty).next_up(), tiny);
+                assert_eq!(tiny.next_up(), tiny_up);
+                assert_eq!(largest_subnormal.next_up(), smallest_normal);
+                assert_eq!(one.next_up(), one + <$fty>::EPSILON);
+                assert_eq!(max_down.next_up(), <$fty>::MAX);
+                assert_eq!(<$fty>::MAX.next_up(), $inf);
+                assert_eq!(($inf as $fty).next_up(), $inf);
+
+                // NaNs are returned unchanged, payload and sign included.
+                let nan0 = $nan as $fty;
+                let nan1 = <$fty>::from_bits(nan0.to_bits() ^ 0x002a_aaaa);
+                let nan2 = <$fty>::from_bits(nan0.to_bits() ^ 0x0015_5555);
+                assert_eq!(nan0.next_up().to_bits(), nan0.to_bits());
+                assert_eq!(nan1.next_up().to_bits(), nan1.to_bits());
+                assert_eq!(nan2.next_up().to_bits(), nan2.to_bits());
+                assert_eq!((-nan1).next_up().to_bits(), (-nan1).to_bits());
+            }
+            #[test]
+            fn next_down() {
+                let tiny = <$fty>::from_bits(1);
+                let tiny_up = <$fty>::from_bits(2);
+                let max_down = <$fty>::from_bits(<$fty>::MAX.to_bits() - 1);
+                let largest_subnormal = <$fty>::from_bits(<$fty>::MIN_POSITIVE.to_bits() - 1);
+                let smallest_normal = <$fty>::MIN_POSITIVE;
+                let one = 1.0 as $fty;
+                assert_eq!(($neginf as $fty).next_down(), $neginf);
+                assert_eq!(<$fty>::MIN.next_down(), $neginf);
+                assert_eq!((-max_down).next_down(), <$fty>::MIN);
+                assert_eq!((-one).next_down(), -one - <$fty>::EPSILON);
+                assert_eq!((-largest_subnormal).next_down(), -smallest_normal);
+                assert_eq!((-tiny).next_down(), -tiny_up);
+                assert_eq!((-0.0 as $fty).next_down(), -tiny);
+                assert_eq!((0.0 as $fty).next_down(), -tiny);
+                assert_eq!(tiny.next_down().to_bits(), (0.0 as $fty).to_bits());
+                assert_eq!(tiny_up.next_down(), tiny);
+                assert_eq!(smallest_normal.next_down(), largest_subnormal);
+                assert_eq!((one + <$fty>::EPSILON).next_down(), one);
+                assert_eq!(<$fty>::MAX.next_down(), max_down);
+                assert_eq!(($inf as $fty).next_down(), <$fty>::MAX);
+
+                // NaNs are returned unchanged, payload and sign included.
+                let nan0 = $nan as $fty;
+                let nan1 = <$fty>::from_bits(nan0.to_bits() ^ 0x002a_aaaa);
+                let nan2 = <$fty>::from_bits(nan0.to_bits() ^ 0x0015_5555);
+                assert_eq!(nan0.next_down().to_bits(), nan0.to_bits());
+                assert_eq!(nan1.next_down().to_bits(), nan1.to_bits());
+                assert_eq!(nan2.next_down().to_bits(), nan2.to_bits());
+                assert_eq!((-nan1).next_down().to_bits(), (-nan1).to_bits());
+            }
+            #[test]
+            fn next_up_down_roundtrip() {
+                let values = [
+                    <$fty>::MIN,
+                    -1.0 as $fty,
+                    -<$fty>::MIN_POSITIVE,
+                    -<$fty>::from_bits(1),
+                    0.0 as $fty,
+                    <$fty>::from_bits(1),
+                    <$fty>::MIN_POSITIVE,
+                    1.0 as $fty,
+                    <$fty>::MAX,
+                ];
+                for &x in values.iter() {
+                    assert_eq!(x.next_up(), -(-x).next_down());
+                    assert_eq!(x.next_down(), -(-x).next_up());
+                    if x != 0.0 {
+                        assert_eq!(x.next_up().next_down(), x);
+                        assert_eq!(x.next_down().next_up(), x);
+                    }
+                    assert!(x.next_up() > x);
+                    assert!(x.next_down() < x);
+                }
+            }
+            #[test]
             fn rem_euclid() {
                 let a: $fty = 42.0;
                 assert!($inf.rem_euclid(a).is_nan());
@@ -0,0 +1,109 @@
+//! Tests for the `next_multiple_of` family of methods. The 8-bit types are
+//! checked exhaustively against a widened reference implementation, which
+//! covers the overflow boundary of every divisor.
+
+/// The smallest multiple of `rhs` that is `>= lhs`, computed without any
+/// risk of overflow.
+fn reference(lhs: i32, rhs: i32) -> i32 {
+    let r = lhs.rem_euclid(rhs);
+    if r == 0 {
+        lhs
+    } else {
+        lhs + (rhs.abs() - r)
+    }
+}
+
+#[test]
+fn next_multiple_of_u8_exhaustive() {
+    for lhs in u8::MIN..=u8::MAX {
+        assert_eq!(lhs.checked_next_multiple_of(0), None);
+        for rhs in 1..=u8::MAX {
+            let expected = reference(lhs as i32, rhs as i32);
+            match lhs.checked_next_multiple_of(rhs) {
+                Some(actual) => {
+                    assert_eq!(actual as i32, expected, "{}.next_multiple_of({})", lhs, rhs);
+                    assert_eq!(lhs.next_multiple_of(rhs), actual);
+                }
+                None => assert!(expected > u8::MAX as i32, "{}.next_multiple_of({})", lhs, rhs),
+            }
+        }
+    }
+}
+
+#[test]
+fn next_multiple_of_i8_exhaustive() {
+    for lhs in i8::MIN..=i8::MAX {
+        assert_eq!(lhs.checked_next_multiple_of(0), None);
+        for rhs in (i8::MIN..=i8::MAX).filter(|&rhs| rhs != 0) {
+            let expected = reference(lhs as i32, rhs as i32);
+            match lhs.checked_next_multiple_of(rhs) {
+                Some(actual) => {
+                    assert_eq!(actual as i32, expected, "{}.next_multiple_of({})", lhs, rhs);
+                    assert_eq!(lhs.next_multiple_of(rhs), actual);
+                }
+                None => assert!(expected > i8::MAX as i32, "{}.next_multiple_of({})", lhs, rhs),
+            }
+        }
+    }
+}
+
+macro_rules! next_multiple_of_tests {
+    ($($name:ident: $T:ty),*) => {
+        $(
+            #[test]
+            fn $name() {
+                // A divisor of one leaves every value unchanged.
+                assert_eq!((0 as $T).next_multiple_of(1), 0);
+                assert_eq!(<$T>::MAX.next_multiple_of(1), <$T>::MAX);
+                assert_eq!(<$T>::MIN.next_multiple_of(1), <$T>::MIN);
+                // Values that already are a multiple are returned as is.
+                assert_eq!((48 as $T).next_multiple_of(16), 48);
+                assert_eq!((0 as $T).checked_next_multiple_of(<$T>::MAX), Some(0));
+                assert_eq!(<$T>::MAX.checked_next_multiple_of(<$T>::MAX), Some(<$T>::MAX));
+                // Rounding up past the top of the range overflows.
+                assert_eq!((<$T>::MAX - 1).checked_next_multiple_of(<$T>::MAX), Some(<$T>::MAX));
+                assert_eq!(<$T>::MAX.checked_next_multiple_of(2), None);
+                assert_eq!((1 as $T).checked_next_multiple_of(<$T>::MAX), Some(<$T>::MAX));
+                assert_eq!((1 as $T).checked_next_multiple_of(0), None);
+            }
+        )*
+    };
+}
+
+next_multiple_of_tests! {
+    next_multiple_of_u16: u16, next_multiple_of_u32: u32, next_multiple_of_u64: u64,
+    next_multiple_of_u128: u128, next_multiple_of_usize: usize,
+    next_multiple_of_i16: i16, next_multiple_of_i32: i32, next_multiple_of_i64: i64,
+    next_multiple_of_i128: i128, next_multiple_of_isize: isize
+}
+
+#[test]
+fn next_multiple_of_signed_rounds_towards_positive_infinity() {
+    assert_eq!((-23i32).next_multiple_of(8), -16);
+    assert_eq!((-23i32).next_multiple_of(-8), -16);
+    assert_eq!(23i32.next_multiple_of(-8), 24);
+    assert_eq!((-1i32).next_multiple_of(8), 0);
+    assert_eq!(i32::MIN.next_multiple_of(-1), i32::MIN);
+    assert_eq!(i32::MIN.checked_next_multiple_of(-1), Some(i32::MIN));
+    assert_eq!(i32::MIN.next_multiple_of(i32::MIN), i32::MIN);
+    assert_eq!((i32::MIN + 1).next_multiple_of(i32::MIN), 0);
+}
+
+#[test]
+#[should_panic]
+fn next_multiple_of_unsigned_zero_divisor() {
+    let _ = 1u32.next_multiple_of(0);
+}
+
+#[test]
+#[should_panic]
+fn next_multiple_of_signed_zero_divisor() {
+    let _ = 1i32.next_multiple_of(0);
+}
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn next_multiple_of_overflow() {
+    let _ = u8::MAX.next_multiple_of(2);
+}
@@ -1 +1,25 @@
 uint_module!(u128, u128);
+
+#[test]
+fn test_widening_carrying_mul_corner_cases() {
+    const HALF: u128 = 1 << 64;
+
+    // Every partial product of the 64-bit digits is involved here.
+    assert_eq!(u128::MAX.widening_mul(u128::MAX), (1, u128::MAX - 1));
+    assert_eq!(u128::MAX.carrying_mul(u128::MAX, u128::MAX), (0, u128::MAX));
+    assert_eq!(u128::MAX.carrying_mul(u128::MAX, 1), (2, u128::MAX - 1));
+
+    // Only the high digits are set.
+    assert_eq!(HALF.widening_mul(HALF), (0, 1));
+    assert_eq!((u128::MAX << 64).widening_mul(u128::MAX << 64), (0, (u128::MAX - 1) << 64 | 1));
+
+    // Carries out of the middle column.
+    assert_eq!((HALF - 1).widening_mul(HALF - 1), ((HALF - 1) * (HALF - 1), 0));
+    assert_eq!((HALF + 1).widening_mul(u128::MAX), (u128::MAX - HALF, HALF));
+
+    // Agrees with the `u64` version on values that fit in it.
+    let (a, b, c) = (u64::MAX - 12345, u64::MAX / 3, u64::MAX - 1);
+    let (low, high) = a.carrying_mul(b, c);
+    let expected = ((high as u128) << 64 | low as u128, 0);
+    assert_eq!((a as u128).carrying_mul(b as u128, c as u128), expected);
+}
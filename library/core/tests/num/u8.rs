@@ -1 +1,15 @@
 uint_module!(u8, u8);
+
+#[test]
+fn test_widening_carrying_mul_exhaustive() {
+    for a in 0..=u8::MAX {
+        for b in 0..=u8::MAX {
+            let product = a as u16 * b as u16;
+            assert_eq!(a.widening_mul(b), (product as u8, (product >> 8) as u8));
+            for &carry in &[1, 0x7f, 0x80, u8::MAX] {
+                let sum = product + carry as u16;
+                assert_eq!(a.carrying_mul(b, carry), (sum as u8, (sum >> 8) as u8));
+            }
+        }
+    }
+}
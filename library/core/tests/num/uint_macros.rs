@@ -205,6 +205,80 @@ fn test_pow() {
                 assert_eq!(r.overflowing_pow(2), (1 as $T, true));
                 assert_eq!(r.saturating_pow(2), MAX);
             }
+
+            #[test]
+            fn test_carrying_add() {
+                assert_eq!($T::MAX.carrying_add(1, false), (0, true));
+                assert_eq!($T::MAX.carrying_add(0, true), (0, true));
+                assert_eq!($T::MAX.carrying_add(1, true), (1, true));
+                assert_eq!($T::MAX.carrying_add($T::MAX, false), ($T::MAX - 1, true));
+                // Both the addition and the carry-in overflow at the same time.
+                assert_eq!($T::MAX.carrying_add($T::MAX, true), ($T::MAX, true));
+                assert_eq!(($T::MAX - 1).carrying_add(0, true), ($T::MAX, false));
+
+                assert_eq!((0 as $T).carrying_add(0, false), (0, false));
+                assert_eq!((0 as $T).carrying_add(0, true), (1, false));
+                assert_eq!((0 as $T).carrying_add($T::MAX, true), (0, true));
+            }
+
+            #[test]
+            fn test_widening_carrying_mul() {
+                assert_eq!((0 as $T).widening_mul($T::MAX), (0, 0));
+                assert_eq!((1 as $T).widening_mul($T::MAX), ($T::MAX, 0));
+                assert_eq!((2 as $T).widening_mul($T::MAX), ($T::MAX - 1, 1));
+                // (2^BITS - 1)^2 = 2^(2 * BITS) - 2^(BITS + 1) + 1
+                assert_eq!($T::MAX.widening_mul($T::MAX), (1, $T::MAX - 1));
+
+                assert_eq!((0 as $T).carrying_mul(0, $T::MAX), ($T::MAX, 0));
+                assert_eq!((1 as $T).carrying_mul($T::MAX, 1), (0, 1));
+                // The largest possible result still fits in the two halves.
+                assert_eq!($T::MAX.carrying_mul($T::MAX, $T::MAX), (0, $T::MAX));
+
+                let half: $T = 1 << ($T::BITS / 2);
+                assert_eq!(half.widening_mul(half), (0, 1));
+                assert_eq!((half - 1).carrying_mul(half + 1, 1), (0, 1));
+            }
+
+            #[test]
+            fn test_borrowing_sub() {
+                assert_eq!((0 as $T).borrowing_sub(1, false), ($T::MAX, true));
+                assert_eq!((0 as $T).borrowing_sub(0, true), ($T::MAX, true));
+                assert_eq!((0 as $T).borrowing_sub(1, true), ($T::MAX - 1, true));
+                // Both the subtraction and the borrow-in overflow at the same time.
+                assert_eq!((0 as $T).borrowing_sub($T::MAX, true), (0, true));
+                assert_eq!((1 as $T).borrowing_sub(0, true), (0, false));
+
+                assert_eq!($T::MAX.borrowing_sub($T::MAX, false), (0, false));
+                assert_eq!($T::MAX.borrowing_sub($T::MAX, true), ($T::MAX, true));
+                assert_eq!($T::MAX.borrowing_sub(0, true), ($T::MAX - 1, false));
+            }
+
+            #[test]
+            fn test_carrying_add_borrowing_sub_multi_limb() {
+                // `[MAX, MAX, 0] + [1, 0, 0]` carries through two limbs and
+                // subtracting it again borrows through them.
+                let a = [$T::MAX, $T::MAX, 0];
+                let b = [1, 0, 0];
+                let mut sum = [0; 3];
+                let mut carry = false;
+                for i in 0..3 {
+                    let (v, c) = a[i].carrying_add(b[i], carry);
+                    sum[i] = v;
+                    carry = c;
+                }
+                assert_eq!(sum, [0, 0, 1]);
+                assert!(!carry);
+
+                let mut diff = [0; 3];
+                let mut borrow = false;
+                for i in 0..3 {
+                    let (v, c) = sum[i].borrowing_sub(b[i], borrow);
+                    diff[i] = v;
+                    borrow = c;
+                }
+                assert_eq!(diff, a);
+                assert!(!borrow);
+            }
         }
     };
 }
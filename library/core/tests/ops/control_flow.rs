@@ -16,3 +16,22 @@ fn control_flow_discriminants_match_result() {
         discriminant_value(&Result::<i32, i32>::Ok(3)),
     );
 }
+
+#[test]
+fn control_flow_values() {
+    assert_eq!(ControlFlow::<i32, &str>::Break(1).break_value(), Some(1));
+    assert_eq!(ControlFlow::<i32, &str>::Continue("a").break_value(), None);
+    assert_eq!(ControlFlow::<i32, &str>::Break(1).continue_value(), None);
+    assert_eq!(ControlFlow::<i32, &str>::Continue("a").continue_value(), Some("a"));
+}
+
+#[test]
+fn control_flow_map() {
+    let brk = ControlFlow::<i32, &str>::Break(1);
+    let cont = ControlFlow::<i32, &str>::Continue("a");
+
+    assert_eq!(brk.map_break(|x| x * 2), ControlFlow::Break(2));
+    assert_eq!(cont.map_break(|x| x * 2), ControlFlow::Continue("a"));
+    assert_eq!(brk.map_continue(str::len), ControlFlow::Break(1));
+    assert_eq!(cont.map_continue(str::len), ControlFlow::Continue(1));
+}
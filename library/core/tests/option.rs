@@ -398,6 +398,42 @@ fn unwrap<T>(o: Option<T>) -> T {
     assert_eq!(x.get(), 0);
 }
 
+#[test]
+fn test_inspect() {
+    let mut calls = 0;
+    let x = Some(5).inspect(|&v| {
+        assert_eq!(v, 5);
+        calls += 1;
+    });
+    assert_eq!(x, Some(5));
+    assert_eq!(calls, 1);
+
+    let x: Option<i32> = None.inspect(|_| calls += 1);
+    assert_eq!(x, None);
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn test_zip_with() {
+    let called = Cell::new(false);
+    let add = |a: i32, b: i32| {
+        called.set(true);
+        a + b
+    };
+
+    assert_eq!(Some(2).zip_with(Some(3), add), Some(5));
+    assert!(called.replace(false));
+
+    assert_eq!(Some(2).zip_with(None, add), None);
+    assert!(!called.get());
+
+    assert_eq!(None.zip_with(Some(3), add), None);
+    assert!(!called.get());
+
+    assert_eq!(None.zip_with(None, add), None);
+    assert!(!called.get());
+}
+
 #[test]
 pub fn option_ext() {
     let thing = "{{ f }}";
@@ -0,0 +1,33 @@
+use std::cell::{Cell, RefCell};
+use std::panic;
+
+thread_local! {
+    static RECORD_PANIC: Cell<bool> = Cell::new(false);
+    static RECORDED: RefCell<Option<(Option<String>, bool)>> = RefCell::new(None);
+}
+
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)] // no threads
+fn panic_info_message_and_can_unwind() {
+    let prev = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if RECORD_PANIC.with(|r| r.get()) {
+            let message = info.message().map(|m| m.to_string());
+            RECORDED.with(|r| *r.borrow_mut() = Some((message, info.can_unwind())));
+        } else {
+            prev(info);
+        }
+    }));
+
+    RECORD_PANIC.with(|r| r.set(true));
+    let result = panic::catch_unwind(|| panic!("x {}", 1));
+    RECORD_PANIC.with(|r| r.set(false));
+
+    // Set default panic hook again.
+    drop(panic::take_hook());
+
+    assert!(result.is_err());
+    let (message, can_unwind) = RECORDED.with(|r| r.borrow_mut().take()).unwrap();
+    assert_eq!(message.as_deref(), Some("x 1"));
+    assert!(can_unwind);
+}
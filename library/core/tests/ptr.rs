@@ -660,3 +660,80 @@ fn drop(&mut self) {
         }
     }
 }
+
+#[test]
+fn nonnull_slice_from_raw_parts() {
+    const EMPTY: NonNull<[u64]> = NonNull::slice_from_raw_parts(NonNull::dangling(), 0);
+    const EMPTY_LEN: usize = EMPTY.len();
+    assert_eq!(EMPTY_LEN, 0);
+    assert_eq!(EMPTY.as_non_null_ptr(), NonNull::dangling());
+
+    // A dangling but aligned pointer can carry any length as metadata.
+    let dangling: NonNull<[u64]> = NonNull::slice_from_raw_parts(NonNull::dangling(), 42);
+    assert_eq!(dangling.len(), 42);
+    assert_eq!(dangling.as_non_null_ptr(), NonNull::dangling());
+    assert_eq!(dangling.as_mut_ptr() as usize % core::mem::align_of::<u64>(), 0);
+    let round_trip = NonNull::slice_from_raw_parts(dangling.as_non_null_ptr(), dangling.len());
+    assert_eq!(round_trip, dangling);
+
+    let mut array = [1u64, 2, 3];
+    let data = NonNull::new(array.as_mut_ptr()).unwrap();
+    let slice = NonNull::slice_from_raw_parts(data, array.len());
+    assert_eq!(slice.len(), 3);
+    unsafe {
+        *slice.get_unchecked_mut(2).as_ptr() = 30;
+        assert_eq!(slice.get_unchecked_mut(1..).len(), 2);
+    }
+    assert_eq!(slice, NonNull::from(&mut array[..]));
+    assert_eq!(array, [1, 2, 30]);
+}
+
+#[test]
+fn strict_provenance_tag_low_bits() {
+    // A `u64` allocation leaves at least the 3 low bits of its address free.
+    let mut boxed = Box::new(0x1234_5678_9abc_def0u64);
+    let ptr: *mut u64 = &mut *boxed;
+    let addr = ptr.addr();
+    assert_eq!(addr % 8, 0);
+
+    for tag in 0..8 {
+        let tagged = ptr.map_addr(|addr| addr | tag);
+        assert_eq!(tagged.addr() & 7, tag);
+        assert_eq!(tagged.addr() & !7, addr);
+
+        // Stripping the tag again gives a pointer that is usable for the
+        // original allocation.
+        let untagged = tagged.map_addr(|addr| addr & !7);
+        assert_eq!(untagged, ptr);
+        unsafe {
+            *untagged += 1;
+            assert_eq!(*(untagged as *const u64), 0x1234_5678_9abc_def0 + tag as u64 + 1);
+            *untagged -= 1;
+        }
+    }
+
+    // `with_addr` can move around within the allocation.
+    let array = [1u16, 2, 3, 4];
+    let first = array.as_ptr();
+    let last = first.with_addr(first.addr() + 3 * 2);
+    assert_eq!(unsafe { *last }, 4);
+    assert_eq!(last.with_addr(first.addr()), first);
+}
+
+#[test]
+fn strict_provenance_invalid() {
+    const SENTINEL: *const u8 = ptr::invalid(1);
+    assert_eq!(SENTINEL.addr(), 1);
+    assert!(!SENTINEL.is_null());
+
+    let sentinel: *mut u32 = ptr::invalid_mut(usize::MAX & !3);
+    assert_eq!(sentinel.addr(), usize::MAX & !3);
+    assert!(ptr::invalid::<u8>(0).is_null());
+
+    // Zero-sized accesses are fine with any non-null, aligned pointer.
+    let zst: *mut () = ptr::invalid_mut(8);
+    unsafe {
+        zst.write(());
+        let () = zst.read();
+    }
+}
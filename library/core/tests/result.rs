@@ -402,3 +402,42 @@ fn result_try_trait_v2_branch() {
     assert_eq!(Ok::<NonZeroU32, ()>(one).branch(), Continue(one));
     assert_eq!(Err::<NonZeroU32, ()>(()).branch(), Break(Err(())));
 }
+
+#[test]
+fn test_flatten() {
+    let x: Result<Result<u32, &str>, &str> = Ok(Ok(6));
+    assert_eq!(x.flatten(), Ok(6));
+
+    let x: Result<Result<u32, &str>, &str> = Ok(Err("inner"));
+    assert_eq!(x.flatten(), Err("inner"));
+
+    let x: Result<Result<u32, &str>, &str> = Err("outer");
+    assert_eq!(x.flatten(), Err("outer"));
+
+    const FLAT: Result<u32, &str> = Ok::<_, &str>(Ok(6)).flatten();
+    assert_eq!(FLAT, Ok(6));
+}
+
+#[test]
+fn test_inspect() {
+    let mut ok_calls = 0;
+    let mut err_calls = 0;
+
+    let x: Result<i32, &str> = Ok(4);
+    let x = x.inspect(|&v| {
+        assert_eq!(v, 4);
+        ok_calls += 1;
+    });
+    let x = x.inspect_err(|_| err_calls += 1);
+    assert_eq!(x, Ok(4));
+    assert_eq!((ok_calls, err_calls), (1, 0));
+
+    let x: Result<i32, &str> = Err("bad");
+    let x = x.inspect(|_| ok_calls += 1);
+    let x = x.inspect_err(|&e| {
+        assert_eq!(e, "bad");
+        err_calls += 1;
+    });
+    assert_eq!(x, Err("bad"));
+    assert_eq!((ok_calls, err_calls), (1, 1));
+}
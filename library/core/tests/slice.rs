@@ -670,6 +670,94 @@ fn test_array_chunks_mut_zip() {
     assert_eq!(v1, [13, 14, 19, 20, 4]);
 }
 
+#[test]
+fn test_as_chunks() {
+    let v: &[i32] = &[0, 1, 2, 3, 4];
+    let (chunks, rem) = v.as_chunks::<2>();
+    assert_eq!(chunks, &[[0, 1], [2, 3]]);
+    assert_eq!(rem, &[4]);
+
+    let (chunks, rem) = v.as_chunks::<5>();
+    assert_eq!(chunks, &[[0, 1, 2, 3, 4]]);
+    assert!(rem.is_empty());
+
+    let (chunks, rem) = v.as_chunks::<6>();
+    assert!(chunks.is_empty());
+    assert_eq!(rem, v);
+
+    let v2: &[i32] = &[];
+    let (chunks, rem) = v2.as_chunks::<3>();
+    assert!(chunks.is_empty());
+    assert!(rem.is_empty());
+}
+
+#[test]
+fn test_as_rchunks() {
+    let v: &[i32] = &[0, 1, 2, 3, 4];
+    let (rem, chunks) = v.as_rchunks::<2>();
+    assert_eq!(rem, &[0]);
+    assert_eq!(chunks, &[[1, 2], [3, 4]]);
+
+    let (rem, chunks) = v.as_rchunks::<3>();
+    assert_eq!(rem, &[0, 1]);
+    assert_eq!(chunks, &[[2, 3, 4]]);
+
+    let (rem, chunks) = v.as_rchunks::<6>();
+    assert_eq!(rem, v);
+    assert!(chunks.is_empty());
+}
+
+#[test]
+fn test_as_chunks_aliasing() {
+    let v: &[u8] = &[0, 1, 2, 3, 4, 5, 6];
+    let (chunks, rem) = v.as_chunks::<3>();
+    assert_eq!(chunks.as_ptr() as *const u8, v.as_ptr());
+    assert_eq!(rem.as_ptr(), v[6..].as_ptr());
+
+    let (rem, chunks) = v.as_rchunks::<3>();
+    assert_eq!(rem.as_ptr(), v.as_ptr());
+    assert_eq!(chunks.as_ptr() as *const u8, v[1..].as_ptr());
+}
+
+#[test]
+fn test_as_chunks_mut() {
+    let v: &mut [i32] = &mut [0, 1, 2, 3, 4];
+    let (chunks, rem) = v.as_chunks_mut::<2>();
+    for chunk in chunks {
+        chunk.swap(0, 1);
+    }
+    rem[0] = 40;
+    assert_eq!(v, &[1, 0, 3, 2, 40]);
+
+    let (rem, chunks) = v.as_rchunks_mut::<2>();
+    rem[0] = 10;
+    for [a, b] in chunks {
+        *a += *b;
+    }
+    assert_eq!(v, &[10, 3, 3, 42, 40]);
+}
+
+#[test]
+fn test_as_chunks_unchecked() {
+    let v: &[i32] = &[0, 1, 2, 3, 4, 5];
+    // SAFETY: 6 is a multiple of 3.
+    let chunks: &[[i32; 3]] = unsafe { v.as_chunks_unchecked() };
+    assert_eq!(chunks, &[[0, 1, 2], [3, 4, 5]]);
+
+    let v: &mut [i32] = &mut [0, 1, 2, 3];
+    // SAFETY: 4 is a multiple of 2.
+    let chunks: &mut [[i32; 2]] = unsafe { v.as_chunks_unchecked_mut() };
+    chunks[1] = [7, 8];
+    assert_eq!(v, &[0, 1, 7, 8]);
+}
+
+#[test]
+#[should_panic]
+fn test_as_chunks_zero() {
+    let v: &[i32] = &[0, 1, 2];
+    let _ = v.as_chunks::<0>();
+}
+
 #[test]
 fn test_array_windows_infer() {
     let v: &[i32] = &[0, 1, 0, 1];
@@ -1992,6 +2080,80 @@ fn eq(&self, other: &Foo) -> bool {
     assert_eq!(duplicates, [Foo(0, 5), Foo(1, 9)]);
 }
 
+#[test]
+fn test_slice_partition_dedup_all_distinct() {
+    let mut slice = [1, 2, 3, 2, 1];
+
+    let (dedup, duplicates) = slice.partition_dedup();
+
+    assert_eq!(dedup, [1, 2, 3, 2, 1]);
+    assert_eq!(duplicates, []);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri is too slow
+fn test_slice_partition_dedup_random() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    // Keeps every element that isn't equal to the last element kept so far.
+    fn model(v: &[u8]) -> (Vec<u8>, Vec<u8>) {
+        let (mut kept, mut removed): (Vec<u8>, _) = (Vec::new(), Vec::new());
+        for &x in v {
+            if kept.last() == Some(&x) { removed.push(x) } else { kept.push(x) }
+        }
+        (kept, removed)
+    }
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for len in 0..40 {
+        for &modulus in &[1, 2, 4, 255] {
+            let mut v: Vec<u8> = (0..len).map(|_| rng.gen::<u8>() % modulus).collect();
+            let (kept, mut removed) = model(&v);
+
+            let (dedup, duplicates) = v.partition_dedup();
+            assert_eq!(dedup, &kept[..]);
+            // The duplicates are in no specified order.
+            let mut duplicates = duplicates.to_vec();
+            duplicates.sort();
+            removed.sort();
+            assert_eq!(duplicates, removed);
+        }
+    }
+}
+
+#[test]
+fn test_subslice_range() {
+    let v = [1, 2, 3, 4, 5];
+
+    assert_eq!(v.subslice_range(&v), Some(0..5));
+    assert_eq!(v.subslice_range(&v[..2]), Some(0..2));
+    assert_eq!(v.subslice_range(&v[3..]), Some(3..5));
+    assert_eq!(v.subslice_range(&v[1..4]), Some(1..4));
+
+    // Empty subslices at either boundary still point into `v`.
+    assert_eq!(v.subslice_range(&v[..0]), Some(0..0));
+    assert_eq!(v.subslice_range(&v[5..]), Some(5..5));
+
+    // Equal contents in a different allocation are not a subslice.
+    let other = [2, 3];
+    assert_eq!(v.subslice_range(&other), None);
+
+    // Neither is a slice that extends past either end of `v`.
+    let outer = [0, 1, 2, 3, 4, 5, 6];
+    assert_eq!(outer[1..6].subslice_range(&outer[..3]), None);
+    assert_eq!(outer[1..6].subslice_range(&outer[4..]), None);
+    assert_eq!(outer[1..6].subslice_range(&outer[..1]), None);
+    assert_eq!(outer[1..6].subslice_range(&outer[6..]), None);
+}
+
+#[test]
+#[should_panic(expected = "elements are zero-sized")]
+fn test_subslice_range_zst() {
+    let v = [(); 3];
+    let _ = v.subslice_range(&v[1..]);
+}
+
 #[test]
 fn test_copy_within() {
     // Start to end, with a RangeTo.
@@ -2053,6 +2215,55 @@ fn test_copy_within_panics_src_out_of_bounds() {
     bytes.copy_within(usize::MAX..=usize::MAX, 0);
 }
 
+#[test]
+fn test_clone_from_slice_clones_in_place() {
+    use core::cell::Cell;
+
+    // Counts how often it is cloned, so that `clone_from_slice` can be checked
+    // to update the existing elements instead of replacing them with clones.
+    #[derive(Debug, PartialEq)]
+    struct Counted<'a> {
+        value: u32,
+        clones: &'a Cell<usize>,
+    }
+
+    impl Clone for Counted<'_> {
+        fn clone(&self) -> Self {
+            self.clones.set(self.clones.get() + 1);
+            Counted { ..*self }
+        }
+
+        fn clone_from(&mut self, source: &Self) {
+            self.value = source.value;
+        }
+    }
+
+    let clones = Cell::new(0);
+    let src = [1, 2, 3].map(|value| Counted { value, clones: &clones });
+    let mut dst = [7, 8, 9].map(|value| Counted { value, clones: &clones });
+    dst[..].clone_from_slice(&src);
+    assert_eq!(dst, src);
+    assert_eq!(clones.get(), 0);
+
+    let src = [String::from("hello"), String::from("world")];
+    let mut dst = [String::with_capacity(64), String::with_capacity(64)];
+    let ptrs = [dst[0].as_ptr(), dst[1].as_ptr()];
+    dst[..].clone_from_slice(&src);
+    assert_eq!(dst, src);
+    assert_eq!([dst[0].as_ptr(), dst[1].as_ptr()], ptrs);
+
+    let mut bytes = [0u8; 4];
+    bytes[..].clone_from_slice(b"abcd");
+    assert_eq!(&bytes, b"abcd");
+}
+
+#[test]
+#[should_panic(expected = "destination and source slices have different lengths")]
+fn test_clone_from_slice_panics_on_length_mismatch() {
+    let mut v = [String::new(), String::new()];
+    v[..].clone_from_slice(&[String::new()]);
+}
+
 #[test]
 fn test_is_sorted() {
     let empty: [i32; 0] = [];
@@ -2094,3 +2305,78 @@ fn foo(x: &Cell<isize>) -> Foo<'_> {
 
     assert_eq!(x.get(), 1);
 }
+
+#[test]
+fn test_split_array_ref() {
+    let v = &[1, 2, 3, 4, 5, 6][..];
+
+    let (left, right) = v.split_array_ref::<0>();
+    assert_eq!(left, &[]);
+    assert_eq!(right, [1, 2, 3, 4, 5, 6]);
+
+    let (left, right) = v.split_array_ref::<6>();
+    assert_eq!(left, &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(right, []);
+
+    let (left, right) = v.rsplit_array_ref::<0>();
+    assert_eq!(left, [1, 2, 3, 4, 5, 6]);
+    assert_eq!(right, &[]);
+
+    let (left, right) = v.rsplit_array_ref::<6>();
+    assert_eq!(left, []);
+    assert_eq!(right, &[1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn test_split_array_mut() {
+    let v = &mut [1, 2, 3, 4, 5, 6][..];
+
+    {
+        let (left, right) = v.split_array_mut::<2>();
+        left[0] = 10;
+        right[0] = 30;
+    }
+    {
+        let (left, right) = v.rsplit_array_mut::<2>();
+        left[1] = 20;
+        right[1] = 60;
+    }
+    assert_eq!(v, [10, 20, 30, 4, 5, 60]);
+
+    let empty: &mut [i32] = &mut [];
+    let (left, right) = empty.split_array_mut::<0>();
+    assert_eq!(left, &mut []);
+    assert!(right.is_empty());
+}
+
+#[should_panic]
+#[test]
+fn test_split_array_ref_out_of_bounds() {
+    let v = &[1, 2, 3, 4, 5, 6][..];
+
+    v.split_array_ref::<7>();
+}
+
+#[should_panic]
+#[test]
+fn test_split_array_mut_out_of_bounds() {
+    let v = &mut [1, 2, 3, 4, 5, 6][..];
+
+    v.split_array_mut::<7>();
+}
+
+#[should_panic]
+#[test]
+fn test_rsplit_array_ref_out_of_bounds() {
+    let v = &[1, 2, 3, 4, 5, 6][..];
+
+    v.rsplit_array_ref::<7>();
+}
+
+#[should_panic]
+#[test]
+fn test_rsplit_array_mut_out_of_bounds() {
+    let v = &mut [1, 2, 3, 4, 5, 6][..];
+
+    v.rsplit_array_mut::<7>();
+}
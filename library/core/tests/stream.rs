@@ -0,0 +1,89 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::stream::{self, Stream};
+use core::task::{Context, Poll, Waker};
+
+/// Yields `1..=limit`, returning `Pending` once before every item.
+struct Counter {
+    count: usize,
+    limit: usize,
+    ready: bool,
+}
+
+impl Counter {
+    fn new(limit: usize) -> Counter {
+        Counter { count: 0, limit, ready: false }
+    }
+}
+
+impl Stream for Counter {
+    type Item = usize;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<usize>> {
+        if self.count == self.limit {
+            return Poll::Ready(None);
+        }
+        if !self.ready {
+            self.ready = true;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        self.ready = false;
+        self.count += 1;
+        Poll::Ready(Some(self.count))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.limit - self.count;
+        (remaining, Some(remaining))
+    }
+}
+
+/// Polls `future` until it is ready, returning how many polls it took.
+fn block_on<F: Future + Unpin>(mut future: F) -> (F::Output, usize) {
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut polls = 0;
+    loop {
+        polls += 1;
+        if let Poll::Ready(output) = Pin::new(&mut future).poll(&mut cx) {
+            return (output, polls);
+        }
+    }
+}
+
+#[test]
+fn next_drives_stream_to_completion() {
+    let mut counter = Counter::new(3);
+    let mut items = Vec::new();
+    loop {
+        let (item, polls) = block_on(stream::next(&mut counter));
+        match item {
+            Some(item) => {
+                assert_eq!(polls, 2);
+                items.push(item);
+            }
+            None => {
+                assert_eq!(polls, 1);
+                break;
+            }
+        }
+    }
+    assert_eq!(items, [1, 2, 3]);
+    assert_eq!(block_on(stream::next(&mut counter)).0, None);
+}
+
+#[test]
+fn next_through_references() {
+    let mut counter = Counter::new(2);
+
+    let mut by_ref = &mut counter;
+    assert_eq!(Stream::size_hint(&by_ref), (2, Some(2)));
+    assert_eq!(block_on(stream::next(&mut by_ref)).0, Some(1));
+    assert_eq!(Stream::size_hint(&by_ref), (1, Some(1)));
+
+    let mut pinned = Pin::new(&mut counter);
+    assert_eq!(Stream::size_hint(&pinned), (1, Some(1)));
+    assert_eq!(block_on(stream::next(&mut pinned)).0, Some(2));
+    assert_eq!(Stream::size_hint(&pinned), (0, Some(0)));
+    assert_eq!(block_on(stream::next(&mut pinned)).0, None);
+}
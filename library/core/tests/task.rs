@@ -12,3 +12,113 @@ fn poll_const() {
     const IS_PENDING: bool = POLL.is_pending();
     assert!(IS_PENDING);
 }
+
+#[test]
+fn noop_waker_polls_and_wakes() {
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::task::{Context, Waker};
+
+    struct YieldOnce(bool);
+
+    impl Future for YieldOnce {
+        type Output = u32;
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+            if self.0 {
+                Poll::Ready(7)
+            } else {
+                self.0 = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut future = YieldOnce(false);
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Pending);
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Ready(7));
+
+    Waker::noop().clone().wake();
+}
+
+#[test]
+fn noop_waker_will_wake_clones() {
+    use core::task::Waker;
+
+    let waker = Waker::noop();
+    let clone = waker.clone();
+    assert!(waker.will_wake(&clone));
+    assert!(clone.will_wake(waker));
+    assert!(clone.will_wake(&clone.clone()));
+}
+
+#[test]
+fn poll_fn_is_unpin_and_debug() {
+    use core::future::{poll_fn, Future};
+    use core::marker::PhantomPinned;
+    use core::pin::Pin;
+    use core::task::{Context, Waker};
+
+    fn assert_unpin<T: Unpin>(_: &T) {}
+
+    let pinned = PhantomPinned;
+    let mut calls = 0;
+    let mut future = poll_fn(move |_| {
+        let _pinned = &pinned;
+        calls += 1;
+        if calls < 3 { Poll::Pending } else { Poll::Ready(calls) }
+    });
+    assert_unpin(&future);
+    assert_eq!(format!("{:?}", future), "PollFn");
+
+    let mut cx = Context::from_waker(Waker::noop());
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Pending);
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Pending);
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Ready(3));
+}
+
+#[test]
+fn ready_macro_in_manual_poll() {
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::task::{ready, Context, Waker};
+
+    struct Countdown(u32);
+
+    impl Future for Countdown {
+        type Output = ();
+        fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<()> {
+            if self.0 == 0 {
+                Poll::Ready(())
+            } else {
+                self.0 -= 1;
+                Poll::Pending
+            }
+        }
+    }
+
+    // Adds one to the output of `Countdown` once it is ready, and counts
+    // how many times it got past the `ready!`.
+    struct PlusOne {
+        inner: Countdown,
+        resumed: u32,
+    }
+
+    impl Future for PlusOne {
+        type Output = u32;
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+            ready!(Pin::new(&mut self.inner).poll(cx));
+            self.resumed += 1;
+            Poll::Ready(self.resumed + 1)
+        }
+    }
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut future = PlusOne { inner: Countdown(2), resumed: 0 };
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Pending);
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Pending);
+    assert_eq!(future.resumed, 0);
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Ready(2));
+    assert_eq!(future.resumed, 1);
+}
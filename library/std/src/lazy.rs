@@ -267,7 +267,7 @@ pub fn get_or_init<F>(&self, f: F) -> &T
     /// # Examples
     ///
     /// ```
-    /// #![feature(once_cell)]
+    /// #![feature(once_cell, once_cell_try)]
     ///
     /// use std::lazy::SyncOnceCell;
     ///
@@ -280,7 +280,7 @@ pub fn get_or_init<F>(&self, f: F) -> &T
     /// assert_eq!(value, Ok(&92));
     /// assert_eq!(cell.get(), Some(&92))
     /// ```
-    #[unstable(feature = "once_cell", issue = "74465")]
+    #[unstable(feature = "once_cell_try", issue = "109737")]
     pub fn get_or_try_init<F, E>(&self, f: F) -> Result<&T, E>
     where
         F: FnOnce() -> Result<T, E>,
@@ -473,7 +473,7 @@ fn drop(&mut self) {
 
 /// A value which is initialized on the first access.
 ///
-/// This type is a thread-safe `Lazy`, and can be used in statics.
+/// This type is a thread-safe `LazyCell`, and can be used in statics.
 ///
 /// # Examples
 ///
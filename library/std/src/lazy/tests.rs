@@ -1,5 +1,5 @@
 use crate::{
-    lazy::{Lazy, SyncLazy, SyncOnceCell},
+    lazy::{LazyCell, SyncLazy, SyncOnceCell},
     panic,
     sync::{
         atomic::{AtomicUsize, Ordering::SeqCst},
@@ -21,7 +21,7 @@ fn default() -> Self {
         }
     }
 
-    let lazy: Lazy<Mutex<Foo>> = <_>::default();
+    let lazy: LazyCell<Mutex<Foo>> = <_>::default();
 
     assert_eq!(CALLED.load(SeqCst), 0);
 
@@ -36,7 +36,7 @@ fn default() -> Self {
 
 #[test]
 fn lazy_poisoning() {
-    let x: Lazy<String> = Lazy::new(|| panic!("kaboom"));
+    let x: LazyCell<String> = LazyCell::new(|| panic!("kaboom"));
     for _ in 0..2 {
         let res = panic::catch_unwind(panic::AssertUnwindSafe(|| x.len()));
         assert!(res.is_err());
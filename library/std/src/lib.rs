@@ -227,6 +227,7 @@
 #![feature(allow_internal_unstable)]
 #![feature(arbitrary_self_types)]
 #![feature(array_error_internals)]
+#![feature(ascii_char)]
 #![feature(asm)]
 #![feature(assert_matches)]
 #![feature(associated_type_bounds)]
@@ -453,7 +453,7 @@ pub fn begin_panic_fmt(msg: &fmt::Arguments<'_>) -> ! {
         intrinsics::abort()
     }
 
-    let info = PanicInfo::internal_constructor(Some(msg), Location::caller());
+    let info = PanicInfo::internal_constructor(Some(msg), Location::caller(), true);
     begin_panic_handler(&info)
 }
 
@@ -600,14 +600,15 @@ fn rust_panic_with_hook(
         } else {
             // Unfortunately, this does not print a backtrace, because creating
             // a `Backtrace` will allocate, which we must to avoid here.
-            let panicinfo = PanicInfo::internal_constructor(message, location);
+            let panicinfo = PanicInfo::internal_constructor(message, location, false);
             rtprintpanic!("{}\npanicked after panic::always_abort(), aborting.\n", panicinfo);
         }
         intrinsics::abort()
     }
 
     unsafe {
-        let mut info = PanicInfo::internal_constructor(message, location);
+        // A panic while already panicking aborts once the hook has run.
+        let mut info = PanicInfo::internal_constructor(message, location, panics <= 1);
         let _guard = HOOK_LOCK.read();
         match HOOK {
             // Some platforms (like wasm) know that printing to stderr won't ever actually
@@ -1,8 +1,8 @@
 //! Benchmarking module.
 use super::{
     event::CompletedTest,
-    options::BenchMode,
-    test_result::TestResult,
+    options::{BenchMode, Options},
+    test_result::{FailureReason, TestResult},
     types::{TestDesc, TestId},
     Sender,
 };
@@ -14,6 +14,14 @@
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+/// Number of samples taken per measurement round unless `--bench-samples`
+/// says otherwise.
+const DEFAULT_SAMPLES: usize = 50;
+
+/// Longest time a benchmark is sampled for unless `--bench-time` says
+/// otherwise.
+const MAX_BENCH_TIME: Duration = Duration::from_secs(3);
+
 /// An identity function that *__hints__* to the compiler to be maximally pessimistic about what
 /// `black_box` could do.
 ///
@@ -32,6 +40,8 @@ pub fn black_box<T>(dummy: T) -> T {
 pub struct Bencher {
     mode: BenchMode,
     summary: Option<stats::Summary>,
+    samples: usize,
+    time_limit: Option<Duration>,
     pub bytes: u64,
 }
 
@@ -46,7 +56,7 @@ pub fn iter<T, F>(&mut self, mut inner: F)
             return;
         }
 
-        self.summary = Some(iter(&mut inner));
+        self.summary = Some(iter_bounded(&mut inner, self.samples, self.time_limit));
     }
 
     pub fn bench<F>(&mut self, mut f: F) -> Option<stats::Summary>
@@ -119,12 +129,53 @@ fn ns_iter_inner<T, F>(inner: &mut F, k: u64) -> u64
     start.elapsed().as_nanos() as u64
 }
 
+/// Times `inner` being run `n` times per sample, filling `samples` from the
+/// front. Returns the number of samples taken, which is less than
+/// `samples.len()` if `out_of_time` returned `true` before all of them were.
+fn take_samples<T, F, D>(inner: &mut F, samples: &mut [f64], n: u64, out_of_time: &D) -> usize
+where
+    F: FnMut() -> T,
+    D: Fn() -> bool,
+{
+    for (i, p) in samples.iter_mut().enumerate() {
+        *p = ns_iter_inner(inner, n) as f64 / n as f64;
+        if out_of_time() {
+            return i + 1;
+        }
+    }
+    samples.len()
+}
+
 pub fn iter<T, F>(inner: &mut F) -> stats::Summary
 where
     F: FnMut() -> T,
 {
+    iter_bounded(inner, DEFAULT_SAMPLES, None)
+}
+
+/// Like [`iter`], but takes `n_samples` samples per measurement round and, if
+/// `time_limit` is set, stops sampling as soon as the benchmark has run for
+/// that long. The returned summary only covers the samples actually taken.
+pub fn iter_bounded<T, F>(
+    inner: &mut F,
+    n_samples: usize,
+    time_limit: Option<Duration>,
+) -> stats::Summary
+where
+    F: FnMut() -> T,
+{
+    assert!(n_samples > 0, "a benchmark needs at least one sample");
+
+    let bench_start = Instant::now();
+    let out_of_time = || time_limit.map_or(false, |limit| bench_start.elapsed() >= limit);
+
     // Initial bench run to get ballpark figure.
     let ns_single = ns_iter_inner(inner, 1);
+    if out_of_time() {
+        // A single iteration already exhausted the time budget, so that is
+        // the only sample we get.
+        return stats::Summary::new(&[ns_single as f64]);
+    }
 
     // Try to estimate iter count for 1ms falling back to 1m
     // iterations if first run took < 1ns.
@@ -139,25 +190,24 @@ pub fn iter<T, F>(inner: &mut F) -> stats::Summary
     n = cmp::max(1, n);
 
     let mut total_run = Duration::new(0, 0);
-    let samples: &mut [f64] = &mut [0.0_f64; 50];
+    let mut samples = vec![0.0_f64; n_samples];
     loop {
         let loop_start = Instant::now();
 
-        for p in &mut *samples {
-            *p = ns_iter_inner(inner, n) as f64 / n as f64;
+        let taken = take_samples(inner, &mut samples, n, &out_of_time);
+        stats::winsorize(&mut samples[..taken], 5.0);
+        let summ = stats::Summary::new(&samples[..taken]);
+        if out_of_time() {
+            return summ;
         }
 
-        stats::winsorize(samples, 5.0);
-        let summ = stats::Summary::new(samples);
-
-        for p in &mut *samples {
-            let ns = ns_iter_inner(inner, 5 * n);
-            *p = ns as f64 / (5 * n) as f64;
+        let taken = take_samples(inner, &mut samples, 5 * n, &out_of_time);
+        stats::winsorize(&mut samples[..taken], 5.0);
+        let summ5 = stats::Summary::new(&samples[..taken]);
+        if out_of_time() {
+            return summ5;
         }
 
-        stats::winsorize(samples, 5.0);
-        let summ5 = stats::Summary::new(samples);
-
         let loop_run = loop_start.elapsed();
 
         // If we've run for 100ms and seem to have converged to a
@@ -170,8 +220,9 @@ pub fn iter<T, F>(inner: &mut F) -> stats::Summary
         }
 
         total_run += loop_run;
-        // Longest we ever run for is 3s.
-        if total_run > Duration::from_secs(3) {
+        // Longest we ever run for is 3s. A time limit replaces that cap, and
+        // may be longer; it is enforced by `out_of_time` above.
+        if time_limit.is_none() && total_run > MAX_BENCH_TIME {
             return summ5;
         }
 
@@ -193,11 +244,18 @@ pub fn benchmark<F>(
     desc: TestDesc,
     monitor_ch: Sender<CompletedTest>,
     nocapture: bool,
+    options: Options,
     f: F,
 ) where
     F: FnMut(&mut Bencher),
 {
-    let mut bs = Bencher { mode: BenchMode::Auto, summary: None, bytes: 0 };
+    let mut bs = Bencher {
+        mode: BenchMode::Auto,
+        summary: None,
+        samples: options.bench_samples.unwrap_or(DEFAULT_SAMPLES),
+        time_limit: options.bench_time,
+        bytes: 0,
+    };
 
     let data = Arc::new(Mutex::new(Vec::new()));
 
@@ -227,9 +285,10 @@ pub fn benchmark<F>(
         }
         Err(_) => TestResult::TrFailed,
     };
+    let reason = (test_result == TestResult::TrFailed).then(|| FailureReason::Panic);
 
     let stdout = data.lock().unwrap().to_vec();
-    let message = CompletedTest::new(id, desc, test_result, None, stdout);
+    let message = CompletedTest::new(id, desc, test_result, reason, None, stdout);
     monitor_ch.send(message).unwrap();
 }
 
@@ -237,6 +296,12 @@ pub fn run_once<F>(f: F)
 where
     F: FnMut(&mut Bencher),
 {
-    let mut bs = Bencher { mode: BenchMode::Single, summary: None, bytes: 0 };
+    let mut bs = Bencher {
+        mode: BenchMode::Single,
+        summary: None,
+        samples: DEFAULT_SAMPLES,
+        time_limit: None,
+        bytes: 0,
+    };
     bs.bench(f);
 }
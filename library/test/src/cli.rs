@@ -2,9 +2,10 @@
 
 use std::env;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use super::helpers::isatty;
-use super::options::{ColorConfig, Options, OutputFormat, RunIgnored};
+use super::options::{ColorConfig, Options, OutputFormat, RunIgnored, TestTypeFilter};
 use super::time::TestTimeOptions;
 
 #[derive(Debug)]
@@ -12,18 +13,45 @@ pub struct TestOpts {
     pub list: bool,
     pub filters: Vec<String>,
     pub filter_exact: bool,
+    pub exact_filters: Vec<String>,
     pub force_run_in_process: bool,
+    pub capture_combined: bool,
     pub exclude_should_panic: bool,
     pub run_ignored: RunIgnored,
+    pub deny_ignored: bool,
     pub run_tests: bool,
     pub bench_benchmarks: bool,
     pub logfile: Option<PathBuf>,
     pub nocapture: bool,
     pub color: ColorConfig,
     pub format: OutputFormat,
+    /// `Some(0)` runs the tests one by one on the main thread.
     pub test_threads: Option<usize>,
     pub skip: Vec<String>,
+    /// Only run tests defined in source files matching one of these globs.
+    pub filter_files: Vec<String>,
+    /// Only run tests of these types, or all tests if this is empty.
+    pub test_types: Vec<TestTypeFilter>,
     pub time_options: Option<TestTimeOptions>,
+    /// Run the tests in a random order.
+    pub shuffle: bool,
+    /// Seed for the random test order. Implies `shuffle`.
+    pub shuffle_seed: Option<u64>,
+    /// File to save the benchmark results of this run to.
+    pub metrics_out: Option<PathBuf>,
+    /// Benchmark results of an earlier run to compare this run against.
+    pub bench_baseline: Option<PathBuf>,
+    /// Fail the run if a benchmark got slower than in `bench_baseline` by
+    /// more than this many percent.
+    pub bench_fail_threshold: Option<f64>,
+    /// Run the whole suite this many times in a row.
+    pub repeat: usize,
+    /// Warn about threads spawned by the tests that are still running after
+    /// the run.
+    pub detect_leaked_threads: bool,
+    /// How many tests of a resource group may run at the same time, for the
+    /// groups that allow more than one.
+    pub resource_limits: Vec<(String, usize)>,
     pub options: Options,
 }
 
@@ -48,6 +76,12 @@ fn optgroups() -> getopts::Options {
         .optflag("", "ignored", "Run only ignored tests")
         .optflag("", "force-run-in-process", "Forces tests to run in-process when panic=abort")
         .optflag("", "exclude-should-panic", "Excludes tests marked as should_panic")
+        .optflag("", "deny-ignored", "Fail the run if any test was ignored")
+        .optflag(
+            "",
+            "detect-leaked-threads",
+            "Warn if threads spawned by the tests are still running after the run",
+        )
         .optflag("", "test", "Run tests and not benchmarks")
         .optflag("", "bench", "Run benchmarks instead of tests")
         .optflag("", "list", "List all tests and benchmarks")
@@ -59,11 +93,18 @@ fn optgroups() -> getopts::Options {
             "don't capture stdout/stderr of each \
              task, allow printing directly",
         )
+        .optflag(
+            "",
+            "capture-combined",
+            "Capture stdout and stderr of tests run in a subprocess \
+             (panic=abort) in the order they were written, instead of \
+             showing all of stderr after stdout",
+        )
         .optopt(
             "",
             "test-threads",
             "Number of threads used for running tests \
-             in parallel",
+             in parallel (0 runs them one by one on the main thread)",
             "n_threads",
         )
         .optmulti(
@@ -73,6 +114,13 @@ fn optgroups() -> getopts::Options {
              be used multiple times)",
             "FILTER",
         )
+        .optmulti(
+            "",
+            "filter-file",
+            "Only run tests defined in a source file whose path matches the \
+             glob PATTERN (this flag can be used multiple times)",
+            "PATTERN",
+        )
         .optflag(
             "q",
             "quiet",
@@ -80,6 +128,13 @@ fn optgroups() -> getopts::Options {
              Alias to --format=terse",
         )
         .optflag("", "exact", "Exactly match filters rather than by substring")
+        .optmulti(
+            "",
+            "test-type",
+            "Only run tests of the given TYPE (this flag can be used \
+             multiple times)",
+            "unit|integration|doc|bench",
+        )
         .optopt(
             "",
             "color",
@@ -100,6 +155,75 @@ fn optgroups() -> getopts::Options {
             "pretty|terse|json|junit",
         )
         .optflag("", "show-output", "Show captured stdout of successful tests")
+        .optflag(
+            "",
+            "no-capture-on-success",
+            "Discard the captured output of passing tests as soon as they finish, \
+             to save memory in large test suites",
+        )
+        .optflag(
+            "",
+            "json-pretty",
+            "Print each event of the json format as indented, multi-line json \
+             instead of one object per line (requires --format json)",
+        )
+        .optopt(
+            "",
+            "bench-time",
+            "Stop sampling a benchmark once it has run for this many seconds \
+             (default: 3)",
+            "SECONDS",
+        )
+        .optflag("", "shuffle", "Run tests in random order")
+        .optopt(
+            "",
+            "shuffle-seed",
+            "Run tests in random order; seed the random number generator with SEED",
+            "SEED",
+        )
+        .optopt("", "metrics-out", "Save the results of the benchmarks to PATH", "PATH")
+        .optopt(
+            "",
+            "bench-baseline",
+            "Compare the results of the benchmarks to the ones saved \
+             by --metrics-out in PATH",
+            "PATH",
+        )
+        .optopt(
+            "",
+            "bench-fail-threshold",
+            "Fail if a benchmark got slower than in the baseline by \
+             more than PERCENT",
+            "PERCENT",
+        )
+        .optopt(
+            "",
+            "bench-samples",
+            "Number of samples to take per measurement round of a benchmark \
+             (default: 50)",
+            "N",
+        )
+        .optopt(
+            "",
+            "failure-exit-code",
+            "Exit code of the process when a test fails, in the range \
+             1..=255 (default: 101)",
+            "CODE",
+        )
+        .optopt(
+            "",
+            "repeat",
+            "Run the whole test suite N times in a row, and fail if any of \
+             the runs fails (default: 1)",
+            "N",
+        )
+        .optmulti(
+            "",
+            "resource-limit",
+            "Allow N tests of the resource group GROUP to run at the same \
+             time (default: 1)",
+            "GROUP=N",
+        )
         .optopt(
             "Z",
             "",
@@ -151,6 +275,16 @@ fn usage(binary: &str, options: &getopts::Options) {
 tests whose names contain the filter are run. Multiple filter strings may
 be passed, which will run all tests matching any of the filters.
 
+With -Z unstable-options, a FILTER starting with `=` only matches the test
+whose name is exactly the rest of the filter, regardless of --exact. This
+allows exact and substring filters to be mixed in a single run.
+
+With -Z unstable-options, --filter-file PATTERN only runs the tests defined in
+source files whose path matches PATTERN, e.g. `--filter-file 'src/net/**'`.
+Paths are the ones `file!()` expands to in the test. In PATTERN, `?` and `*`
+match within one path component, and `**` matches across components.
+Tests with no recorded source file, like doctests, never match.
+
 By default, all tests are run in parallel. This can be altered with the
 --test-threads flag or the RUST_TEST_THREADS environment variable when running
 tests (set it to 1).
@@ -225,8 +359,33 @@ fn parse_opts_impl(matches: getopts::Matches) -> OptRes {
 
     // Unstable flags
     let force_run_in_process = unstable_optflag!(matches, allow_unstable, "force-run-in-process");
+    let capture_combined = unstable_optflag!(matches, allow_unstable, "capture-combined");
     let exclude_should_panic = unstable_optflag!(matches, allow_unstable, "exclude-should-panic");
+    let deny_ignored = unstable_optflag!(matches, allow_unstable, "deny-ignored");
+    let detect_leaked_threads =
+        unstable_optflag!(matches, allow_unstable, "detect-leaked-threads");
+    let json_pretty = unstable_optflag!(matches, allow_unstable, "json-pretty");
+    let discard_passing_output =
+        unstable_optflag!(matches, allow_unstable, "no-capture-on-success");
+    if discard_passing_output && matches.opt_present("show-output") {
+        return Err("--no-capture-on-success can't be combined with --show-output".into());
+    }
     let time_options = get_time_options(&matches, allow_unstable)?;
+    let bench_time = get_bench_time(&matches, allow_unstable)?;
+    let bench_samples = get_bench_samples(&matches, allow_unstable)?;
+    let failure_exit_code = get_failure_exit_code(&matches, allow_unstable)?;
+    let repeat = get_repeat(&matches, allow_unstable)?;
+    let resource_limits = get_resource_limits(&matches, allow_unstable)?;
+    let filter_files = get_filter_files(&matches, allow_unstable)?;
+    let shuffle = unstable_optflag!(matches, allow_unstable, "shuffle");
+    let shuffle_seed = get_shuffle_seed(&matches, allow_unstable)?;
+    let test_types = get_test_types(&matches, allow_unstable)?;
+    let metrics_out = get_unstable_path(&matches, allow_unstable, "metrics-out")?;
+    let bench_baseline = get_unstable_path(&matches, allow_unstable, "bench-baseline")?;
+    let bench_fail_threshold = get_bench_fail_threshold(&matches, allow_unstable)?;
+    if bench_fail_threshold.is_some() && bench_baseline.is_none() {
+        return Err("--bench-fail-threshold requires --bench-baseline".into());
+    }
 
     let include_ignored = matches.opt_present("include-ignored");
     let quiet = matches.opt_present("quiet");
@@ -239,21 +398,33 @@ fn parse_opts_impl(matches: getopts::Matches) -> OptRes {
 
     let logfile = get_log_file(&matches)?;
     let run_ignored = get_run_ignored(&matches, include_ignored)?;
-    let filters = matches.free.clone();
+    let (filters, exact_filters) = get_filters(&matches, allow_unstable)?;
     let nocapture = get_nocapture(&matches)?;
-    let test_threads = get_test_threads(&matches)?;
+    let test_threads = get_test_threads(&matches, allow_unstable)?;
     let color = get_color_config(&matches)?;
     let format = get_format(&matches, quiet, allow_unstable)?;
+    if json_pretty && format != OutputFormat::Json {
+        return Err("--json-pretty requires --format json".into());
+    }
 
-    let options = Options::new().display_output(matches.opt_present("show-output"));
+    let options = Options::new()
+        .display_output(matches.opt_present("show-output"))
+        .json_pretty(json_pretty)
+        .discard_passing_output(discard_passing_output)
+        .bench_time(bench_time)
+        .bench_samples(bench_samples)
+        .failure_exit_code(failure_exit_code);
 
     let test_opts = TestOpts {
         list,
         filters,
         filter_exact: exact,
+        exact_filters,
         force_run_in_process,
+        capture_combined,
         exclude_should_panic,
         run_ignored,
+        deny_ignored,
         run_tests,
         bench_benchmarks,
         logfile,
@@ -262,7 +433,17 @@ fn parse_opts_impl(matches: getopts::Matches) -> OptRes {
         format,
         test_threads,
         skip,
+        filter_files,
+        test_types,
         time_options,
+        shuffle,
+        shuffle_seed,
+        metrics_out,
+        bench_baseline,
+        bench_fail_threshold,
+        repeat,
+        detect_leaked_threads,
+        resource_limits,
         options,
     };
 
@@ -303,10 +484,272 @@ fn get_time_options(
     Ok(options)
 }
 
-fn get_test_threads(matches: &getopts::Matches) -> OptPartRes<Option<usize>> {
+fn get_bench_time(
+    matches: &getopts::Matches,
+    allow_unstable: bool,
+) -> OptPartRes<Option<Duration>> {
+    let bench_time = match matches.opt_str("bench-time") {
+        Some(_) if !allow_unstable => {
+            return Err("The \"bench-time\" flag is only accepted on the nightly compiler \
+                        with -Z unstable-options"
+                .into());
+        }
+        Some(secs_str) => match secs_str.parse::<f64>() {
+            Ok(secs) if secs.is_finite() && secs > 0.0 => Some(Duration::from_secs_f64(secs)),
+            _ => {
+                return Err(format!(
+                    "argument for --bench-time must be a positive number of seconds (was {})",
+                    secs_str
+                ));
+            }
+        },
+        None => None,
+    };
+
+    Ok(bench_time)
+}
+
+fn get_bench_samples(
+    matches: &getopts::Matches,
+    allow_unstable: bool,
+) -> OptPartRes<Option<usize>> {
+    let bench_samples = match matches.opt_str("bench-samples") {
+        Some(_) if !allow_unstable => {
+            return Err("The \"bench-samples\" flag is only accepted on the nightly compiler \
+                        with -Z unstable-options"
+                .into());
+        }
+        Some(n_str) => match n_str.parse::<usize>() {
+            Ok(0) => return Err("argument for --bench-samples must not be 0".to_string()),
+            Ok(n) => Some(n),
+            Err(e) => {
+                return Err(format!(
+                    "argument for --bench-samples must be a number > 0 \
+                     (error: {})",
+                    e
+                ));
+            }
+        },
+        None => None,
+    };
+
+    Ok(bench_samples)
+}
+
+fn get_failure_exit_code(
+    matches: &getopts::Matches,
+    allow_unstable: bool,
+) -> OptPartRes<Option<i32>> {
+    let failure_exit_code = match matches.opt_str("failure-exit-code") {
+        Some(_) if !allow_unstable => {
+            return Err("The \"failure-exit-code\" flag is only accepted on the nightly \
+                        compiler with -Z unstable-options"
+                .into());
+        }
+        Some(n_str) => match n_str.parse::<i32>() {
+            Ok(n @ 1..=255) => Some(n),
+            _ => {
+                return Err(format!(
+                    "argument for --failure-exit-code must be a number in the range 1..=255, \
+                     got `{}`",
+                    n_str
+                ));
+            }
+        },
+        None => None,
+    };
+
+    Ok(failure_exit_code)
+}
+
+fn get_repeat(matches: &getopts::Matches, allow_unstable: bool) -> OptPartRes<usize> {
+    let repeat = match matches.opt_str("repeat") {
+        Some(_) if !allow_unstable => {
+            return Err("The \"repeat\" flag is only accepted on the nightly compiler \
+                        with -Z unstable-options"
+                .into());
+        }
+        Some(n_str) => match n_str.parse::<usize>() {
+            Ok(n) if n > 0 => n,
+            _ => {
+                return Err(format!(
+                    "argument for --repeat must be a number > 0, got `{}`",
+                    n_str
+                ));
+            }
+        },
+        None => 1,
+    };
+
+    Ok(repeat)
+}
+
+fn get_resource_limits(
+    matches: &getopts::Matches,
+    allow_unstable: bool,
+) -> OptPartRes<Vec<(String, usize)>> {
+    let limits = matches.opt_strs("resource-limit");
+    if !limits.is_empty() && !allow_unstable {
+        return Err("The \"resource-limit\" flag is only accepted on the nightly compiler \
+                    with -Z unstable-options"
+            .into());
+    }
+
+    limits
+        .into_iter()
+        .map(|limit| {
+            let parsed = limit.split_once('=').and_then(|(group, n)| match n.parse::<usize>() {
+                Ok(n) if n > 0 && !group.is_empty() => Some((group.to_string(), n)),
+                _ => None,
+            });
+            parsed.ok_or_else(|| {
+                format!(
+                    "argument for --resource-limit must be of the form GROUP=N with N > 0, \
+                     got `{}`",
+                    limit
+                )
+            })
+        })
+        .collect()
+}
+
+fn get_filter_files(matches: &getopts::Matches, allow_unstable: bool) -> OptPartRes<Vec<String>> {
+    let filter_files = matches.opt_strs("filter-file");
+    if !filter_files.is_empty() && !allow_unstable {
+        return Err("The \"filter-file\" flag is only accepted on the nightly compiler \
+                    with -Z unstable-options"
+            .into());
+    }
+
+    Ok(filter_files)
+}
+
+fn get_shuffle_seed(matches: &getopts::Matches, allow_unstable: bool) -> OptPartRes<Option<u64>> {
+    let shuffle_seed = match matches.opt_str("shuffle-seed") {
+        Some(_) if !allow_unstable => {
+            return Err("The \"shuffle-seed\" flag is only accepted on the nightly compiler \
+                        with -Z unstable-options"
+                .into());
+        }
+        Some(n_str) => match n_str.parse::<u64>() {
+            Ok(n) => Some(n),
+            Err(e) => {
+                return Err(format!(
+                    "argument for --shuffle-seed must be a number \
+                     (error: {})",
+                    e
+                ));
+            }
+        },
+        None => None,
+    };
+
+    Ok(shuffle_seed)
+}
+
+fn get_unstable_path(
+    matches: &getopts::Matches,
+    allow_unstable: bool,
+    flag: &str,
+) -> OptPartRes<Option<PathBuf>> {
+    match matches.opt_str(flag) {
+        Some(_) if !allow_unstable => Err(format!(
+            "The \"{}\" flag is only accepted on the nightly compiler with -Z unstable-options",
+            flag
+        )),
+        path => Ok(path.map(PathBuf::from)),
+    }
+}
+
+fn get_bench_fail_threshold(
+    matches: &getopts::Matches,
+    allow_unstable: bool,
+) -> OptPartRes<Option<f64>> {
+    let threshold = match matches.opt_str("bench-fail-threshold") {
+        Some(_) if !allow_unstable => {
+            return Err("The \"bench-fail-threshold\" flag is only accepted on the nightly \
+                        compiler with -Z unstable-options"
+                .into());
+        }
+        Some(pct_str) => match pct_str.parse::<f64>() {
+            Ok(pct) if pct.is_finite() && pct >= 0.0 => Some(pct),
+            _ => {
+                return Err(format!(
+                    "argument for --bench-fail-threshold must be a non-negative \
+                     percentage (was {})",
+                    pct_str
+                ));
+            }
+        },
+        None => None,
+    };
+
+    Ok(threshold)
+}
+
+/// Splits the free arguments into substring filters and `=`-prefixed exact
+/// filters.
+fn get_filters(
+    matches: &getopts::Matches,
+    allow_unstable: bool,
+) -> OptPartRes<(Vec<String>, Vec<String>)> {
+    let mut filters = Vec::new();
+    let mut exact_filters = Vec::new();
+    for filter in &matches.free {
+        match filter.strip_prefix('=') {
+            Some(name) => {
+                if !allow_unstable {
+                    return Err(format!(
+                        "The exact filter \"{}\" is only accepted on the nightly compiler \
+                         with -Z unstable-options",
+                        filter
+                    ));
+                }
+                exact_filters.push(name.to_string());
+            }
+            None => filters.push(filter.clone()),
+        }
+    }
+    Ok((filters, exact_filters))
+}
+
+fn get_test_types(
+    matches: &getopts::Matches,
+    allow_unstable: bool,
+) -> OptPartRes<Vec<TestTypeFilter>> {
+    let types = matches.opt_strs("test-type");
+    if !types.is_empty() && !allow_unstable {
+        return Err("The \"test-type\" flag is only accepted on the nightly compiler \
+                    with -Z unstable-options"
+            .into());
+    }
+
+    types
+        .iter()
+        .map(|ty| match &**ty {
+            "unit" => Ok(TestTypeFilter::Unit),
+            "integration" => Ok(TestTypeFilter::Integration),
+            "doc" => Ok(TestTypeFilter::Doc),
+            "bench" => Ok(TestTypeFilter::Bench),
+            _ => Err(format!(
+                "argument for --test-type must be unit, integration, doc or bench (was {})",
+                ty
+            )),
+        })
+        .collect()
+}
+
+fn get_test_threads(
+    matches: &getopts::Matches,
+    allow_unstable: bool,
+) -> OptPartRes<Option<usize>> {
     let test_threads = match matches.opt_str("test-threads") {
         Some(n_str) => match n_str.parse::<usize>() {
-            Ok(0) => return Err("argument for --test-threads must not be 0".to_string()),
+            Ok(0) if !allow_unstable => {
+                return Err("argument for --test-threads must not be 0 \
+                            (0 is only accepted on the nightly compiler with -Z unstable-options)"
+                    .to_string());
+            }
             Ok(n) => Some(n),
             Err(e) => {
                 return Err(format!(
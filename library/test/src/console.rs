@@ -10,12 +10,15 @@
     cli::TestOpts,
     event::{CompletedTest, TestEvent},
     filter_tests,
-    formatters::{JsonFormatter, JunitFormatter, OutputFormatter, PrettyFormatter, TerseFormatter},
+    formatters::{
+        write_json_discovered, JsonFormatter, JunitFormatter, OutputFormatter, PrettyFormatter,
+        TerseFormatter,
+    },
     helpers::{concurrency::get_concurrency, metrics::MetricMap},
     options::{Options, OutputFormat},
     run_tests, term,
     test_result::TestResult,
-    time::{TestExecTime, TestSuiteExecTime},
+    time::{TestCpuTime, TestExecTime, TestSuiteExecTime},
     types::{NamePadding, TestDesc, TestDescAndFn},
 };
 
@@ -55,6 +58,13 @@ pub struct ConsoleTestState {
     pub failures: Vec<(TestDesc, Vec<u8>)>,
     pub not_failures: Vec<(TestDesc, Vec<u8>)>,
     pub time_failures: Vec<(TestDesc, Vec<u8>)>,
+    /// The tests that were ignored, collected only for `--deny-ignored`.
+    pub ignored_tests: Option<Vec<TestDesc>>,
+    /// Seed the tests were shuffled with, if they ran in random order.
+    pub shuffle_seed: Option<u64>,
+    /// Threads the tests left running, if `--detect-leaked-threads` was
+    /// passed and they could be counted.
+    pub leaked_threads: Option<usize>,
     pub options: Options,
 }
 
@@ -79,6 +89,9 @@ pub fn new(opts: &TestOpts) -> io::Result<ConsoleTestState> {
             failures: Vec::new(),
             not_failures: Vec::new(),
             time_failures: Vec::new(),
+            ignored_tests: opts.deny_ignored.then(Vec::new),
+            shuffle_seed: None,
+            leaked_threads: None,
             options: opts.options,
         })
     }
@@ -103,6 +116,7 @@ pub fn write_log_result(
         test: &TestDesc,
         result: &TestResult,
         exec_time: Option<&TestExecTime>,
+        cpu_time: Option<&TestCpuTime>,
     ) -> io::Result<()> {
         self.write_log(|| {
             format!(
@@ -112,6 +126,7 @@ pub fn write_log_result(
                     TestResult::TrFailed => "failed".to_owned(),
                     TestResult::TrFailedMsg(ref msg) => format!("failed: {}", msg),
                     TestResult::TrIgnored => "ignored".to_owned(),
+                    TestResult::TrIgnoredMsg(ref msg) => format!("ignored: {}", msg),
                     TestResult::TrAllowedFail => "failed (allowed)".to_owned(),
                     TestResult::TrBench(ref bs) => fmt_bench_samples(bs),
                     TestResult::TrTimedFail => "failed (time limit exceeded)".to_owned(),
@@ -120,7 +135,10 @@ pub fn write_log_result(
             )
         })?;
         if let Some(exec_time) = exec_time {
-            self.write_log(|| format!(" <{}>", exec_time))?;
+            match cpu_time {
+                Some(cpu_time) => self.write_log(|| format!(" <{}, {}>", exec_time, cpu_time))?,
+                None => self.write_log(|| format!(" <{}>", exec_time))?,
+            }
         }
         self.write_log(|| "\n")
     }
@@ -137,7 +155,19 @@ pub fn list_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> io::Res
         Some(t) => OutputLocation::Pretty(t),
     };
 
+    list_tests(&mut output, opts, tests)
+}
+
+// Writes the listing of the tests selected by `opts` to `output`. Tests are
+// filtered the same way as for a real run, so e.g. `--ignored` only lists the
+// ignored tests.
+pub(crate) fn list_tests<T: Write>(
+    output: &mut OutputLocation<T>,
+    opts: &TestOpts,
+    tests: Vec<TestDescAndFn>,
+) -> io::Result<()> {
     let quiet = opts.format == OutputFormat::Terse;
+    let json = opts.format == OutputFormat::Json;
     let mut st = ConsoleTestState::new(opts)?;
 
     let mut ntest = 0;
@@ -146,7 +176,7 @@ pub fn list_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> io::Res
     for test in filter_tests(&opts, tests) {
         use crate::TestFn::*;
 
-        let TestDescAndFn { desc: TestDesc { name, .. }, testfn } = test;
+        let TestDescAndFn { desc, testfn } = test;
 
         let fntype = match testfn {
             StaticTestFn(..) | DynTestFn(..) => {
@@ -159,8 +189,12 @@ pub fn list_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> io::Res
             }
         };
 
-        writeln!(output, "{}: {}", name, fntype)?;
-        st.write_log(|| format!("{} {}\n", fntype, name))?;
+        if json {
+            write_json_discovered(output, &desc, fntype, opts.options.json_pretty)?;
+        } else {
+            writeln!(output, "{}: {}", desc.name, fntype)?;
+        }
+        st.write_log(|| format!("{} {}\n", fntype, desc.name))?;
     }
 
     fn plural(count: u32, s: &str) -> String {
@@ -170,7 +204,7 @@ fn plural(count: u32, s: &str) -> String {
         }
     }
 
-    if !quiet {
+    if !quiet && !json {
         if ntest != 0 || nbench != 0 {
             writeln!(output)?;
         }
@@ -182,15 +216,21 @@ fn plural(count: u32, s: &str) -> String {
 }
 
 // Updates `ConsoleTestState` depending on result of the test execution.
-fn handle_test_result(st: &mut ConsoleTestState, completed_test: CompletedTest) {
+pub(crate) fn handle_test_result(st: &mut ConsoleTestState, completed_test: CompletedTest) {
     let test = completed_test.desc;
-    let stdout = completed_test.stdout;
+    // With `--capture-combined`, show the output in the order it was written.
+    let stdout = completed_test.combined_output.unwrap_or(completed_test.stdout);
     match completed_test.result {
         TestResult::TrOk => {
             st.passed += 1;
             st.not_failures.push((test, stdout));
         }
-        TestResult::TrIgnored => st.ignored += 1,
+        TestResult::TrIgnored | TestResult::TrIgnoredMsg(_) => {
+            st.ignored += 1;
+            if let Some(ignored_tests) = &mut st.ignored_tests {
+                ignored_tests.push(test);
+            }
+        }
         TestResult::TrAllowedFail => st.allowed_fail += 1,
         TestResult::TrBench(bs) => {
             st.metrics.insert_metric(
@@ -219,29 +259,45 @@ fn handle_test_result(st: &mut ConsoleTestState, completed_test: CompletedTest)
 
 // Handler for events that occur during test execution.
 // It is provided as a callback to the `run_tests` function.
-fn on_test_event(
+pub(crate) fn on_test_event(
     event: &TestEvent,
     st: &mut ConsoleTestState,
     out: &mut dyn OutputFormatter,
 ) -> io::Result<()> {
     match (*event).clone() {
-        TestEvent::TeFiltered(ref filtered_tests) => {
+        TestEvent::TeFiltered(ref filtered_tests, shuffle_seed) => {
             st.total = filtered_tests.len();
+            st.shuffle_seed = shuffle_seed;
             out.write_run_start(filtered_tests.len())?;
         }
         TestEvent::TeFilteredOut(filtered_out) => {
             st.filtered_out = filtered_out;
         }
+        TestEvent::TeLeakedThreads(leaked) => {
+            st.leaked_threads = Some(leaked);
+        }
         TestEvent::TeWait(ref test) => out.write_test_start(test)?,
         TestEvent::TeTimeout(ref test) => out.write_timeout(test)?,
         TestEvent::TeResult(completed_test) => {
             let test = &completed_test.desc;
             let result = &completed_test.result;
+            let reason = completed_test.reason;
             let exec_time = &completed_test.exec_time;
-            let stdout = &completed_test.stdout;
-
-            st.write_log_result(test, result, exec_time.as_ref())?;
-            out.write_result(test, result, exec_time.as_ref(), &*stdout, st)?;
+            let cpu_time = &completed_test.cpu_time;
+            let stdout = completed_test.combined_output.as_ref().unwrap_or(&completed_test.stdout);
+            let metrics = &completed_test.metrics;
+
+            st.write_log_result(test, result, exec_time.as_ref(), cpu_time.as_ref())?;
+            out.write_result(
+                test,
+                result,
+                reason,
+                exec_time.as_ref(),
+                cpu_time.as_ref(),
+                &*stdout,
+                metrics,
+                st,
+            )?;
             handle_test_result(st, completed_test);
         }
     }
@@ -252,6 +308,14 @@ fn on_test_event(
 /// A simple console test runner.
 /// Runs provided tests reporting process and results to the stdout.
 pub fn run_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> io::Result<bool> {
+    run_tests_console_with_state(opts, tests).map(|(success, _)| success)
+}
+
+/// Like `run_tests_console`, but also returns the state at the end of the run.
+pub(crate) fn run_tests_console_with_state(
+    opts: &TestOpts,
+    tests: Vec<TestDescAndFn>,
+) -> io::Result<(bool, ConsoleTestState)> {
     let output = match term::stdout() {
         None => OutputLocation::Raw(io::stdout()),
         Some(t) => OutputLocation::Pretty(t),
@@ -276,7 +340,9 @@ pub fn run_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> io::Resu
         OutputFormat::Terse => {
             Box::new(TerseFormatter::new(output, opts.use_color(), max_name_len, is_multithreaded))
         }
-        OutputFormat::Json => Box::new(JsonFormatter::new(output)),
+        OutputFormat::Json => {
+            Box::new(JsonFormatter::new(output).pretty(opts.options.json_pretty))
+        }
         OutputFormat::Junit => Box::new(JunitFormatter::new(output)),
     };
     let mut st = ConsoleTestState::new(opts)?;
@@ -292,7 +358,111 @@ pub fn run_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> io::Resu
 
     assert!(st.current_test_count() == st.total);
 
-    out.write_run_finish(&st)
+    let success = out.write_run_finish(&st)?;
+    let no_denied_ignores = check_ignored(opts, &st, &mut io::stderr())?;
+    check_leaked_threads(opts, &st, &mut io::stderr())?;
+
+    if let Some(ref path) = opts.metrics_out {
+        st.metrics.save(path)?;
+    }
+    let no_regressions = match opts.bench_baseline {
+        Some(ref path) => {
+            let baseline = MetricMap::load(path)?;
+            compare_to_baseline(
+                &st.metrics,
+                &baseline,
+                opts.bench_fail_threshold,
+                &mut io::stderr(),
+            )?
+        }
+        None => true,
+    };
+
+    Ok((success && no_denied_ignores && no_regressions, st))
+}
+
+// Implements `--deny-ignored`: lists the tests that were ignored during the run
+// and returns `false` if there were any.
+pub(crate) fn check_ignored(
+    opts: &TestOpts,
+    st: &ConsoleTestState,
+    out: &mut dyn Write,
+) -> io::Result<bool> {
+    let ignored_tests = match st.ignored_tests {
+        Some(ref ignored_tests) if opts.deny_ignored && !ignored_tests.is_empty() => ignored_tests,
+        _ => return Ok(true),
+    };
+
+    writeln!(out, "error: the following tests were ignored, but --deny-ignored was passed:")?;
+    let mut names: Vec<_> = ignored_tests.iter().map(|desc| desc.name.to_string()).collect();
+    names.sort();
+    for name in &names {
+        writeln!(out, "    {}", name)?;
+    }
+
+    Ok(false)
+}
+
+// Implements `--detect-leaked-threads`: warns about the threads the tests
+// spawned but did not wait for. This does not fail the run.
+pub(crate) fn check_leaked_threads(
+    opts: &TestOpts,
+    st: &ConsoleTestState,
+    out: &mut dyn Write,
+) -> io::Result<()> {
+    if !opts.detect_leaked_threads {
+        return Ok(());
+    }
+
+    match st.leaked_threads {
+        None => writeln!(out, "warning: leaked threads can't be detected on this platform"),
+        Some(0) => Ok(()),
+        Some(leaked) => writeln!(
+            out,
+            "warning: {} thread{} spawned by the tests {} still running after the run",
+            leaked,
+            if leaked == 1 { "" } else { "s" },
+            if leaked == 1 { "was" } else { "were" },
+        ),
+    }
+}
+
+// Implements `--bench-baseline`: prints how much every benchmark of the run
+// changed relative to `baseline`, and returns `false` if any of them got
+// slower by more than `fail_threshold` percent.
+pub(crate) fn compare_to_baseline(
+    current: &MetricMap,
+    baseline: &MetricMap,
+    fail_threshold: Option<f64>,
+    out: &mut dyn Write,
+) -> io::Result<bool> {
+    if current.is_empty() {
+        return Ok(true);
+    }
+
+    let mut no_regressions = true;
+    writeln!(out, "benchmarks compared to baseline:")?;
+    for (name, _) in current.iter() {
+        let ratio = match current.ratio_of(name, baseline) {
+            Some(ratio) => ratio,
+            None => {
+                writeln!(out, "    {}: no baseline", name)?;
+                continue;
+            }
+        };
+        let change = (ratio - 1.0) * 100.0;
+        let regressed = fail_threshold.map_or(false, |threshold| change > threshold);
+        no_regressions &= !regressed;
+        writeln!(
+            out,
+            "    {}: {:+.2}%{}",
+            name,
+            change,
+            if regressed { " REGRESSED" } else { "" }
+        )?;
+    }
+
+    Ok(no_regressions)
 }
 
 // Calculates padding for given test description.
@@ -1,8 +1,9 @@
 //! Module containing different events that can occur
 //! during tests execution process.
 
-use super::test_result::TestResult;
-use super::time::TestExecTime;
+use super::helpers::metrics::MetricMap;
+use super::test_result::{FailureReason, TestResult};
+use super::time::{TestCpuTime, TestExecTime};
 use super::types::{TestDesc, TestId};
 
 #[derive(Debug, Clone)]
@@ -10,8 +11,18 @@ pub struct CompletedTest {
     pub id: TestId,
     pub desc: TestDesc,
     pub result: TestResult,
+    pub reason: Option<FailureReason>,
     pub exec_time: Option<TestExecTime>,
+    /// CPU time the test consumed. Only measured along with `exec_time`, and
+    /// only on platforms where libtest knows how to.
+    pub cpu_time: Option<TestCpuTime>,
     pub stdout: Vec<u8>,
+    /// With `--capture-combined`, the output of a test run in a subprocess in
+    /// the order it was written, stdout and stderr interleaved. `stdout` still
+    /// has the subprocess's stdout and stderr one after the other.
+    pub combined_output: Option<Vec<u8>>,
+    /// Metrics the test reported through `report_metric`.
+    pub metrics: MetricMap,
 }
 
 impl CompletedTest {
@@ -19,18 +30,32 @@ pub fn new(
         id: TestId,
         desc: TestDesc,
         result: TestResult,
+        reason: Option<FailureReason>,
         exec_time: Option<TestExecTime>,
         stdout: Vec<u8>,
     ) -> Self {
-        Self { id, desc, result, exec_time, stdout }
+        Self {
+            id,
+            desc,
+            result,
+            reason,
+            exec_time,
+            cpu_time: None,
+            stdout,
+            combined_output: None,
+            metrics: MetricMap::new(),
+        }
     }
 }
 
 #[derive(Debug, Clone)]
 pub enum TestEvent {
-    TeFiltered(Vec<TestDesc>),
+    TeFiltered(Vec<TestDesc>, Option<u64>),
     TeWait(TestDesc),
     TeResult(CompletedTest),
     TeTimeout(TestDesc),
     TeFilteredOut(usize),
+    /// Number of threads spawned during the run that were still alive at its
+    /// end. Only sent with `--detect-leaked-threads`.
+    TeLeakedThreads(usize),
 }
@@ -3,23 +3,47 @@
 use super::OutputFormatter;
 use crate::{
     console::{ConsoleTestState, OutputLocation},
-    test_result::TestResult,
+    helpers::metrics::MetricMap,
+    test_result::{FailureReason, TestResult},
     time,
     types::TestDesc,
 };
 
 pub(crate) struct JsonFormatter<T> {
     out: OutputLocation<T>,
+    /// Whether each event is printed as indented, multi-line json rather than
+    /// as a single line.
+    pretty: bool,
+    /// The part of the current event written so far, when `pretty` is set.
+    /// Events are assembled piecewise, so they can only be indented once the
+    /// whole object is known.
+    pending: String,
 }
 
 impl<T: Write> JsonFormatter<T> {
     pub fn new(out: OutputLocation<T>) -> Self {
-        Self { out }
+        Self { out, pretty: false, pending: String::new() }
+    }
+
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    #[cfg(test)]
+    pub fn output_location(&self) -> &OutputLocation<T> {
+        &self.out
     }
 
     fn writeln_message(&mut self, s: &str) -> io::Result<()> {
         assert!(!s.contains('\n'));
 
+        if self.pretty {
+            self.pending.push_str(s);
+            let event = format!("{}\n", PrettyJson(&self.pending));
+            self.pending.clear();
+            return self.out.write_all(event.as_bytes());
+        }
         self.out.write_all(s.as_ref())?;
         self.out.write_all(b"\n")
     }
@@ -27,31 +51,42 @@ fn writeln_message(&mut self, s: &str) -> io::Result<()> {
     fn write_message(&mut self, s: &str) -> io::Result<()> {
         assert!(!s.contains('\n'));
 
+        if self.pretty {
+            self.pending.push_str(s);
+            return Ok(());
+        }
         self.out.write_all(s.as_ref())
     }
 
     fn write_event(
         &mut self,
         ty: &str,
-        name: &str,
+        desc: &TestDesc,
         evt: &str,
         exec_time: Option<&time::TestExecTime>,
+        cpu_time: Option<&time::TestCpuTime>,
         stdout: Option<Cow<'_, str>>,
+        metrics: &MetricMap,
         extra: Option<&str>,
     ) -> io::Result<()> {
         // A doc test's name includes a filename which must be escaped for correct json.
         self.write_message(&*format!(
             r#"{{ "type": "{}", "name": "{}", "event": "{}""#,
             ty,
-            EscapedString(name),
+            EscapedString(desc.name.as_slice()),
             evt
         ))?;
+        self.write_message(&*format!("{}", JsonMetadata(desc.metadata())))?;
         if let Some(exec_time) = exec_time {
             self.write_message(&*format!(r#", "exec_time": {}"#, exec_time.0.as_secs_f64()))?;
         }
+        if let Some(cpu_time) = cpu_time {
+            self.write_message(&*format!(r#", "cpu_time": {}"#, cpu_time.0.as_secs_f64()))?;
+        }
         if let Some(stdout) = stdout {
             self.write_message(&*format!(r#", "stdout": "{}""#, EscapedString(stdout)))?;
         }
+        self.write_message(&*format!("{}", JsonMetrics(metrics)))?;
         if let Some(extra) = extra {
             self.write_message(&*format!(r#", {}"#, extra))?;
         }
@@ -69,8 +104,9 @@ fn write_run_start(&mut self, test_count: usize) -> io::Result<()> {
 
     fn write_test_start(&mut self, desc: &TestDesc) -> io::Result<()> {
         self.writeln_message(&*format!(
-            r#"{{ "type": "test", "event": "started", "name": "{}" }}"#,
-            EscapedString(desc.name.as_slice())
+            r#"{{ "type": "test", "event": "started", "name": "{}"{} }}"#,
+            EscapedString(desc.name.as_slice()),
+            JsonMetadata(desc.metadata())
         ))
     }
 
@@ -78,8 +114,11 @@ fn write_result(
         &mut self,
         desc: &TestDesc,
         result: &TestResult,
+        reason: Option<FailureReason>,
         exec_time: Option<&time::TestExecTime>,
+        cpu_time: Option<&time::TestCpuTime>,
         stdout: &[u8],
+        metrics: &MetricMap,
         state: &ConsoleTestState,
     ) -> io::Result<()> {
         let display_stdout = state.options.display_output || *result != TestResult::TrOk;
@@ -88,44 +127,86 @@ fn write_result(
         } else {
             None
         };
+        // The human-readable `reason` or `message`, if any, followed by the
+        // machine-readable `reason_code`.
+        let with_reason_code = |extra: Option<String>| {
+            let code = reason.map(|r| format!(r#""reason_code": "{}""#, r.as_str()));
+            match (extra, code) {
+                (Some(extra), Some(code)) => Some(format!("{}, {}", extra, code)),
+                (extra, code) => extra.or(code),
+            }
+        };
         match *result {
             TestResult::TrOk => {
-                self.write_event("test", desc.name.as_slice(), "ok", exec_time, stdout, None)
+                self.write_event("test", desc, "ok", exec_time, cpu_time, stdout, metrics, None)
             }
 
-            TestResult::TrFailed => {
-                self.write_event("test", desc.name.as_slice(), "failed", exec_time, stdout, None)
-            }
+            TestResult::TrFailed => self.write_event(
+                "test",
+                desc,
+                "failed",
+                exec_time,
+                cpu_time,
+                stdout,
+                metrics,
+                with_reason_code(None).as_deref(),
+            ),
 
             TestResult::TrTimedFail => self.write_event(
                 "test",
-                desc.name.as_slice(),
+                desc,
                 "failed",
                 exec_time,
+                cpu_time,
                 stdout,
-                Some(r#""reason": "time limit exceeded""#),
+                metrics,
+                with_reason_code(Some(r#""reason": "time limit exceeded""#.to_string()))
+                    .as_deref(),
             ),
 
             TestResult::TrFailedMsg(ref m) => self.write_event(
                 "test",
-                desc.name.as_slice(),
+                desc,
                 "failed",
                 exec_time,
+                cpu_time,
                 stdout,
-                Some(&*format!(r#""message": "{}""#, EscapedString(m))),
+                metrics,
+                with_reason_code(Some(format!(r#""message": "{}""#, EscapedString(m))))
+                    .as_deref(),
             ),
 
-            TestResult::TrIgnored => {
-                self.write_event("test", desc.name.as_slice(), "ignored", exec_time, stdout, None)
-            }
+            TestResult::TrIgnored => self.write_event(
+                "test",
+                desc,
+                "ignored",
+                exec_time,
+                cpu_time,
+                stdout,
+                metrics,
+                None,
+            ),
+
+            TestResult::TrIgnoredMsg(ref m) => self.write_event(
+                "test",
+                desc,
+                "ignored",
+                exec_time,
+                cpu_time,
+                stdout,
+                metrics,
+                Some(&*format!(r#""message": "{}""#, EscapedString(m))),
+            ),
 
             TestResult::TrAllowedFail => self.write_event(
                 "test",
-                desc.name.as_slice(),
+                desc,
                 "allowed_failure",
                 exec_time,
+                cpu_time,
                 stdout,
-                None,
+                metrics,
+                with_reason_code(None).as_deref(),
             ),
 
             TestResult::TrBench(ref bs) => {
@@ -142,11 +223,12 @@ fn write_result(
                     "{{ \"type\": \"bench\", \
                      \"name\": \"{}\", \
                      \"median\": {}, \
-                     \"deviation\": {}{} }}",
+                     \"deviation\": {}{}{} }}",
                     EscapedString(desc.name.as_slice()),
                     median,
                     deviation,
-                    mbps
+                    mbps,
+                    JsonMetadata(desc.metadata())
                 );
 
                 self.writeln_message(&*line)
@@ -156,8 +238,9 @@ fn write_result(
 
     fn write_timeout(&mut self, desc: &TestDesc) -> io::Result<()> {
         self.writeln_message(&*format!(
-            r#"{{ "type": "test", "event": "timeout", "name": "{}" }}"#,
-            EscapedString(desc.name.as_slice())
+            r#"{{ "type": "test", "event": "timeout", "name": "{}"{} }}"#,
+            EscapedString(desc.name.as_slice()),
+            JsonMetadata(desc.metadata())
         ))
     }
 
@@ -185,15 +268,146 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
             self.write_message(&time_str)?;
         }
 
+        if let Some(shuffle_seed) = state.shuffle_seed {
+            self.write_message(&*format!(", \"shuffle_seed\": {}", shuffle_seed))?;
+        }
+
         self.writeln_message(" }")?;
 
         Ok(state.failed == 0)
     }
 }
 
+/// Writes the line describing a test found by `--list --format json`.
+pub(crate) fn write_json_discovered<T: Write>(
+    out: &mut OutputLocation<T>,
+    desc: &TestDesc,
+    ty: &str,
+    pretty: bool,
+) -> io::Result<()> {
+    let event = format!(
+        r#"{{ "type": "{}", "event": "discovered", "name": "{}"{} }}"#,
+        ty,
+        EscapedString(desc.name.as_slice()),
+        JsonMetadata(desc.metadata())
+    );
+    if pretty { writeln!(out, "{}", PrettyJson(&event)) } else { writeln!(out, "{}", event) }
+}
+
+/// Re-indents a compact json document, putting every member and array element
+/// on its own line, indented by two spaces per level of nesting. Whitespace
+/// outside of strings is dropped, and empty objects and arrays stay on one line.
+struct PrettyJson<'a>(&'a str);
+
+impl std::fmt::Display for PrettyJson<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use std::fmt::Write as _;
+
+        fn newline(f: &mut std::fmt::Formatter<'_>, depth: usize) -> std::fmt::Result {
+            write!(f, "\n{:width$}", "", width = depth * 2)
+        }
+
+        let mut chars = self.0.chars().peekable();
+        let mut depth = 0;
+        let mut in_string = false;
+        let mut escaped = false;
+        while let Some(c) = chars.next() {
+            if in_string {
+                f.write_char(c)?;
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                } else if c == '"' {
+                    in_string = false;
+                }
+                continue;
+            }
+            match c {
+                '"' => {
+                    in_string = true;
+                    f.write_char(c)?;
+                }
+                '{' | '[' => {
+                    f.write_char(c)?;
+                    while chars.peek().map_or(false, |c| c.is_ascii_whitespace()) {
+                        chars.next();
+                    }
+                    if let Some(&close @ ('}' | ']')) = chars.peek() {
+                        chars.next();
+                        f.write_char(close)?;
+                    } else {
+                        depth += 1;
+                        newline(f, depth)?;
+                    }
+                }
+                '}' | ']' => {
+                    depth -= 1;
+                    newline(f, depth)?;
+                    f.write_char(c)?;
+                }
+                ',' => {
+                    f.write_char(c)?;
+                    newline(f, depth)?;
+                }
+                ':' => f.write_str(": ")?,
+                c if c.is_ascii_whitespace() => {}
+                c => f.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formats a test's metadata as a `, "metadata": { ... }` member, or as
+/// nothing at all if the test has none.
+struct JsonMetadata(&'static [(&'static str, &'static str)]);
+
+impl std::fmt::Display for JsonMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+        f.write_str(r#", "metadata": { "#)?;
+        for (i, (key, value)) in self.0.iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, r#""{}": "{}""#, EscapedString(key), EscapedString(value))?;
+        }
+        f.write_str(" }")
+    }
+}
+
+/// Formats the metrics reported by a test as a `"metrics"` field, or nothing
+/// if there aren't any.
+struct JsonMetrics<'a>(&'a MetricMap);
+
+impl std::fmt::Display for JsonMetrics<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+        f.write_str(r#", "metrics": { "#)?;
+        for (i, (name, metric)) in self.0.iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            write!(
+                f,
+                r#""{}": {{ "value": {}, "noise": {} }}"#,
+                EscapedString(name),
+                metric.value(),
+                metric.noise()
+            )?;
+        }
+        f.write_str(" }")
+    }
+}
+
 /// A formatting utility used to print strings with characters in need of escaping.
 /// Base code taken form `libserialize::json::escape_str`
-struct EscapedString<S: AsRef<str>>(S);
+pub(crate) struct EscapedString<S: AsRef<str>>(pub(crate) S);
 
 impl<S: AsRef<str>> std::fmt::Display for EscapedString<S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> ::std::fmt::Result {
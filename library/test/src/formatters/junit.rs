@@ -4,6 +4,7 @@
 use super::OutputFormatter;
 use crate::{
     console::{ConsoleTestState, OutputLocation},
+    helpers::metrics::MetricMap,
     test_result::TestResult,
     time,
     types::{TestDesc, TestType},
@@ -46,14 +47,17 @@ fn write_result(
         &mut self,
         desc: &TestDesc,
         result: &TestResult,
+        _reason: Option<FailureReason>,
         exec_time: Option<&time::TestExecTime>,
+        _cpu_time: Option<&time::TestCpuTime>,
         _stdout: &[u8],
+        _metrics: &MetricMap,
         _state: &ConsoleTestState,
     ) -> io::Result<()> {
         // Because the testsuit node holds some of the information as attributes, we can't write it
         // until all of the tests has ran. Instead of writting every result as they come in, we add
         // them to a Vec and write them all at once when run is complete.
-        let duration = exec_time.map(|t| t.0.clone()).unwrap_or_default();
+        let duration = exec_time.map(|t| t.0).unwrap_or_default();
         self.results.push((desc.clone(), result.clone(), duration));
         Ok(())
     }
@@ -72,7 +76,7 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
         for (desc, result, duration) in std::mem::replace(&mut self.results, Vec::new()) {
             let (class_name, test_name) = parse_class_name(&desc);
             match result {
-                TestResult::TrIgnored => { /* no-op */ }
+                TestResult::TrIgnored | TestResult::TrIgnoredMsg(_) => { /* no-op */ }
                 TestResult::TrFailed => {
                     self.write_message(&*format!(
                         "<testcase classname=\"{}\" \
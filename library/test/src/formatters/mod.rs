@@ -2,7 +2,8 @@
 
 use crate::{
     console::ConsoleTestState,
-    test_result::TestResult,
+    helpers::metrics::MetricMap,
+    test_result::{FailureReason, TestResult},
     time,
     types::{TestDesc, TestName},
 };
@@ -12,7 +13,7 @@
 mod pretty;
 mod terse;
 
-pub(crate) use self::json::JsonFormatter;
+pub(crate) use self::json::{write_json_discovered, EscapedString, JsonFormatter};
 pub(crate) use self::junit::JunitFormatter;
 pub(crate) use self::pretty::PrettyFormatter;
 pub(crate) use self::terse::TerseFormatter;
@@ -25,8 +26,11 @@ fn write_result(
         &mut self,
         desc: &TestDesc,
         result: &TestResult,
+        reason: Option<FailureReason>,
         exec_time: Option<&time::TestExecTime>,
+        cpu_time: Option<&time::TestCpuTime>,
         stdout: &[u8],
+        metrics: &MetricMap,
         state: &ConsoleTestState,
     ) -> io::Result<()>;
     fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool>;
@@ -40,3 +44,17 @@ pub(crate) fn write_stderr_delimiter(test_output: &mut Vec<u8>, test_name: &Test
     }
     writeln!(test_output, "---- {} stderr ----", test_name).unwrap();
 }
+
+/// Returns the hint printed after a failed run explaining how to reproduce the
+/// order the tests ran in, if they were shuffled.
+pub(crate) fn shuffle_seed_note(state: &ConsoleTestState) -> Option<String> {
+    let seed = state.shuffle_seed?;
+    if state.failed == 0 {
+        return None;
+    }
+    Some(format!(
+        "note: tests ran in a shuffled order; rerun with \
+         `-Z unstable-options --shuffle-seed {}` to reproduce it\n\n",
+        seed
+    ))
+}
@@ -1,11 +1,12 @@
 use std::{io, io::prelude::Write};
 
-use super::OutputFormatter;
+use super::{shuffle_seed_note, OutputFormatter};
 use crate::{
     bench::fmt_bench_samples,
     console::{ConsoleTestState, OutputLocation},
+    helpers::metrics::MetricMap,
     term,
-    test_result::TestResult,
+    test_result::{FailureReason, TestResult},
     time,
     types::TestDesc,
 };
@@ -98,9 +99,13 @@ fn write_time(
         &mut self,
         desc: &TestDesc,
         exec_time: Option<&time::TestExecTime>,
+        cpu_time: Option<&time::TestCpuTime>,
     ) -> io::Result<()> {
         if let (Some(opts), Some(time)) = (self.time_options, exec_time) {
-            let time_str = format!(" <{}>", time);
+            let time_str = match cpu_time {
+                Some(cpu_time) => format!(" <{}, {}>", time, cpu_time),
+                None => format!(" <{}>", time),
+            };
 
             let color = if opts.colored {
                 if opts.is_critical(desc, time) {
@@ -202,8 +207,11 @@ fn write_result(
         &mut self,
         desc: &TestDesc,
         result: &TestResult,
+        _: Option<FailureReason>,
         exec_time: Option<&time::TestExecTime>,
+        cpu_time: Option<&time::TestCpuTime>,
         _: &[u8],
+        _: &MetricMap,
         _: &ConsoleTestState,
     ) -> io::Result<()> {
         if self.is_multithreaded {
@@ -214,6 +222,10 @@ fn write_result(
             TestResult::TrOk => self.write_ok()?,
             TestResult::TrFailed | TestResult::TrFailedMsg(_) => self.write_failed()?,
             TestResult::TrIgnored => self.write_ignored()?,
+            TestResult::TrIgnoredMsg(ref msg) => {
+                self.write_ignored()?;
+                self.write_plain(&format!(", {}", msg))?;
+            }
             TestResult::TrAllowedFail => self.write_allowed_fail()?,
             TestResult::TrBench(ref bs) => {
                 self.write_bench()?;
@@ -222,7 +234,7 @@ fn write_result(
             TestResult::TrTimedFail => self.write_time_failed()?,
         }
 
-        self.write_time(desc, exec_time)?;
+        self.write_time(desc, exec_time, cpu_time)?;
         self.write_plain("\n")
     }
 
@@ -284,6 +296,10 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
 
         self.write_plain("\n\n")?;
 
+        if let Some(note) = shuffle_seed_note(state) {
+            self.write_plain(&note)?;
+        }
+
         Ok(success)
     }
 }
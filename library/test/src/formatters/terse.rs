@@ -1,11 +1,12 @@
 use std::{io, io::prelude::Write};
 
-use super::OutputFormatter;
+use super::{shuffle_seed_note, OutputFormatter};
 use crate::{
     bench::fmt_bench_samples,
     console::{ConsoleTestState, OutputLocation},
+    helpers::metrics::MetricMap,
     term,
-    test_result::TestResult,
+    test_result::{FailureReason, TestResult},
     time,
     types::NamePadding,
     types::TestDesc,
@@ -192,8 +193,11 @@ fn write_result(
         &mut self,
         desc: &TestDesc,
         result: &TestResult,
+        _: Option<FailureReason>,
         _: Option<&time::TestExecTime>,
+        _: Option<&time::TestCpuTime>,
         _: &[u8],
+        _: &MetricMap,
         _: &ConsoleTestState,
     ) -> io::Result<()> {
         match *result {
@@ -201,7 +205,7 @@ fn write_result(
             TestResult::TrFailed | TestResult::TrFailedMsg(_) | TestResult::TrTimedFail => {
                 self.write_failed()
             }
-            TestResult::TrIgnored => self.write_ignored(),
+            TestResult::TrIgnored | TestResult::TrIgnoredMsg(_) => self.write_ignored(),
             TestResult::TrAllowedFail => self.write_allowed_fail(),
             TestResult::TrBench(ref bs) => {
                 if self.is_multithreaded {
@@ -265,6 +269,10 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
 
         self.write_plain("\n\n")?;
 
+        if let Some(note) = shuffle_seed_note(state) {
+            self.write_plain(&note)?;
+        }
+
         Ok(success)
     }
 }
@@ -0,0 +1,175 @@
+//! Helper module to measure the CPU time (user + system) spent by the
+//! current thread or by a child process.
+
+use std::io::{self, Read};
+use std::process::{Child, ExitStatus, Output};
+use std::thread;
+use std::time::Duration;
+
+cfg_if::cfg_if! {
+    if #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "macos",
+        target_os = "ios",
+    ))] {
+        /// Returns the CPU time consumed by the calling thread so far.
+        pub fn thread_cpu_time() -> Option<Duration> {
+            let mut ts = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+            let r = unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut ts) };
+            if r != 0 {
+                return None;
+            }
+            Some(Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32))
+        }
+    } else if #[cfg(windows)] {
+        /// Returns the CPU time consumed by the calling thread so far.
+        pub fn thread_cpu_time() -> Option<Duration> {
+            type HANDLE = *mut u8;
+            extern "system" {
+                fn GetCurrentThread() -> HANDLE;
+            }
+            windows::handle_cpu_time(unsafe { GetCurrentThread() }, windows::GetThreadTimes)
+        }
+    } else {
+        pub fn thread_cpu_time() -> Option<Duration> {
+            None
+        }
+    }
+}
+
+/// Like `Child::wait_with_output`, but additionally returns the CPU time
+/// consumed by the child process, if it can be determined on this platform.
+pub fn wait_with_output(mut child: Child) -> io::Result<(Output, Option<Duration>)> {
+    // Drain stderr on a separate thread so that a child filling up one pipe
+    // while we block on the other one can't deadlock.
+    let stderr_reader = child.stderr.take().map(|mut stderr| {
+        thread::spawn(move || -> io::Result<Vec<u8>> {
+            let mut buf = Vec::new();
+            stderr.read_to_end(&mut buf)?;
+            Ok(buf)
+        })
+    });
+    let mut stdout = Vec::new();
+    if let Some(mut out) = child.stdout.take() {
+        out.read_to_end(&mut stdout)?;
+    }
+    let stderr = match stderr_reader {
+        Some(reader) => reader.join().unwrap()?,
+        None => Vec::new(),
+    };
+
+    let (status, cpu_time) = wait_with_cpu_time(child)?;
+    Ok((Output { status, stdout, stderr }, cpu_time))
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "macos",
+        target_os = "ios",
+    ))] {
+        /// Waits for `child` to exit and returns its exit status together with
+        /// the CPU time it consumed, as reported by `wait4`.
+        fn wait_with_cpu_time(child: Child) -> io::Result<(ExitStatus, Option<Duration>)> {
+            use std::os::unix::process::ExitStatusExt;
+
+            let pid = child.id() as libc::pid_t;
+            let mut status = 0;
+            let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+            loop {
+                let r = unsafe { libc::wait4(pid, &mut status, 0, &mut usage) };
+                if r != -1 {
+                    break;
+                }
+                let err = io::Error::last_os_error();
+                if err.kind() != io::ErrorKind::Interrupted {
+                    return Err(err);
+                }
+            }
+            // The child has been reaped above, so `Child` must not wait on it again.
+            drop(child);
+
+            let timeval = |tv: libc::timeval| {
+                Duration::new(tv.tv_sec as u64, tv.tv_usec as u32 * 1000)
+            };
+            let cpu_time = timeval(usage.ru_utime) + timeval(usage.ru_stime);
+            Ok((ExitStatus::from_raw(status), Some(cpu_time)))
+        }
+    } else if #[cfg(windows)] {
+        /// Waits for `child` to exit and returns its exit status together with
+        /// the CPU time it consumed, as reported by `GetProcessTimes`.
+        fn wait_with_cpu_time(mut child: Child) -> io::Result<(ExitStatus, Option<Duration>)> {
+            use std::os::windows::io::AsRawHandle;
+
+            let status = child.wait()?;
+            let handle = child.as_raw_handle() as *mut u8;
+            Ok((status, windows::handle_cpu_time(handle, windows::GetProcessTimes)))
+        }
+    } else {
+        fn wait_with_cpu_time(mut child: Child) -> io::Result<(ExitStatus, Option<Duration>)> {
+            Ok((child.wait()?, None))
+        }
+    }
+}
+
+#[cfg(windows)]
+mod windows {
+    use std::time::Duration;
+
+    type BOOL = i32;
+    type HANDLE = *mut u8;
+
+    #[repr(C)]
+    #[derive(Default)]
+    pub struct FILETIME {
+        low: u32,
+        high: u32,
+    }
+
+    type TimesFn = unsafe extern "system" fn(
+        HANDLE,
+        *mut FILETIME,
+        *mut FILETIME,
+        *mut FILETIME,
+        *mut FILETIME,
+    ) -> BOOL;
+
+    extern "system" {
+        pub fn GetThreadTimes(
+            handle: HANDLE,
+            creation: *mut FILETIME,
+            exit: *mut FILETIME,
+            kernel: *mut FILETIME,
+            user: *mut FILETIME,
+        ) -> BOOL;
+        pub fn GetProcessTimes(
+            handle: HANDLE,
+            creation: *mut FILETIME,
+            exit: *mut FILETIME,
+            kernel: *mut FILETIME,
+            user: *mut FILETIME,
+        ) -> BOOL;
+    }
+
+    /// Sums up the kernel and user time reported by `times` for `handle`.
+    pub fn handle_cpu_time(handle: HANDLE, times: TimesFn) -> Option<Duration> {
+        let mut creation = FILETIME::default();
+        let mut exit = FILETIME::default();
+        let mut kernel = FILETIME::default();
+        let mut user = FILETIME::default();
+        let r = unsafe { times(handle, &mut creation, &mut exit, &mut kernel, &mut user) };
+        if r == 0 {
+            return None;
+        }
+        // `FILETIME`s count in units of 100 nanoseconds.
+        let ticks = |t: FILETIME| ((t.high as u64) << 32) | t.low as u64;
+        Some(Duration::from_nanos((ticks(kernel) + ticks(user)) * 100))
+    }
+}
@@ -0,0 +1,45 @@
+//! Glob matching of test source file paths, for `--filter-file`.
+
+/// Checks whether `path` matches the glob `pattern`.
+///
+/// `?` matches any single character and `*` any run of characters, both
+/// within a single path component. `**` also matches across components, so
+/// `src/**/*.rs` matches `src/lib.rs` as well as `src/net/tcp/mod.rs`.
+/// Every other character matches itself. `\` is treated as a path separator
+/// in both arguments, so patterns written with `/` work on Windows paths too.
+pub fn glob_matches(pattern: &str, path: &str) -> bool {
+    let normalize =
+        |s: &str| -> Vec<char> { s.chars().map(|c| if c == '\\' { '/' } else { c }).collect() };
+    matches(&normalize(pattern), &normalize(path))
+}
+
+fn matches(pattern: &[char], path: &[char]) -> bool {
+    match pattern {
+        [] => path.is_empty(),
+        // `**/` matches zero or more whole directories.
+        ['*', '*', '/', rest @ ..] => {
+            matches(rest, path)
+                || (0..path.len()).any(|i| path[i] == '/' && matches(rest, &path[i + 1..]))
+        }
+        ['*', '*', rest @ ..] => (0..=path.len()).any(|i| matches(rest, &path[i..])),
+        ['*', rest @ ..] => {
+            for i in 0..=path.len() {
+                if matches(rest, &path[i..]) {
+                    return true;
+                }
+                if path.get(i) == Some(&'/') {
+                    break;
+                }
+            }
+            false
+        }
+        ['?', rest @ ..] => match path {
+            [c, path @ ..] => *c != '/' && matches(rest, path),
+            [] => false,
+        },
+        [p, rest @ ..] => match path {
+            [c, path @ ..] => c == p && matches(rest, path),
+            [] => false,
+        },
+    }
+}
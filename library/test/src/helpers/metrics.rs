@@ -1,5 +1,10 @@
 //! Benchmark metrics.
 use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, prelude::Write};
+use std::path::Path;
+
+use crate::formatters::EscapedString;
 
 #[derive(Clone, PartialEq, Debug, Copy)]
 pub struct Metric {
@@ -11,9 +16,17 @@ impl Metric {
     pub fn new(value: f64, noise: f64) -> Metric {
         Metric { value, noise }
     }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn noise(&self) -> f64 {
+        self.noise
+    }
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Debug)]
 pub struct MetricMap(BTreeMap<String, Metric>);
 
 impl MetricMap {
@@ -39,6 +52,51 @@ pub fn insert_metric(&mut self, name: &str, value: f64, noise: f64) {
         self.0.insert(name.to_owned(), m);
     }
 
+    /// Returns the value of the metric `name` relative to its value in
+    /// `baseline`, i.e. `1.0` if it didn't change and `2.0` if it doubled.
+    /// Returns `None` if the metric is missing from either map, or if its
+    /// baseline value is zero.
+    pub fn ratio_of(&self, name: &str, baseline: &MetricMap) -> Option<f64> {
+        let current = self.0.get(name)?;
+        let old = baseline.0.get(name)?;
+        if old.value == 0.0 { None } else { Some(current.value / old.value) }
+    }
+
+    /// Iterates over the metrics in the order of their names.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Metric)> {
+        self.0.iter().map(|(name, metric)| (&**name, metric))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Writes the metrics to `p` as a JSON object that maps the name of
+    /// every metric to its value and noise. `load` reads it back.
+    pub fn save(&self, p: &Path) -> io::Result<()> {
+        let mut file = File::create(p)?;
+        writeln!(file, "{{")?;
+        for (i, (name, metric)) in self.0.iter().enumerate() {
+            writeln!(
+                file,
+                r#"  "{}": {{ "value": {}, "noise": {} }}{}"#,
+                EscapedString(name),
+                metric.value,
+                metric.noise,
+                if i + 1 == self.0.len() { "" } else { "," }
+            )?;
+        }
+        writeln!(file, "}}")
+    }
+
+    /// Reads metrics saved by `save`.
+    pub fn load(p: &Path) -> io::Result<MetricMap> {
+        let contents = fs::read_to_string(p)?;
+        parse_metrics(&contents).map_err(|e| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", p.display(), e))
+        })
+    }
+
     pub fn fmt_metrics(&self) -> String {
         let v = self
             .0
@@ -48,3 +106,112 @@ pub fn fmt_metrics(&self) -> String {
         v.join(", ")
     }
 }
+
+// Parses the output of `MetricMap::save`. This only understands the subset of
+// JSON that `save` produces, which is all that libtest ever needs to read.
+fn parse_metrics(s: &str) -> Result<MetricMap, String> {
+    let mut p = JsonParser { rest: s };
+    let mut map = MetricMap::new();
+
+    p.expect('{')?;
+    if !p.eat('}') {
+        loop {
+            let name = p.string()?;
+            p.expect(':')?;
+            p.expect('{')?;
+            let (mut value, mut noise) = (None, None);
+            loop {
+                let key = p.string()?;
+                p.expect(':')?;
+                let number = p.number()?;
+                match &*key {
+                    "value" => value = Some(number),
+                    "noise" => noise = Some(number),
+                    _ => return Err(format!("unknown field `{}` in metric `{}`", key, name)),
+                }
+                if !p.eat(',') {
+                    break;
+                }
+            }
+            p.expect('}')?;
+            match (value, noise) {
+                (Some(value), Some(noise)) => map.insert_metric(&name, value, noise),
+                _ => return Err(format!("metric `{}` needs both a value and a noise", name)),
+            }
+            if !p.eat(',') {
+                break;
+            }
+        }
+        p.expect('}')?;
+    }
+
+    if !p.rest.trim().is_empty() {
+        return Err("trailing characters after the metrics".into());
+    }
+    Ok(map)
+}
+
+struct JsonParser<'a> {
+    rest: &'a str,
+}
+
+impl JsonParser<'_> {
+    fn eat(&mut self, c: char) -> bool {
+        self.rest = self.rest.trim_start();
+        match self.rest.strip_prefix(c) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        if self.eat(c) { Ok(()) } else { Err(format!("expected `{}`", c)) }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut out = String::new();
+        let mut chars = self.rest.char_indices();
+        while let Some((i, c)) = chars.next() {
+            let c = match c {
+                '"' => {
+                    self.rest = &self.rest[i + 1..];
+                    return Ok(out);
+                }
+                '\\' => match chars.next().map(|(_, c)| c) {
+                    Some(c @ ('"' | '\\' | '/')) => c,
+                    Some('b') => '\x08',
+                    Some('f') => '\x0c',
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('t') => '\t',
+                    Some('u') => {
+                        let hex: String = chars.by_ref().take(4).map(|(_, c)| c).collect();
+                        u32::from_str_radix(&hex, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| format!("invalid escape `\\u{}`", hex))?
+                    }
+                    _ => return Err("invalid escape in string".into()),
+                },
+                c => c,
+            };
+            out.push(c);
+        }
+        Err("unterminated string".into())
+    }
+
+    fn number(&mut self) -> Result<f64, String> {
+        self.rest = self.rest.trim_start();
+        let len = self
+            .rest
+            .find(|c: char| !matches!(c, '0'..='9' | '-' | '+' | '.' | 'e' | 'E'))
+            .unwrap_or(self.rest.len());
+        let (number, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        number.parse().map_err(|_| format!("invalid number `{}`", number))
+    }
+}
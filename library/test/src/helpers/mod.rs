@@ -2,6 +2,10 @@
 //! but used in `libtest`.
 
 pub mod concurrency;
+pub mod cpu_time;
 pub mod exit_code;
+pub mod glob;
 pub mod isatty;
 pub mod metrics;
+pub mod shuffle;
+pub mod threads;
@@ -0,0 +1,22 @@
+//! Helper module which lists the threads that are alive in the current
+//! process, used by `--detect-leaked-threads`.
+
+use std::collections::HashSet;
+
+cfg_if::cfg_if! {
+    if #[cfg(any(target_os = "linux", target_os = "android"))] {
+        /// Returns the ids of the threads of this process that are alive, or
+        /// `None` if they can't be listed.
+        pub fn live_threads() -> Option<HashSet<u64>> {
+            let tasks = std::fs::read_dir("/proc/self/task").ok()?;
+            tasks
+                .map(|task| task.ok()?.file_name().to_str()?.parse().ok())
+                .collect()
+        }
+    } else {
+        // FIXME: Use the platform's thread enumeration APIs elsewhere.
+        pub fn live_threads() -> Option<HashSet<u64>> {
+            None
+        }
+    }
+}
@@ -23,6 +23,7 @@
 #![feature(libc)]
 #![feature(rustc_private)]
 #![feature(nll)]
+#![feature(once_cell)]
 #![feature(available_concurrency)]
 #![feature(bench_black_box)]
 #![feature(internal_output_capture)]
@@ -35,7 +36,10 @@
 // Public reexports
 pub use self::bench::{black_box, Bencher};
 pub use self::console::run_tests_console;
-pub use self::options::{ColorConfig, Options, OutputFormat, RunIgnored, ShouldPanic};
+pub use self::options::{
+    ColorConfig, LogCapture, LogSink, Options, OutputFormat, RunIgnored, ShouldPanic,
+    TestTypeFilter,
+};
 pub use self::types::TestName::*;
 pub use self::types::*;
 pub use self::ColorConfig::*;
@@ -49,10 +53,14 @@ pub mod test {
         cli::{parse_opts, TestOpts},
         filter_tests,
         helpers::metrics::{Metric, MetricMap},
-        options::{Concurrent, Options, RunIgnored, RunStrategy, ShouldPanic},
-        run_test, test_main, test_main_static,
-        test_result::{TestResult, TrFailed, TrFailedMsg, TrIgnored, TrOk},
-        time::{TestExecTime, TestTimeOptions},
+        options::{
+            Concurrent, LogCapture, LogSink, Options, RunIgnored, RunStrategy, ShouldPanic,
+        },
+        before_all, report_metric, run_test, skip_test, test_main, test_main_static,
+        test_result::{
+            FailureReason, TestResult, TrFailed, TrFailedMsg, TrIgnored, TrIgnoredMsg, TrOk,
+        },
+        time::{TestCpuTime, TestExecTime, TestTimeOptions},
         types::{
             DynTestFn, DynTestName, StaticBenchFn, StaticTestFn, StaticTestName, TestDesc,
             TestDescAndFn, TestId, TestName, TestType,
@@ -61,13 +69,16 @@ pub mod test {
 }
 
 use std::{
+    cell::RefCell,
     collections::VecDeque,
-    env, io,
+    env, fmt, io,
     io::prelude::Write,
+    lazy::SyncLazy,
+    mem,
     panic::{self, catch_unwind, AssertUnwindSafe, PanicInfo},
     process::{self, Command, Termination},
     sync::mpsc::{channel, Sender},
-    sync::{Arc, Mutex},
+    sync::{Arc, Mutex, PoisonError},
     thread,
     time::{Duration, Instant},
 };
@@ -90,20 +101,38 @@ pub mod test {
 
 use event::{CompletedTest, TestEvent};
 use helpers::concurrency::get_concurrency;
+use helpers::cpu_time::{self, thread_cpu_time};
 use helpers::exit_code::get_exit_code;
-use options::{Concurrent, RunStrategy};
+use helpers::glob::glob_matches;
+use helpers::metrics::MetricMap;
+use helpers::shuffle::{get_shuffle_seed, shuffle_tests};
+use helpers::threads::live_threads;
+use options::{Concurrent, LogCapture, RunStrategy, TestTypeFilter};
 use test_result::*;
-use time::TestExecTime;
+use time::{TestCpuTime, TestExecTime};
 
 // Process exit code to be used to indicate test failures.
 const ERROR_EXIT_CODE: i32 = 101;
 
 const SECONDARY_TEST_INVOKER_VAR: &str = "__RUST_TEST_INVOKE";
 
+/// Set for a secondary test process when `--capture-combined` was passed.
+const SECONDARY_TEST_CAPTURE_COMBINED_VAR: &str = "__RUST_TEST_CAPTURE_COMBINED";
+
+/// Global setup hooks registered through `before_all` that haven't run yet.
+static BEFORE_ALL_HOOKS: SyncLazy<Mutex<Vec<fn()>>> = SyncLazy::new(|| Mutex::new(Vec::new()));
+
+thread_local! {
+    /// Captured output of the test running on this thread, while a `LogCapture` is installed.
+    static LOG_CAPTURE_BUFFER: RefCell<Option<Arc<Mutex<Vec<u8>>>>> = RefCell::new(None);
+    /// Metrics reported through `report_metric` by the test running on this thread.
+    static TEST_METRICS: RefCell<MetricMap> = RefCell::new(MetricMap::new());
+}
+
 // The default console test runner. It accepts the command line
 // arguments and a vector of test_descs.
 pub fn test_main(args: &[String], tests: Vec<TestDescAndFn>, options: Option<Options>) {
-    let mut opts = match cli::parse_opts(args) {
+    let opts = match parse_opts_with_options(args, options) {
         Some(Ok(o)) => o,
         Some(Err(msg)) => {
             eprintln!("error: {}", msg);
@@ -111,8 +140,9 @@ pub fn test_main(args: &[String], tests: Vec<TestDescAndFn>, options: Option<Opt
         }
         None => return,
     };
-    if let Some(options) = options {
-        opts.options = options;
+    if opts.repeat > 1 && !tests.iter().all(|test| is_static_test(&test.testfn)) {
+        eprintln!("error: --repeat is only supported for statically defined tests");
+        process::exit(ERROR_EXIT_CODE);
     }
     if opts.list {
         if let Err(e) = console::list_tests_console(&opts, tests) {
@@ -120,9 +150,9 @@ pub fn test_main(args: &[String], tests: Vec<TestDescAndFn>, options: Option<Opt
             process::exit(ERROR_EXIT_CODE);
         }
     } else {
-        match console::run_tests_console(&opts, tests) {
+        match run_tests_console_repeatedly(&opts, tests) {
             Ok(true) => {}
-            Ok(false) => process::exit(ERROR_EXIT_CODE),
+            Ok(false) => process::exit(failure_exit_code(&opts)),
             Err(e) => {
                 eprintln!("error: io error when listing tests: {:?}", e);
                 process::exit(ERROR_EXIT_CODE);
@@ -131,6 +161,84 @@ pub fn test_main(args: &[String], tests: Vec<TestDescAndFn>, options: Option<Opt
     }
 }
 
+/// Parses the command line for `test_main`, merging in the `Options` of the
+/// caller, if any. The caller's values win where it sets them, but everything
+/// else the command line asked for is kept, since a runner like the one for
+/// `panic=abort` passes its own `Options` just to set `panic_abort`.
+fn parse_opts_with_options(args: &[String], options: Option<Options>) -> Option<cli::OptRes> {
+    let mut opts = match cli::parse_opts(args)? {
+        Ok(o) => o,
+        Err(msg) => return Some(Err(msg)),
+    };
+    if let Some(options) = options {
+        let cli = opts.options;
+        opts.options = options
+            .display_output(options.display_output || cli.display_output)
+            .discard_passing_output(options.discard_passing_output || cli.discard_passing_output)
+            .json_pretty(options.json_pretty || cli.json_pretty)
+            .bench_time(options.bench_time.or(cli.bench_time))
+            .bench_samples(options.bench_samples.or(cli.bench_samples))
+            .failure_exit_code(options.failure_exit_code.or(cli.failure_exit_code));
+    }
+    Some(Ok(opts))
+}
+
+/// Implements `--repeat`: runs the whole suite `opts.repeat` times in a row
+/// and, if there was more than one run, reports the combined results at the
+/// end. Returns `false` if any of the runs failed.
+///
+/// Since every run needs its own copy of the tests, this only supports static
+/// tests when repeating.
+fn run_tests_console_repeatedly(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> io::Result<bool> {
+    if opts.repeat <= 1 {
+        return console::run_tests_console(opts, tests);
+    }
+
+    let (mut passed, mut failed, mut ignored) = (0, 0, 0);
+    let mut failed_runs = 0;
+    let mut first_failed_run = None;
+    for run in 1..=opts.repeat {
+        let tests = tests.iter().map(|test| make_owned_test(&test)).collect();
+        let (success, st) = console::run_tests_console_with_state(opts, tests)?;
+        passed += st.passed;
+        failed += st.failed;
+        ignored += st.ignored;
+        if !success {
+            failed_runs += 1;
+            first_failed_run.get_or_insert(run);
+        }
+    }
+
+    // The summary goes to stderr so that it doesn't interfere with
+    // machine-readable output formats.
+    let mut out = io::stderr();
+    writeln!(
+        out,
+        "\nrepeated the test suite {} times: {} runs passed, {} failed; \
+         {} tests passed, {} failed, {} ignored in total",
+        opts.repeat,
+        opts.repeat - failed_runs,
+        failed_runs,
+        passed,
+        failed,
+        ignored
+    )?;
+    if let Some(run) = first_failed_run {
+        writeln!(out, "run {} was the first to fail", run)?;
+    }
+
+    Ok(failed_runs == 0)
+}
+
+fn is_static_test(testfn: &TestFn) -> bool {
+    matches!(testfn, StaticTestFn(_) | StaticBenchFn(_))
+}
+
+/// The exit code of the process when some test failed.
+fn failure_exit_code(opts: &TestOpts) -> i32 {
+    opts.options.failure_exit_code.unwrap_or(ERROR_EXIT_CODE)
+}
+
 /// A variant optimized for invocation with a static test vector.
 /// This will panic (intentionally) when fed any dynamic tests.
 ///
@@ -155,6 +263,8 @@ pub fn test_main_static_abort(tests: &[&TestDescAndFn]) {
     // will then exit the process.
     if let Ok(name) = env::var(SECONDARY_TEST_INVOKER_VAR) {
         env::remove_var(SECONDARY_TEST_INVOKER_VAR);
+        let capture_combined = env::var_os(SECONDARY_TEST_CAPTURE_COMBINED_VAR).is_some();
+        env::remove_var(SECONDARY_TEST_CAPTURE_COMBINED_VAR);
         let test = tests
             .iter()
             .filter(|test| test.desc.name.as_slice() == name)
@@ -166,7 +276,7 @@ pub fn test_main_static_abort(tests: &[&TestDescAndFn]) {
             StaticTestFn(f) => f,
             _ => panic!("only static tests are supported"),
         };
-        run_test_in_spawned_subprocess(desc, Box::new(testfn));
+        run_test_in_spawned_subprocess(desc, Box::new(testfn), capture_combined);
     }
 
     let args = env::args().collect::<Vec<_>>();
@@ -199,6 +309,66 @@ pub fn assert_test_result<T: Termination>(result: T) {
     );
 }
 
+/// Stops the currently running test and reports it as ignored with the given
+/// reason.
+///
+/// This is meant for tests that can only decide at runtime that they are not
+/// applicable, e.g. because a required tool is missing from the environment.
+/// It works by panicking with a payload that the test runner recognizes, so it
+/// must be called from the thread running the test.
+pub fn skip_test(reason: &str) -> ! {
+    panic::panic_any(SkippedTest { reason: reason.to_string() })
+}
+
+/// Records a named measurement for the currently running test.
+///
+/// This lets regular tests, not just benchmarks, report values such as sizes
+/// or counts. The metrics are attached to the test's result and show up in
+/// the JSON output. `value` and `noise` have the same meaning as for
+/// [`MetricMap::insert_metric`]; reporting a metric with the same name again
+/// replaces the earlier value.
+///
+/// Like [`skip_test`], this must be called from the thread running the test.
+/// Metrics reported by tests that are run in a separate process, as is the
+/// case with `panic=abort`, are not collected.
+pub fn report_metric(name: &str, value: f64, noise: f64) {
+    TEST_METRICS.with(|metrics| metrics.borrow_mut().insert_metric(name, value, noise));
+}
+
+/// Registers `f` to be run once before any test is scheduled.
+///
+/// This is meant for one-time global initialization, e.g. setting up logging
+/// or the environment. Registered functions run in the order they were
+/// registered, on the thread calling `run_tests`, the next time tests are run.
+/// Each function runs only once, even if tests are run several times. If one
+/// of them panics, the test run is aborted. Tests run in a subprocess, as is
+/// the case with `panic=abort`, run the functions registered in that process
+/// before the test.
+pub fn before_all(f: fn()) {
+    BEFORE_ALL_HOOKS.lock().unwrap_or_else(PoisonError::into_inner).push(f);
+}
+
+/// Runs and unregisters all hooks registered with `before_all`.
+fn run_before_all_hooks() {
+    // The hooks are taken out before they run, so that a hook can register
+    // more of them without deadlocking. Those run right after it.
+    loop {
+        let hooks = {
+            let mut hooks = BEFORE_ALL_HOOKS.lock().unwrap_or_else(PoisonError::into_inner);
+            mem::take(&mut *hooks)
+        };
+        if hooks.is_empty() {
+            break;
+        }
+        for hook in hooks {
+            if catch_unwind(hook).is_err() {
+                eprintln!("error: a `before_all` hook panicked, aborting the test run");
+                process::exit(ERROR_EXIT_CODE);
+            }
+        }
+    }
+}
+
 pub fn run_tests<F>(
     opts: &TestOpts,
     tests: Vec<TestDescAndFn>,
@@ -213,6 +383,7 @@ pub fn run_tests<F>(
 
     struct RunningTest {
         join_handle: Option<thread::JoinHandle<()>>,
+        resource: Option<&'static str>,
     }
 
     // Use a deterministic hasher
@@ -247,7 +418,9 @@ struct TimeoutEntry {
 
     let filtered_descs = filtered_tests.iter().map(|t| t.desc.clone()).collect();
 
-    let event = TestEvent::TeFiltered(filtered_descs);
+    let shuffle_seed = get_shuffle_seed(opts);
+
+    let event = TestEvent::TeFiltered(filtered_descs, shuffle_seed);
     notify_about_test_event(event)?;
 
     let (filtered_tests, filtered_benchs): (Vec<_>, _) = filtered_tests
@@ -259,9 +432,19 @@ struct TimeoutEntry {
     let concurrency = opts.test_threads.unwrap_or_else(get_concurrency);
 
     let mut remaining = filtered_tests;
-    remaining.reverse();
+    if let Some(shuffle_seed) = shuffle_seed {
+        shuffle_tests(shuffle_seed, &mut remaining);
+    } else {
+        remaining.reverse();
+    }
     let mut pending = 0;
 
+    run_before_all_hooks();
+
+    // Threads that are already running, like the ones the `before_all` hooks
+    // started, are not leaked by the tests.
+    let threads_before_run = if opts.detect_leaked_threads { live_threads() } else { None };
+
     let (tx, rx) = channel::<CompletedTest>();
     let run_strategy = if opts.options.panic_abort && !opts.force_run_in_process {
         RunStrategy::SpawnPrimary
@@ -272,6 +455,13 @@ struct TimeoutEntry {
     let mut running_tests: TestMap = HashMap::default();
     let mut timeout_queue: VecDeque<TimeoutEntry> = VecDeque::new();
 
+    // Number of running tests for each resource group, see `TestDesc::resource`.
+    let mut running_per_resource: HashMap<&'static str, usize> = HashMap::new();
+    let resource_limit = |resource: &str| {
+        let limit = opts.resource_limits.iter().find(|(group, _)| group == resource);
+        limit.map_or(1, |&(_, n)| n.max(1))
+    };
+
     fn get_timed_out_tests(
         running_tests: &TestMap,
         timeout_queue: &mut VecDeque<TimeoutEntry>,
@@ -297,23 +487,39 @@ fn calc_timeout(timeout_queue: &VecDeque<TimeoutEntry>) -> Option<Duration> {
         })
     }
 
-    if concurrency == 1 {
+    // With `--test-threads 0` or `1` every test runs in-line on this thread and
+    // its result is handled right away, without going through `rx`, so that the
+    // test shows up directly below `run_tests` in a debugger.
+    if concurrency <= 1 {
         while !remaining.is_empty() {
             let (id, test) = remaining.pop().unwrap();
             let event = TestEvent::TeWait(test.desc.clone());
             notify_about_test_event(event)?;
-            let join_handle =
-                run_test(opts, !opts.run_tests, id, test, run_strategy, tx.clone(), Concurrent::No);
-            assert!(join_handle.is_none());
-            let completed_test = rx.recv().unwrap();
+            let completed_test = run_test_serially(opts, !opts.run_tests, id, test, run_strategy);
 
             let event = TestEvent::TeResult(completed_test);
             notify_about_test_event(event)?;
         }
     } else {
         while pending > 0 || !remaining.is_empty() {
-            while pending < concurrency && !remaining.is_empty() {
-                let (id, test) = remaining.pop().unwrap();
+            while pending < concurrency {
+                // Take the next test whose resource group isn't busy. Tests of a
+                // busy group stay in place, so they keep their relative order.
+                let next = remaining.iter().rposition(|(_, test)| match test.desc.resource() {
+                    Some(resource) => {
+                        running_per_resource.get(resource).copied().unwrap_or(0)
+                            < resource_limit(resource)
+                    }
+                    None => true,
+                });
+                let (id, test) = match next {
+                    Some(next) => remaining.remove(next),
+                    None => break,
+                };
+                let resource = test.desc.resource();
+                if let Some(resource) = resource {
+                    *running_per_resource.entry(resource).or_insert(0) += 1;
+                }
                 let timeout = time::get_default_test_timeout();
                 let desc = test.desc.clone();
 
@@ -328,7 +534,7 @@ fn calc_timeout(timeout_queue: &VecDeque<TimeoutEntry>) -> Option<Duration> {
                     tx.clone(),
                     Concurrent::Yes,
                 );
-                running_tests.insert(id, RunningTest { join_handle });
+                running_tests.insert(id, RunningTest { join_handle, resource });
                 timeout_queue.push_back(TimeoutEntry { id, desc, timeout });
                 pending += 1;
             }
@@ -359,6 +565,9 @@ fn calc_timeout(timeout_queue: &VecDeque<TimeoutEntry>) -> Option<Duration> {
 
             let mut completed_test = res.unwrap();
             let running_test = running_tests.remove(&completed_test.id).unwrap();
+            if let Some(resource) = running_test.resource {
+                *running_per_resource.get_mut(resource).unwrap() -= 1;
+            }
             if let Some(join_handle) = running_test.join_handle {
                 if let Err(_) = join_handle.join() {
                     if let TrOk = completed_test.result {
@@ -386,6 +595,15 @@ fn calc_timeout(timeout_queue: &VecDeque<TimeoutEntry>) -> Option<Duration> {
             notify_about_test_event(event)?;
         }
     }
+
+    // Every test thread has been joined by now, so any thread that did not
+    // exist before the run was spawned by a test and outlived it.
+    if let Some(threads_before_run) = threads_before_run {
+        if let Some(threads_after_run) = live_threads() {
+            let leaked = threads_after_run.difference(&threads_before_run).count();
+            notify_about_test_event(TestEvent::TeLeakedThreads(leaked))?;
+        }
+    }
     Ok(())
 }
 
@@ -401,18 +619,41 @@ pub fn filter_tests(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> Vec<TestDescA
     };
 
     // Remove tests that don't match the test filter
-    if !opts.filters.is_empty() {
-        filtered.retain(|test| opts.filters.iter().any(|filter| matches_filter(test, filter)));
+    if !opts.filters.is_empty() || !opts.exact_filters.is_empty() {
+        filtered.retain(|test| {
+            opts.filters.iter().any(|filter| matches_filter(test, filter))
+                || opts.exact_filters.iter().any(|name| test.desc.name.as_slice() == name)
+        });
     }
 
     // Skip tests that match any of the skip filters
     filtered.retain(|test| !opts.skip.iter().any(|sf| matches_filter(test, sf)));
 
+    // Keep only the tests defined in a file matching `--filter-file`
+    if !opts.filter_files.is_empty() {
+        filtered.retain(|test| {
+            test.desc.source_file().map_or(false, |file| {
+                opts.filter_files.iter().any(|pattern| glob_matches(pattern, file))
+            })
+        });
+    }
+
     // Excludes #[should_panic] tests
     if opts.exclude_should_panic {
         filtered.retain(|test| test.desc.should_panic == ShouldPanic::No);
     }
 
+    // Keep only the tests of the types passed to `--test-type`
+    if !opts.test_types.is_empty() {
+        let matches_type = |test: &TestDescAndFn, filter: &TestTypeFilter| match filter {
+            TestTypeFilter::Unit => test.desc.test_type == TestType::UnitTest,
+            TestTypeFilter::Integration => test.desc.test_type == TestType::IntegrationTest,
+            TestTypeFilter::Doc => test.desc.test_type == TestType::DocTest,
+            TestTypeFilter::Bench => matches!(test.testfn, StaticBenchFn(..) | DynBenchFn(..)),
+        };
+        filtered.retain(|test| opts.test_types.iter().any(|filter| matches_type(test, filter)));
+    }
+
     // maybe unignore tests
     match opts.run_ignored {
         RunIgnored::Yes => {
@@ -461,24 +702,12 @@ pub fn run_test(
 ) -> Option<thread::JoinHandle<()>> {
     let TestDescAndFn { desc, testfn } = test;
 
-    // Emscripten can catch panics but other wasm targets cannot
-    let ignore_because_no_process_support = desc.should_panic != ShouldPanic::No
-        && cfg!(target_arch = "wasm32")
-        && !cfg!(target_os = "emscripten");
-
-    if force_ignore || desc.ignore || ignore_because_no_process_support {
-        let message = CompletedTest::new(id, desc, TrIgnored, None, Vec::new());
+    if is_ignored(&desc, force_ignore) {
+        let message = CompletedTest::new(id, desc, TrIgnored, None, None, Vec::new());
         monitor_ch.send(message).unwrap();
         return None;
     }
 
-    struct TestRunOpts {
-        pub strategy: RunStrategy,
-        pub nocapture: bool,
-        pub concurrency: Concurrent,
-        pub time: Option<time::TestTimeOptions>,
-    }
-
     fn run_test_inner(
         id: TestId,
         desc: TestDesc,
@@ -489,25 +718,7 @@ fn run_test_inner(
         let concurrency = opts.concurrency;
         let name = desc.name.clone();
 
-        let runtest = move || match opts.strategy {
-            RunStrategy::InProcess => run_test_in_process(
-                id,
-                desc,
-                opts.nocapture,
-                opts.time.is_some(),
-                testfn,
-                monitor_ch,
-                opts.time,
-            ),
-            RunStrategy::SpawnPrimary => spawn_test_subprocess(
-                id,
-                desc,
-                opts.nocapture,
-                opts.time.is_some(),
-                monitor_ch,
-                opts.time,
-            ),
-        };
+        let runtest = move || monitor_ch.send(run_unit_test(id, desc, testfn, opts)).unwrap();
 
         // If the platform is single-threaded we're just going to run
         // the test synchronously, regardless of the concurrency
@@ -533,20 +744,19 @@ fn run_test_inner(
         }
     }
 
-    let test_run_opts =
-        TestRunOpts { strategy, nocapture: opts.nocapture, concurrency, time: opts.time_options };
+    let test_run_opts = TestRunOpts::new(opts, strategy, concurrency);
 
     match testfn {
         DynBenchFn(bencher) => {
             // Benchmarks aren't expected to panic, so we run them all in-process.
-            crate::bench::benchmark(id, desc, monitor_ch, opts.nocapture, |harness| {
+            crate::bench::benchmark(id, desc, monitor_ch, opts.nocapture, opts.options, |harness| {
                 bencher.run(harness)
             });
             None
         }
         StaticBenchFn(benchfn) => {
             // Benchmarks aren't expected to panic, so we run them all in-process.
-            crate::bench::benchmark(id, desc, monitor_ch, opts.nocapture, benchfn);
+            crate::bench::benchmark(id, desc, monitor_ch, opts.nocapture, opts.options, benchfn);
             None
         }
         DynTestFn(f) => {
@@ -572,6 +782,99 @@ fn run_test_inner(
     }
 }
 
+/// Runs the unit test `test` on the current thread and returns its result.
+/// This is `run_test` for the serial path of `run_tests`, which doesn't need
+/// the channel.
+fn run_test_serially(
+    opts: &TestOpts,
+    force_ignore: bool,
+    id: TestId,
+    test: TestDescAndFn,
+    strategy: RunStrategy,
+) -> CompletedTest {
+    let TestDescAndFn { desc, testfn } = test;
+
+    if is_ignored(&desc, force_ignore) {
+        return CompletedTest::new(id, desc, TrIgnored, None, None, Vec::new());
+    }
+
+    let testfn: Box<dyn FnOnce() + Send> = match testfn {
+        DynTestFn(f) => {
+            match strategy {
+                RunStrategy::InProcess => (),
+                _ => panic!("Cannot run dynamic test fn out-of-process"),
+            };
+            Box::new(move || __rust_begin_short_backtrace(f))
+        }
+        StaticTestFn(f) => Box::new(move || __rust_begin_short_backtrace(f)),
+        StaticBenchFn(_) | DynBenchFn(_) => unreachable!("benchmarks are run by `run_test`"),
+    };
+    run_unit_test(id, desc, testfn, TestRunOpts::new(opts, strategy, Concurrent::No))
+}
+
+fn is_ignored(desc: &TestDesc, force_ignore: bool) -> bool {
+    // Emscripten can catch panics but other wasm targets cannot
+    let ignore_because_no_process_support = desc.should_panic != ShouldPanic::No
+        && cfg!(target_arch = "wasm32")
+        && !cfg!(target_os = "emscripten");
+
+    force_ignore || desc.ignore || ignore_because_no_process_support
+}
+
+struct TestRunOpts {
+    strategy: RunStrategy,
+    nocapture: bool,
+    capture_combined: bool,
+    concurrency: Concurrent,
+    time: Option<time::TestTimeOptions>,
+    log_capture: Option<LogCapture>,
+    discard_passing_output: bool,
+}
+
+impl TestRunOpts {
+    fn new(opts: &TestOpts, strategy: RunStrategy, concurrency: Concurrent) -> Self {
+        TestRunOpts {
+            strategy,
+            nocapture: opts.nocapture,
+            capture_combined: opts.capture_combined,
+            concurrency,
+            time: opts.time_options,
+            log_capture: opts.options.log_capture,
+            discard_passing_output: opts.options.discard_passing_output,
+        }
+    }
+}
+
+/// Runs a unit test to completion on the current thread.
+fn run_unit_test(
+    id: TestId,
+    desc: TestDesc,
+    testfn: Box<dyn FnOnce() + Send>,
+    opts: TestRunOpts,
+) -> CompletedTest {
+    match opts.strategy {
+        RunStrategy::InProcess => run_test_in_process(
+            id,
+            desc,
+            opts.nocapture,
+            opts.time.is_some(),
+            testfn,
+            opts.time,
+            opts.log_capture,
+            opts.discard_passing_output,
+        ),
+        RunStrategy::SpawnPrimary => spawn_test_subprocess(
+            id,
+            desc,
+            opts.nocapture,
+            opts.capture_combined,
+            opts.time.is_some(),
+            opts.time,
+            opts.discard_passing_output,
+        ),
+    }
+}
+
 /// Fixed frame used to clean the backtrace with `RUST_BACKTRACE=1`.
 #[inline(never)]
 fn __rust_begin_short_backtrace<F: FnOnce()>(f: F) {
@@ -587,106 +890,205 @@ fn run_test_in_process(
     nocapture: bool,
     report_time: bool,
     testfn: Box<dyn FnOnce() + Send>,
-    monitor_ch: Sender<CompletedTest>,
     time_opts: Option<time::TestTimeOptions>,
-) {
+    log_capture: Option<LogCapture>,
+    discard_passing_output: bool,
+) -> CompletedTest {
     // Buffer for capturing standard I/O
     let data = Arc::new(Mutex::new(Vec::new()));
 
     if !nocapture {
         io::set_output_capture(Some(data.clone()));
     }
+    if let Some(log_capture) = log_capture {
+        if !nocapture {
+            LOG_CAPTURE_BUFFER.with(|buffer| *buffer.borrow_mut() = Some(data.clone()));
+        }
+        (log_capture.install)(write_captured_log);
+    }
 
-    let start = report_time.then(Instant::now);
+    // Start from a clean slate in case an earlier test on this thread left
+    // metrics behind.
+    TEST_METRICS.with(|metrics| metrics.replace(MetricMap::new()));
+
+    let start = report_time.then(|| (Instant::now(), thread_cpu_time()));
     let result = catch_unwind(AssertUnwindSafe(testfn));
-    let exec_time = start.map(|start| {
-        let duration = start.elapsed();
-        TestExecTime(duration)
+    let exec_time = start.map(|(start, _)| TestExecTime(start.elapsed()));
+    let cpu_time = start.and_then(|(_, start_cpu)| {
+        Some(TestCpuTime(thread_cpu_time()?.checked_sub(start_cpu?)?))
     });
 
     io::set_output_capture(None);
+    if let Some(log_capture) = log_capture {
+        (log_capture.uninstall)();
+        LOG_CAPTURE_BUFFER.with(|buffer| buffer.borrow_mut().take());
+    }
 
-    let test_result = match result {
+    let (test_result, reason) = match result {
         Ok(()) => calc_result(&desc, Ok(()), &time_opts, &exec_time),
         Err(e) => calc_result(&desc, Err(e.as_ref()), &time_opts, &exec_time),
     };
-    let stdout = data.lock().unwrap_or_else(|e| e.into_inner()).to_vec();
-    let message = CompletedTest::new(id, desc, test_result, exec_time, stdout);
-    monitor_ch.send(message).unwrap();
+    let stdout = if discard_passing_output && test_result == TrOk {
+        Vec::new()
+    } else {
+        mem::take(&mut *data.lock().unwrap_or_else(|e| e.into_inner()))
+    };
+    let mut message = CompletedTest::new(id, desc, test_result, reason, exec_time, stdout);
+    message.cpu_time = cpu_time;
+    message.metrics = TEST_METRICS.with(|metrics| metrics.replace(MetricMap::new()));
+    message
+}
+
+/// The `LogSink` handed to `LogCapture::install`.
+fn write_captured_log(args: fmt::Arguments<'_>) {
+    LOG_CAPTURE_BUFFER.with(|buffer| match &*buffer.borrow() {
+        Some(data) => {
+            let mut data = data.lock().unwrap_or_else(PoisonError::into_inner);
+            let _ = writeln!(data, "{}", args);
+        }
+        None => eprintln!("{}", args),
+    })
 }
 
 fn spawn_test_subprocess(
     id: TestId,
     desc: TestDesc,
     nocapture: bool,
+    capture_combined: bool,
     report_time: bool,
-    monitor_ch: Sender<CompletedTest>,
     time_opts: Option<time::TestTimeOptions>,
-) {
-    let (result, test_output, exec_time) = (|| {
+    discard_passing_output: bool,
+) -> CompletedTest {
+    let (result, reason, mut test_output, mut combined_output, exec_time, cpu_time) = (|| {
         let args = env::args().collect::<Vec<_>>();
         let current_exe = &args[0];
 
         let mut command = Command::new(current_exe);
         command.env(SECONDARY_TEST_INVOKER_VAR, desc.name.as_slice());
+        // Like `Command::output`, which this used to call, don't let the test
+        // read from our stdin.
+        command.stdin(process::Stdio::null());
+        if capture_combined && !nocapture {
+            command.env(SECONDARY_TEST_CAPTURE_COMBINED_VAR, "1");
+        }
         if nocapture {
             command.stdout(process::Stdio::inherit());
             command.stderr(process::Stdio::inherit());
+        } else {
+            command.stdout(process::Stdio::piped());
+            command.stderr(process::Stdio::piped());
         }
 
         let start = report_time.then(Instant::now);
-        let output = match command.output() {
+        let (output, cpu_time) = match command.spawn().and_then(cpu_time::wait_with_output) {
             Ok(out) => out,
             Err(e) => {
                 let err = format!("Failed to spawn {} as child for test: {:?}", args[0], e);
-                return (TrFailed, err.into_bytes(), None);
+                let reason = Some(FailureReason::SpawnFailure);
+                return (TrFailed, reason, err.into_bytes(), None, None, None);
             }
         };
-        let exec_time = start.map(|start| {
-            let duration = start.elapsed();
-            TestExecTime(duration)
-        });
+        let exec_time = start.map(|start| TestExecTime(start.elapsed()));
+        let cpu_time = start.and(cpu_time).map(TestCpuTime);
 
         let std::process::Output { stdout, stderr, status } = output;
-        let mut test_output = stdout;
-        formatters::write_stderr_delimiter(&mut test_output, &desc.name);
-        test_output.extend_from_slice(&stderr);
+        let mut combined_output = (capture_combined && !nocapture)
+            .then(|| combine_subprocess_output(stdout.clone(), &stderr, &desc.name, true));
+        let mut test_output = combine_subprocess_output(stdout, &stderr, &desc.name, false);
 
-        let result = match (|| -> Result<TestResult, String> {
+        let (result, reason) = match (|| -> Result<_, String> {
             let exit_code = get_exit_code(status)?;
             Ok(get_result_from_exit_code(&desc, exit_code, &time_opts, &exec_time))
         })() {
             Ok(r) => r,
             Err(e) => {
-                write!(&mut test_output, "Unexpected error: {}", e).unwrap();
-                TrFailed
+                let err = format!("Unexpected error: {}", e);
+                test_output.extend_from_slice(err.as_bytes());
+                if let Some(combined_output) = &mut combined_output {
+                    combined_output.extend_from_slice(err.as_bytes());
+                }
+                (TrFailed, Some(FailureReason::NonZeroExit))
             }
         };
 
-        (result, test_output, exec_time)
+        (result, reason, test_output, combined_output, exec_time, cpu_time)
     })();
 
-    let message = CompletedTest::new(id, desc, result, exec_time, test_output);
-    monitor_ch.send(message).unwrap();
+    if discard_passing_output && result == TrOk {
+        test_output = Vec::new();
+        combined_output = None;
+    }
+    let mut message = CompletedTest::new(id, desc, result, reason, exec_time, test_output);
+    message.cpu_time = cpu_time;
+    message.combined_output = combined_output;
+    message
 }
 
-fn run_test_in_spawned_subprocess(desc: TestDesc, testfn: Box<dyn FnOnce() + Send>) -> ! {
+/// Joins the stdout and stderr of a test subprocess into the output shown for
+/// the test.
+///
+/// With `--capture-combined`, the subprocess writes everything it printed to
+/// stdout in the order it was written, so anything on stderr is only output
+/// that bypassed the capture, e.g. from an abort.
+fn combine_subprocess_output(
+    stdout: Vec<u8>,
+    stderr: &[u8],
+    test_name: &TestName,
+    capture_combined: bool,
+) -> Vec<u8> {
+    let mut test_output = stdout;
+    if !capture_combined || !stderr.is_empty() {
+        formatters::write_stderr_delimiter(&mut test_output, test_name);
+        test_output.extend_from_slice(stderr);
+    }
+    test_output
+}
+
+fn run_test_in_spawned_subprocess(
+    desc: TestDesc,
+    testfn: Box<dyn FnOnce() + Send>,
+    capture_combined: bool,
+) -> ! {
+    // This process doesn't go through `run_tests`.
+    run_before_all_hooks();
+
+    // Capture everything the test prints, to stdout or stderr, into one
+    // buffer so that it can be handed to the primary process in order.
+    let combined_output = capture_combined.then(|| {
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        io::set_output_capture(Some(buffer.clone()));
+        buffer
+    });
+    let write_combined_output = move || {
+        if let Some(buffer) = &combined_output {
+            let buffer = buffer.lock().unwrap_or_else(PoisonError::into_inner);
+            let mut stdout = io::stdout();
+            let _ = stdout.write_all(&buffer).and_then(|()| stdout.flush());
+        }
+    };
+
     let builtin_panic_hook = panic::take_hook();
     let record_result = Arc::new(move |panic_info: Option<&'_ PanicInfo<'_>>| {
-        let test_result = match panic_info {
+        let (test_result, _) = match panic_info {
             Some(info) => calc_result(&desc, Err(info.payload()), &None, &None),
             None => calc_result(&desc, Ok(()), &None, &None),
         };
 
-        // We don't support serializing TrFailedMsg, so just
+        // We don't support serializing TrFailedMsg or TrIgnoredMsg, so just
         // print the message out to stderr.
-        if let TrFailedMsg(msg) = &test_result {
+        if let TrFailedMsg(msg) | TrIgnoredMsg(msg) = &test_result {
             eprintln!("{}", msg);
         }
 
+        if let TrIgnoredMsg(_) = test_result {
+            write_combined_output();
+            process::exit(test_result::TR_IGNORED);
+        }
+
         if let Some(info) = panic_info {
             builtin_panic_hook(info);
         }
+        write_combined_output();
 
         if let TrOk = test_result {
             process::exit(test_result::TR_OK);
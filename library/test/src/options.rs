@@ -1,5 +1,8 @@
 //! Enums denoting options for test execution.
 
+use std::fmt;
+use std::time::Duration;
+
 /// Whether to execute tests concurrently or not
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Concurrent {
@@ -52,6 +55,19 @@ pub enum RunIgnored {
     Only,
 }
 
+/// Kind of tests selected by `--test-type`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TestTypeFilter {
+    /// Tests whose type is `TestType::UnitTest`.
+    Unit,
+    /// Tests whose type is `TestType::IntegrationTest`.
+    Integration,
+    /// Tests whose type is `TestType::DocTest`.
+    Doc,
+    /// Benchmarks, whatever their type.
+    Bench,
+}
+
 #[derive(Clone, Copy)]
 pub enum RunStrategy {
     /// Runs the test in the current process, and sends the result back over the
@@ -69,12 +85,34 @@ pub enum RunStrategy {
 #[derive(Copy, Clone, Debug)]
 pub struct Options {
     pub display_output: bool,
+    /// Drops the captured output of each passing test as soon as it finishes,
+    /// instead of keeping it for the rest of the run. Failures keep theirs.
+    pub discard_passing_output: bool,
+    /// Pretty-prints each event of the json format over several indented lines.
+    pub json_pretty: bool,
     pub panic_abort: bool,
+    /// Upper bound on the time spent sampling a single benchmark.
+    pub bench_time: Option<Duration>,
+    /// Number of samples taken per measurement round of a benchmark.
+    pub bench_samples: Option<usize>,
+    /// Routes the records of a logging framework into each test's captured output.
+    pub log_capture: Option<LogCapture>,
+    /// Exit code of the process when a test fails, instead of 101.
+    pub failure_exit_code: Option<i32>,
 }
 
 impl Options {
     pub fn new() -> Options {
-        Options { display_output: false, panic_abort: false }
+        Options {
+            display_output: false,
+            discard_passing_output: false,
+            json_pretty: false,
+            panic_abort: false,
+            bench_time: None,
+            bench_samples: None,
+            log_capture: None,
+            failure_exit_code: None,
+        }
     }
 
     pub fn display_output(mut self, display_output: bool) -> Options {
@@ -82,8 +120,64 @@ pub fn display_output(mut self, display_output: bool) -> Options {
         self
     }
 
+    pub fn discard_passing_output(mut self, discard_passing_output: bool) -> Options {
+        self.discard_passing_output = discard_passing_output;
+        self
+    }
+
+    pub fn json_pretty(mut self, json_pretty: bool) -> Options {
+        self.json_pretty = json_pretty;
+        self
+    }
+
     pub fn panic_abort(mut self, panic_abort: bool) -> Options {
         self.panic_abort = panic_abort;
         self
     }
+
+    pub fn bench_time(mut self, bench_time: Option<Duration>) -> Options {
+        self.bench_time = bench_time;
+        self
+    }
+
+    pub fn bench_samples(mut self, bench_samples: Option<usize>) -> Options {
+        self.bench_samples = bench_samples;
+        self
+    }
+
+    pub fn log_capture(mut self, log_capture: Option<LogCapture>) -> Options {
+        self.log_capture = log_capture;
+        self
+    }
+
+    /// Sets the exit code of the process when a test fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `failure_exit_code` is outside of `1..=255`.
+    pub fn failure_exit_code(mut self, failure_exit_code: Option<i32>) -> Options {
+        if let Some(code) = failure_exit_code {
+            assert!((1..=255).contains(&code), "failure exit code {} is not in 1..=255", code);
+        }
+        self.failure_exit_code = failure_exit_code;
+        self
+    }
+}
+
+/// Appends one formatted log record, followed by a newline, to the output
+/// captured for the test running on the current thread.
+pub type LogSink = fn(fmt::Arguments<'_>);
+
+/// Hooks connecting a logging framework, such as `log` or `tracing`, to the
+/// per-test output capture.
+///
+/// libtest doesn't depend on any logging crate. Instead, `install` is called on
+/// the test's thread right before an in-process test runs, and is handed a
+/// [`LogSink`] the caller's logger should forward records to. `uninstall` is
+/// called on the same thread once the test has finished. Records logged from
+/// other threads, or while output capture is disabled, go to standard error.
+#[derive(Copy, Clone, Debug)]
+pub struct LogCapture {
+    pub install: fn(LogSink),
+    pub uninstall: fn(),
 }
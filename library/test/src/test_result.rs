@@ -12,6 +12,7 @@
 // it means.
 pub const TR_OK: i32 = 50;
 pub const TR_FAILED: i32 = 51;
+pub const TR_IGNORED: i32 = 52;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum TestResult {
@@ -19,19 +20,84 @@ pub enum TestResult {
     TrFailed,
     TrFailedMsg(String),
     TrIgnored,
+    TrIgnoredMsg(String),
     TrAllowedFail,
     TrBench(BenchSamples),
     TrTimedFail,
 }
 
+/// Why a test did not pass, as reported to tools consuming the JSON output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureReason {
+    /// The test panicked.
+    Panic,
+    /// The test ran past its critical time limit.
+    Timeout,
+    /// A `#[should_panic]` test did not panic, or panicked with an unexpected
+    /// message.
+    ShouldPanicMismatch,
+    /// The subprocess the test should have run in could not be spawned.
+    SpawnFailure,
+    /// The subprocess the test ran in exited with an unexpected code or was
+    /// killed by a signal, e.g. because the test aborted.
+    NonZeroExit,
+}
+
+impl FailureReason {
+    /// The stable name of this reason, as it appears in the JSON output.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FailureReason::Panic => "panic",
+            FailureReason::Timeout => "timeout",
+            FailureReason::ShouldPanicMismatch => "should_panic_mismatch",
+            FailureReason::SpawnFailure => "spawn_failure",
+            FailureReason::NonZeroExit => "nonzero_exit",
+        }
+    }
+}
+
+/// Works out why the test described by `desc` ended with `result`, if it
+/// failed (or was allowed to fail) while running to completion.
+fn failure_reason(desc: &TestDesc, result: &TestResult) -> Option<FailureReason> {
+    match result {
+        TestResult::TrOk
+        | TestResult::TrIgnored
+        | TestResult::TrIgnoredMsg(_)
+        | TestResult::TrBench(_) => None,
+        TestResult::TrTimedFail => Some(FailureReason::Timeout),
+        TestResult::TrFailed | TestResult::TrFailedMsg(_) | TestResult::TrAllowedFail => {
+            // A test expected to panic can only fail by not panicking the
+            // way it was supposed to.
+            match desc.should_panic {
+                ShouldPanic::No => Some(FailureReason::Panic),
+                ShouldPanic::Yes | ShouldPanic::YesWithMessage(_) => {
+                    Some(FailureReason::ShouldPanicMismatch)
+                }
+            }
+        }
+    }
+}
+
+/// Panic payload used by `skip_test` to mark the running test as ignored.
+pub(crate) struct SkippedTest {
+    pub reason: String,
+}
+
 /// Creates a `TestResult` depending on the raw result of test execution
-/// and associated data.
+/// and associated data, along with the reason the test failed, if it did.
 pub fn calc_result<'a>(
     desc: &TestDesc,
     task_result: Result<(), &'a (dyn Any + 'static + Send)>,
     time_opts: &Option<time::TestTimeOptions>,
     exec_time: &Option<time::TestExecTime>,
-) -> TestResult {
+) -> (TestResult, Option<FailureReason>) {
+    // A test that skipped itself is ignored, whatever it was expected to do.
+    if let Err(err) = task_result {
+        if let Some(skipped) = err.downcast_ref::<SkippedTest>() {
+            return (TestResult::TrIgnoredMsg(skipped.reason.clone()), None);
+        }
+    }
+
     let result = match (&desc.should_panic, task_result) {
         (&ShouldPanic::No, Ok(())) | (&ShouldPanic::Yes, Err(_)) => TestResult::TrOk,
         (&ShouldPanic::YesWithMessage(msg), Err(ref err)) => {
@@ -67,47 +133,54 @@ pub fn calc_result<'a>(
         _ if desc.allow_fail => TestResult::TrAllowedFail,
         _ => TestResult::TrFailed,
     };
+    let reason = failure_reason(desc, &result);
 
     // If test is already failed (or allowed to fail), do not change the result.
     if result != TestResult::TrOk {
-        return result;
+        return (result, reason);
     }
 
     // Check if test is failed due to timeout.
     if let (Some(opts), Some(time)) = (time_opts, exec_time) {
         if opts.error_on_excess && opts.is_critical(desc, time) {
-            return TestResult::TrTimedFail;
+            return (TestResult::TrTimedFail, Some(FailureReason::Timeout));
         }
     }
 
-    result
+    (result, None)
 }
 
-/// Creates a `TestResult` depending on the exit code of test subprocess.
+/// Creates a `TestResult` depending on the exit code of test subprocess,
+/// along with the reason the test failed, if it did.
 pub fn get_result_from_exit_code(
     desc: &TestDesc,
     code: i32,
     time_opts: &Option<time::TestTimeOptions>,
     exec_time: &Option<time::TestExecTime>,
-) -> TestResult {
+) -> (TestResult, Option<FailureReason>) {
     let result = match (desc.allow_fail, code) {
         (_, TR_OK) => TestResult::TrOk,
+        (_, TR_IGNORED) => TestResult::TrIgnored,
         (true, TR_FAILED) => TestResult::TrAllowedFail,
         (false, TR_FAILED) => TestResult::TrFailed,
-        (_, _) => TestResult::TrFailedMsg(format!("got unexpected return code {}", code)),
+        (_, _) => {
+            let result = TestResult::TrFailedMsg(format!("got unexpected return code {}", code));
+            return (result, Some(FailureReason::NonZeroExit));
+        }
     };
+    let reason = failure_reason(desc, &result);
 
     // If test is already failed (or allowed to fail), do not change the result.
     if result != TestResult::TrOk {
-        return result;
+        return (result, reason);
     }
 
     // Check if test is failed due to timeout.
     if let (Some(opts), Some(time)) = (time_opts, exec_time) {
         if opts.error_on_excess && opts.is_critical(desc, time) {
-            return TestResult::TrTimedFail;
+            return (TestResult::TrTimedFail, Some(FailureReason::Timeout));
         }
     }
 
-    result
+    (result, None)
 }
@@ -2,8 +2,8 @@
 
 use crate::{
     bench::Bencher,
-    console::OutputLocation,
-    formatters::PrettyFormatter,
+    console::{list_tests, OutputLocation},
+    formatters::{JsonFormatter, OutputFormatter, PrettyFormatter},
     options::OutputFormat,
     test::{
         filter_tests,
@@ -20,6 +20,7 @@
         TestDescAndFn,
         TestOpts,
         TrIgnored,
+        TrIgnoredMsg,
         TrOk,
         // FIXME (introduced by #65251)
         // ShouldPanic, StaticTestName, TestDesc, TestDescAndFn, TestOpts, TestTimeOptions,
@@ -27,8 +28,9 @@
     },
     time::{TestTimeOptions, TimeThreshold},
 };
+use std::path::PathBuf;
 use std::sync::mpsc::channel;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 impl TestOpts {
     fn new() -> TestOpts {
@@ -36,9 +38,12 @@ fn new() -> TestOpts {
             list: false,
             filters: vec![],
             filter_exact: false,
+            exact_filters: vec![],
             force_run_in_process: false,
+            capture_combined: false,
             exclude_should_panic: false,
             run_ignored: RunIgnored::No,
+            deny_ignored: false,
             run_tests: false,
             bench_benchmarks: false,
             logfile: None,
@@ -47,7 +52,17 @@ fn new() -> TestOpts {
             format: OutputFormat::Pretty,
             test_threads: None,
             skip: vec![],
+            filter_files: vec![],
+            test_types: vec![],
             time_options: None,
+            shuffle: false,
+            shuffle_seed: None,
+            metrics_out: None,
+            bench_baseline: None,
+            bench_fail_threshold: None,
+            repeat: 1,
+            detect_leaked_threads: false,
+            resource_limits: vec![],
             options: Options::new(),
         }
     }
@@ -64,6 +79,12 @@ fn one_ignored_one_unignored_test() -> Vec<TestDescAndFn> {
                 compile_fail: false,
                 no_run: false,
                 test_type: TestType::Unknown,
+                #[cfg(not(bootstrap))]
+                metadata: &[],
+                #[cfg(not(bootstrap))]
+                resource: None,
+                #[cfg(not(bootstrap))]
+                source_file: None,
             },
             testfn: DynTestFn(Box::new(move || {})),
         },
@@ -76,6 +97,12 @@ fn one_ignored_one_unignored_test() -> Vec<TestDescAndFn> {
                 compile_fail: false,
                 no_run: false,
                 test_type: TestType::Unknown,
+                #[cfg(not(bootstrap))]
+                metadata: &[],
+                #[cfg(not(bootstrap))]
+                resource: None,
+                #[cfg(not(bootstrap))]
+                source_file: None,
             },
             testfn: DynTestFn(Box::new(move || {})),
         },
@@ -96,6 +123,12 @@ fn f() {
             compile_fail: false,
             no_run: false,
             test_type: TestType::Unknown,
+            #[cfg(not(bootstrap))]
+            metadata: &[],
+            #[cfg(not(bootstrap))]
+            resource: None,
+            #[cfg(not(bootstrap))]
+            source_file: None,
         },
         testfn: DynTestFn(Box::new(f)),
     };
@@ -117,6 +150,12 @@ fn f() {}
             compile_fail: false,
             no_run: false,
             test_type: TestType::Unknown,
+            #[cfg(not(bootstrap))]
+            metadata: &[],
+            #[cfg(not(bootstrap))]
+            resource: None,
+            #[cfg(not(bootstrap))]
+            source_file: None,
         },
         testfn: DynTestFn(Box::new(f)),
     };
@@ -126,6 +165,37 @@ fn f() {}
     assert_eq!(result, TrIgnored);
 }
 
+// FIXME: Re-enable emscripten once it can catch panics again (introduced by #65251)
+#[test]
+#[cfg(not(target_os = "emscripten"))]
+fn skipped_tests_result_in_ignored() {
+    fn f() {
+        crate::skip_test("not applicable here");
+    }
+    let desc = TestDescAndFn {
+        desc: TestDesc {
+            name: StaticTestName("whatever"),
+            ignore: false,
+            should_panic: ShouldPanic::No,
+            allow_fail: false,
+            compile_fail: false,
+            no_run: false,
+            test_type: TestType::Unknown,
+            #[cfg(not(bootstrap))]
+            metadata: &[],
+            #[cfg(not(bootstrap))]
+            resource: None,
+            #[cfg(not(bootstrap))]
+            source_file: None,
+        },
+        testfn: DynTestFn(Box::new(f)),
+    };
+    let (tx, rx) = channel();
+    run_test(&TestOpts::new(), false, TestId(0), desc, RunStrategy::InProcess, tx, Concurrent::No);
+    let result = rx.recv().unwrap().result;
+    assert_eq!(result, TrIgnoredMsg("not applicable here".to_string()));
+}
+
 // FIXME: Re-enable emscripten once it can catch panics again (introduced by #65251)
 #[test]
 #[cfg(not(target_os = "emscripten"))]
@@ -142,6 +212,12 @@ fn f() {
             compile_fail: false,
             no_run: false,
             test_type: TestType::Unknown,
+            #[cfg(not(bootstrap))]
+            metadata: &[],
+            #[cfg(not(bootstrap))]
+            resource: None,
+            #[cfg(not(bootstrap))]
+            source_file: None,
         },
         testfn: DynTestFn(Box::new(f)),
     };
@@ -167,6 +243,12 @@ fn f() {
             compile_fail: false,
             no_run: false,
             test_type: TestType::Unknown,
+            #[cfg(not(bootstrap))]
+            metadata: &[],
+            #[cfg(not(bootstrap))]
+            resource: None,
+            #[cfg(not(bootstrap))]
+            source_file: None,
         },
         testfn: DynTestFn(Box::new(f)),
     };
@@ -197,6 +279,12 @@ fn f() {
             compile_fail: false,
             no_run: false,
             test_type: TestType::Unknown,
+            #[cfg(not(bootstrap))]
+            metadata: &[],
+            #[cfg(not(bootstrap))]
+            resource: None,
+            #[cfg(not(bootstrap))]
+            source_file: None,
         },
         testfn: DynTestFn(Box::new(f)),
     };
@@ -231,6 +319,12 @@ fn f() {
             compile_fail: false,
             no_run: false,
             test_type: TestType::Unknown,
+            #[cfg(not(bootstrap))]
+            metadata: &[],
+            #[cfg(not(bootstrap))]
+            resource: None,
+            #[cfg(not(bootstrap))]
+            source_file: None,
         },
         testfn: DynTestFn(Box::new(f)),
     };
@@ -257,6 +351,12 @@ fn f() {}
                 compile_fail: false,
                 no_run: false,
                 test_type: TestType::Unknown,
+                #[cfg(not(bootstrap))]
+                metadata: &[],
+                #[cfg(not(bootstrap))]
+                resource: None,
+                #[cfg(not(bootstrap))]
+                source_file: None,
             },
             testfn: DynTestFn(Box::new(f)),
         };
@@ -291,6 +391,12 @@ fn f() {}
             compile_fail: false,
             no_run: false,
             test_type: TestType::Unknown,
+            #[cfg(not(bootstrap))]
+            metadata: &[],
+            #[cfg(not(bootstrap))]
+            resource: None,
+            #[cfg(not(bootstrap))]
+            source_file: None,
         },
         testfn: DynTestFn(Box::new(f)),
     };
@@ -315,6 +421,53 @@ fn test_should_report_time() {
     assert!(exec_time.is_some());
 }
 
+#[test]
+fn test_cpu_time_not_above_wall_time() {
+    fn f() {
+        // Burn some CPU, then block without using any.
+        let mut x = 0u64;
+        for i in 0..1_000_000 {
+            x = black_box(x.wrapping_add(i));
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    let desc = TestDescAndFn {
+        desc: TestDesc {
+            name: StaticTestName("whatever"),
+            ignore: false,
+            should_panic: ShouldPanic::No,
+            allow_fail: false,
+            compile_fail: false,
+            no_run: false,
+            test_type: TestType::Unknown,
+            #[cfg(not(bootstrap))]
+            metadata: &[],
+            #[cfg(not(bootstrap))]
+            resource: None,
+            #[cfg(not(bootstrap))]
+            source_file: None,
+        },
+        testfn: DynTestFn(Box::new(f)),
+    };
+    let test_opts = TestOpts { time_options: Some(TestTimeOptions::default()), ..TestOpts::new() };
+    let (tx, rx) = channel();
+    run_test(&test_opts, false, TestId(0), desc, RunStrategy::InProcess, tx, Concurrent::No);
+    let completed_test = rx.recv().unwrap();
+    let exec_time = completed_test.exec_time.unwrap();
+
+    assert!(exec_time.0 >= Duration::from_millis(50));
+    if let Some(cpu_time) = completed_test.cpu_time {
+        // Leave some slack for the granularity of the clocks involved.
+        assert!(cpu_time.0 <= exec_time.0 + Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn test_exec_time_display() {
+    assert_eq!(TestExecTime(Duration::from_millis(1500)).to_string(), "1.500s");
+    assert_eq!(TestCpuTime(Duration::from_millis(250)).to_string(), "cpu 0.250s");
+}
+
 fn time_test_failure_template(test_type: TestType) -> TestResult {
     fn f() {}
     let desc = TestDescAndFn {
@@ -326,6 +479,12 @@ fn f() {}
             compile_fail: false,
             no_run: false,
             test_type,
+            #[cfg(not(bootstrap))]
+            metadata: &[],
+            #[cfg(not(bootstrap))]
+            resource: None,
+            #[cfg(not(bootstrap))]
+            source_file: None,
         },
         testfn: DynTestFn(Box::new(f)),
     };
@@ -365,6 +524,12 @@ fn typed_test_desc(test_type: TestType) -> TestDesc {
         compile_fail: false,
         no_run: false,
         test_type,
+        #[cfg(not(bootstrap))]
+        metadata: &[],
+        #[cfg(not(bootstrap))]
+        resource: None,
+        #[cfg(not(bootstrap))]
+        source_file: None,
     }
 }
 
@@ -421,6 +586,190 @@ fn parse_show_output_flag() {
     assert!(opts.options.display_output);
 }
 
+#[test]
+fn parse_bench_limit_flags() {
+    let args = vec![
+        "progname".to_string(),
+        "--bench-time".to_string(),
+        "0.5".to_string(),
+        "--bench-samples".to_string(),
+        "10".to_string(),
+        "-Zunstable-options".to_string(),
+    ];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert_eq!(opts.options.bench_time, Some(Duration::from_millis(500)));
+    assert_eq!(opts.options.bench_samples, Some(10));
+
+    let args = vec!["progname".to_string(), "--bench-samples".to_string(), "10".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+
+    let args = vec![
+        "progname".to_string(),
+        "--bench-samples".to_string(),
+        "0".to_string(),
+        "-Zunstable-options".to_string(),
+    ];
+    assert!(parse_opts(&args).unwrap().is_err());
+}
+
+// FIXME: Re-enable emscripten once it can catch panics again (introduced by #65251)
+#[test]
+#[cfg(not(target_os = "emscripten"))]
+fn failing_run_uses_configured_exit_code() {
+    let parse = |code: &str| {
+        let args = vec![
+            "progname".to_string(),
+            "--failure-exit-code".to_string(),
+            code.to_string(),
+            "-Zunstable-options".to_string(),
+        ];
+        parse_opts(&args).unwrap()
+    };
+    assert!(parse("0").is_err());
+    assert!(parse("256").is_err());
+    assert!(parse("three").is_err());
+
+    let opts = parse("3").unwrap();
+    assert_eq!(opts.options.failure_exit_code, Some(3));
+    let desc = TestDescAndFn {
+        desc: TestDesc {
+            name: StaticTestName("fails"),
+            ignore: false,
+            should_panic: ShouldPanic::No,
+            allow_fail: false,
+            compile_fail: false,
+            no_run: false,
+            test_type: TestType::Unknown,
+            #[cfg(not(bootstrap))]
+            metadata: &[],
+            #[cfg(not(bootstrap))]
+            resource: None,
+            #[cfg(not(bootstrap))]
+            source_file: None,
+        },
+        testfn: DynTestFn(Box::new(|| panic!())),
+    };
+    assert!(!run_tests_console(&opts, vec![desc]).unwrap());
+    assert_eq!(failure_exit_code(&opts), 3);
+    assert_eq!(failure_exit_code(&TestOpts::new()), 101);
+
+    let args = vec!["progname".to_string(), "--failure-exit-code".to_string(), "3".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+}
+
+#[test]
+fn repeat_runs_each_test_several_times() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+    static FLAKY_RUNS: AtomicUsize = AtomicUsize::new(0);
+    fn counted() {
+        RUNS.fetch_add(1, Ordering::SeqCst);
+    }
+    fn fails_the_second_time() {
+        assert_ne!(FLAKY_RUNS.fetch_add(1, Ordering::SeqCst), 1);
+    }
+    fn desc(name: &'static str, f: fn()) -> TestDescAndFn {
+        TestDescAndFn {
+            desc: TestDesc {
+                name: StaticTestName(name),
+                ignore: false,
+                should_panic: ShouldPanic::No,
+                allow_fail: false,
+                compile_fail: false,
+                no_run: false,
+                test_type: TestType::Unknown,
+                #[cfg(not(bootstrap))]
+                metadata: &[],
+                #[cfg(not(bootstrap))]
+                resource: None,
+                #[cfg(not(bootstrap))]
+                source_file: None,
+            },
+            testfn: StaticTestFn(f),
+        }
+    }
+
+    let parse = |n: &str| {
+        let args = vec![
+            "progname".to_string(),
+            "--repeat".to_string(),
+            n.to_string(),
+            "-Zunstable-options".to_string(),
+        ];
+        parse_opts(&args).unwrap()
+    };
+    assert!(parse("0").is_err());
+    assert!(parse("many").is_err());
+    let args = vec!["progname".to_string(), "--repeat".to_string(), "3".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+    assert_eq!(TestOpts::new().repeat, 1);
+
+    let opts = parse("3").unwrap();
+    assert_eq!(opts.repeat, 3);
+    assert!(run_tests_console_repeatedly(&opts, vec![desc("counted", counted)]).unwrap());
+    assert_eq!(RUNS.load(Ordering::SeqCst), 3);
+
+    let tests = vec![desc("counted", counted), desc("flaky", fails_the_second_time)];
+    assert!(!run_tests_console_repeatedly(&opts, tests).unwrap());
+    assert_eq!(RUNS.load(Ordering::SeqCst), 6);
+    assert_eq!(FLAKY_RUNS.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn parse_bench_baseline_flags() {
+    let args = vec![
+        "progname".to_string(),
+        "--metrics-out".to_string(),
+        "new.json".to_string(),
+        "--bench-baseline".to_string(),
+        "old.json".to_string(),
+        "--bench-fail-threshold".to_string(),
+        "5".to_string(),
+        "-Zunstable-options".to_string(),
+    ];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert_eq!(opts.metrics_out, Some(PathBuf::from("new.json")));
+    assert_eq!(opts.bench_baseline, Some(PathBuf::from("old.json")));
+    assert_eq!(opts.bench_fail_threshold, Some(5.0));
+
+    let args = vec!["progname".to_string(), "--bench-baseline".to_string(), "old.json".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+
+    let args = vec![
+        "progname".to_string(),
+        "--bench-fail-threshold".to_string(),
+        "5".to_string(),
+        "-Zunstable-options".to_string(),
+    ];
+    assert!(parse_opts(&args).unwrap().is_err());
+}
+
+#[test]
+fn parse_shuffle_flags() {
+    let args = vec![
+        "progname".to_string(),
+        "--shuffle".to_string(),
+        "--shuffle-seed".to_string(),
+        "12345".to_string(),
+        "-Zunstable-options".to_string(),
+    ];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert!(opts.shuffle);
+    assert_eq!(opts.shuffle_seed, Some(12345));
+
+    let args = vec!["progname".to_string(), "--shuffle-seed".to_string(), "12345".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+
+    let args = vec![
+        "progname".to_string(),
+        "--shuffle-seed".to_string(),
+        "not-a-seed".to_string(),
+        "-Zunstable-options".to_string(),
+    ];
+    assert!(parse_opts(&args).unwrap().is_err());
+}
+
 #[test]
 fn parse_include_ignored_flag() {
     let args = vec!["progname".to_string(), "filter".to_string(), "--include-ignored".to_string()];
@@ -428,6 +777,288 @@ fn parse_include_ignored_flag() {
     assert_eq!(opts.run_ignored, RunIgnored::Yes);
 }
 
+#[test]
+fn parse_deny_ignored_flag() {
+    let args = vec![
+        "progname".to_string(),
+        "--deny-ignored".to_string(),
+        "-Zunstable-options".to_string(),
+    ];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert!(opts.deny_ignored);
+
+    let args = vec!["progname".to_string(), "--deny-ignored".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+}
+
+#[test]
+fn deny_ignored_fails_run_and_lists_tests() {
+    let opts = TestOpts { deny_ignored: true, run_tests: true, ..TestOpts::new() };
+    let mut st = console::ConsoleTestState::new(&opts).unwrap();
+    run_tests(&opts, one_ignored_one_unignored_test(), |event| {
+        if let TestEvent::TeResult(completed_test) = event {
+            console::handle_test_result(&mut st, completed_test);
+        }
+        Ok(())
+    })
+    .unwrap();
+    assert_eq!(st.ignored, 1);
+
+    let mut out = Vec::new();
+    assert!(!console::check_ignored(&opts, &st, &mut out).unwrap());
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.contains("--deny-ignored"));
+    assert!(out.contains("    1\n"));
+    assert!(!out.contains("    2\n"));
+
+    // Without the flag, ignored tests don't fail the run.
+    let mut out = Vec::new();
+    assert!(console::check_ignored(&TestOpts::new(), &st, &mut out).unwrap());
+    assert!(out.is_empty());
+
+    // Nor are they collected.
+    let st = console::ConsoleTestState::new(&TestOpts::new()).unwrap();
+    assert!(st.ignored_tests.is_none());
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn detect_leaked_threads_reports_threads_outliving_tests() {
+    use std::sync::mpsc::Sender;
+    use std::sync::{Arc, Mutex};
+
+    let args = vec![
+        "progname".to_string(),
+        "--detect-leaked-threads".to_string(),
+        "-Zunstable-options".to_string(),
+    ];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert!(opts.detect_leaked_threads);
+    let args = vec!["progname".to_string(), "--detect-leaked-threads".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+
+    // The leaked thread runs until `release` is dropped, so that it is still
+    // alive when the run ends.
+    let release: Arc<Mutex<Option<Sender<()>>>> = Arc::new(Mutex::new(None));
+    let leaked_handle = Arc::new(Mutex::new(None));
+    let test = {
+        let release = release.clone();
+        let leaked_handle = leaked_handle.clone();
+        TestDescAndFn {
+            desc: TestDesc {
+                name: StaticTestName("leaks_a_thread"),
+                ignore: false,
+                should_panic: ShouldPanic::No,
+                allow_fail: false,
+                compile_fail: false,
+                no_run: false,
+                test_type: TestType::Unknown,
+                #[cfg(not(bootstrap))]
+                metadata: &[],
+                #[cfg(not(bootstrap))]
+                resource: None,
+                #[cfg(not(bootstrap))]
+                source_file: None,
+            },
+            testfn: DynTestFn(Box::new(move || {
+                let (tx, rx) = channel::<()>();
+                *release.lock().unwrap() = Some(tx);
+                *leaked_handle.lock().unwrap() = Some(std::thread::spawn(move || {
+                    let _ = rx.recv();
+                }));
+            })),
+        }
+    };
+
+    let mut st = console::ConsoleTestState::new(&opts).unwrap();
+    run_tests(&opts, vec![test], |event| {
+        if let TestEvent::TeLeakedThreads(leaked) = event {
+            st.leaked_threads = Some(leaked);
+        }
+        Ok(())
+    })
+    .unwrap();
+    let leaked = st.leaked_threads.unwrap();
+    // Other tests of this crate may spawn threads at the same time, so only a
+    // lower bound can be checked.
+    assert!(leaked >= 1);
+
+    let mut out = Vec::new();
+    console::check_leaked_threads(&opts, &st, &mut out).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.contains("still running after the run"), "{}", out);
+
+    let mut out = Vec::new();
+    console::check_leaked_threads(&TestOpts::new(), &st, &mut out).unwrap();
+    assert!(out.is_empty());
+
+    drop(release.lock().unwrap().take());
+    leaked_handle.lock().unwrap().take().unwrap().join().unwrap();
+}
+
+#[test]
+#[cfg(not(bootstrap))]
+fn tests_sharing_a_resource_never_overlap() {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let args = vec![
+        "progname".to_string(),
+        "--resource-limit".to_string(),
+        "gpu=2".to_string(),
+        "-Zunstable-options".to_string(),
+    ];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert_eq!(opts.resource_limits, [("gpu".to_string(), 2)]);
+    let args = vec!["progname".to_string(), "--resource-limit".to_string(), "gpu=2".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+    for bad in &["gpu", "gpu=0", "=2", "gpu=many"] {
+        let args = vec![
+            "progname".to_string(),
+            "--resource-limit".to_string(),
+            bad.to_string(),
+            "-Zunstable-options".to_string(),
+        ];
+        assert!(parse_opts(&args).unwrap().is_err(), "{}", bad);
+    }
+
+    let active = Arc::new(AtomicUsize::new(0));
+    let overlapped = Arc::new(AtomicBool::new(false));
+    let uses_db = |name: &'static str| {
+        let active = active.clone();
+        let overlapped = overlapped.clone();
+        TestDescAndFn {
+            desc: TestDesc {
+                name: StaticTestName(name),
+                ignore: false,
+                should_panic: ShouldPanic::No,
+                allow_fail: false,
+                compile_fail: false,
+                no_run: false,
+                test_type: TestType::Unknown,
+                #[cfg(not(bootstrap))]
+                metadata: &[],
+                #[cfg(not(bootstrap))]
+                resource: Some("db"),
+                #[cfg(not(bootstrap))]
+                source_file: None,
+            },
+            testfn: DynTestFn(Box::new(move || {
+                if active.fetch_add(1, Ordering::SeqCst) != 0 {
+                    overlapped.store(true, Ordering::SeqCst);
+                }
+                std::thread::sleep(Duration::from_millis(50));
+                active.fetch_sub(1, Ordering::SeqCst);
+            })),
+        }
+    };
+
+    let opts = TestOpts { run_tests: true, test_threads: Some(4), ..TestOpts::new() };
+    let tests = vec![uses_db("db_a"), uses_db("db_b"), uses_db("db_c")];
+    let mut passed = 0;
+    run_tests(&opts, tests, |event| {
+        if let TestEvent::TeResult(completed_test) = event {
+            assert_eq!(completed_test.result, TrOk);
+            passed += 1;
+        }
+        Ok(())
+    })
+    .unwrap();
+    assert_eq!(passed, 3);
+    assert!(!overlapped.load(Ordering::SeqCst));
+}
+
+#[test]
+fn before_all_runs_once_before_first_test() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    static NESTED_CALLS: AtomicUsize = AtomicUsize::new(0);
+    fn setup() {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+        // Hooks may register more hooks.
+        before_all(nested_setup);
+    }
+    fn nested_setup() {
+        NESTED_CALLS.fetch_add(1, Ordering::SeqCst);
+    }
+
+    before_all(setup);
+
+    let opts = TestOpts::new();
+    let mut calls_at_first_wait = None;
+    run_tests(&opts, one_ignored_one_unignored_test(), |event| {
+        if let TestEvent::TeWait(_) = event {
+            calls_at_first_wait.get_or_insert((
+                CALLS.load(Ordering::SeqCst),
+                NESTED_CALLS.load(Ordering::SeqCst),
+            ));
+        }
+        Ok(())
+    })
+    .unwrap();
+    assert_eq!(calls_at_first_wait, Some((1, 1)));
+
+    // The hooks are not run again for later runs.
+    run_tests(&opts, one_ignored_one_unignored_test(), |_| Ok(())).unwrap();
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    assert_eq!(NESTED_CALLS.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn log_capture_routes_records_into_test_output() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Stand-in for a `log::Log` implementation that forwards to the installed sink.
+    static SINK: SyncLazy<Mutex<Option<LogSink>>> = SyncLazy::new(|| Mutex::new(None));
+    static UNINSTALLS: AtomicUsize = AtomicUsize::new(0);
+    fn install(sink: LogSink) {
+        *SINK.lock().unwrap() = Some(sink);
+    }
+    fn uninstall() {
+        *SINK.lock().unwrap() = None;
+        UNINSTALLS.fetch_add(1, Ordering::SeqCst);
+    }
+    fn info(args: fmt::Arguments<'_>) {
+        if let Some(sink) = *SINK.lock().unwrap() {
+            sink(args);
+        }
+    }
+
+    fn f() {
+        info(format_args!("INFO connecting to {}", "db"));
+        panic!("connection refused");
+    }
+    let desc = TestDescAndFn {
+        desc: TestDesc {
+            name: StaticTestName("whatever"),
+            ignore: false,
+            should_panic: ShouldPanic::No,
+            allow_fail: false,
+            compile_fail: false,
+            no_run: false,
+            test_type: TestType::Unknown,
+            #[cfg(not(bootstrap))]
+            metadata: &[],
+            #[cfg(not(bootstrap))]
+            resource: None,
+            #[cfg(not(bootstrap))]
+            source_file: None,
+        },
+        testfn: DynTestFn(Box::new(f)),
+    };
+    let mut opts = TestOpts::new();
+    opts.options = opts.options.log_capture(Some(LogCapture { install, uninstall }));
+    let (tx, rx) = channel();
+    run_test(&opts, false, TestId(0), desc, RunStrategy::InProcess, tx, Concurrent::No);
+    let completed = rx.recv().unwrap();
+    assert_eq!(completed.result, TrFailed);
+    let stdout = String::from_utf8(completed.stdout).unwrap();
+    assert!(stdout.contains("INFO connecting to db\n"), "captured output: {:?}", stdout);
+    assert_eq!(UNINSTALLS.load(Ordering::SeqCst), 1);
+    assert!(SINK.lock().unwrap().is_none());
+}
+
 #[test]
 pub fn filter_for_ignored_option() {
     // When we run ignored tests the test filter should filter out all the
@@ -462,6 +1093,26 @@ pub fn run_include_ignored_option() {
     assert!(!filtered[1].desc.ignore);
 }
 
+#[test]
+pub fn list_ignored_tests() {
+    fn list(run_ignored: RunIgnored) -> String {
+        let mut opts = TestOpts::new();
+        opts.list = true;
+        opts.run_ignored = run_ignored;
+
+        let mut output = OutputLocation::Raw(Vec::new());
+        list_tests(&mut output, &opts, one_ignored_one_unignored_test()).unwrap();
+        match output {
+            OutputLocation::Raw(ref m) => String::from_utf8_lossy(m).into_owned(),
+            OutputLocation::Pretty(_) => unreachable!(),
+        }
+    }
+
+    assert_eq!(list(RunIgnored::No), "1: test\n2: test\n\n2 tests, 0 benchmarks\n");
+    assert_eq!(list(RunIgnored::Only), "1: test\n\n1 test, 0 benchmarks\n");
+    assert_eq!(list(RunIgnored::Yes), "1: test\n2: test\n\n2 tests, 0 benchmarks\n");
+}
+
 #[test]
 pub fn exclude_should_panic_option() {
     let mut opts = TestOpts::new();
@@ -478,6 +1129,12 @@ pub fn exclude_should_panic_option() {
             compile_fail: false,
             no_run: false,
             test_type: TestType::Unknown,
+            #[cfg(not(bootstrap))]
+            metadata: &[],
+            #[cfg(not(bootstrap))]
+            resource: None,
+            #[cfg(not(bootstrap))]
+            source_file: None,
         },
         testfn: DynTestFn(Box::new(move || {})),
     });
@@ -488,6 +1145,157 @@ pub fn exclude_should_panic_option() {
     assert!(filtered.iter().all(|test| test.desc.should_panic == ShouldPanic::No));
 }
 
+#[test]
+fn filter_by_test_type() {
+    fn tests() -> Vec<TestDescAndFn> {
+        fn bench(_: &mut Bencher) {}
+
+        let kinds = [
+            ("unit", TestType::UnitTest, false),
+            ("integration", TestType::IntegrationTest, false),
+            ("doc", TestType::DocTest, false),
+            ("unknown", TestType::Unknown, false),
+            ("unit_bench", TestType::UnitTest, true),
+        ];
+        kinds
+            .iter()
+            .map(|&(name, test_type, is_bench)| TestDescAndFn {
+                desc: TestDesc {
+                    name: StaticTestName(name),
+                    ignore: false,
+                    should_panic: ShouldPanic::No,
+                    allow_fail: false,
+                    compile_fail: false,
+                    no_run: false,
+                    test_type,
+                    #[cfg(not(bootstrap))]
+                    metadata: &[],
+                    #[cfg(not(bootstrap))]
+                    resource: None,
+                    #[cfg(not(bootstrap))]
+                    source_file: None,
+                },
+                testfn: if is_bench {
+                    StaticBenchFn(bench)
+                } else {
+                    DynTestFn(Box::new(move || {}))
+                },
+            })
+            .collect()
+    }
+
+    fn names(test_types: Vec<TestTypeFilter>) -> Vec<&'static str> {
+        let opts = TestOpts { test_types, ..TestOpts::new() };
+        filter_tests(&opts, tests())
+            .into_iter()
+            .map(|test| match test.desc.name {
+                StaticTestName(name) => name,
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    assert_eq!(names(vec![]).len(), 5);
+    assert_eq!(names(vec![TestTypeFilter::Doc]), ["doc"]);
+    assert_eq!(names(vec![TestTypeFilter::Unit]), ["unit", "unit_bench"]);
+    assert_eq!(names(vec![TestTypeFilter::Bench]), ["unit_bench"]);
+    assert_eq!(
+        names(vec![TestTypeFilter::Integration, TestTypeFilter::Doc]),
+        ["doc", "integration"]
+    );
+
+    let args = vec![
+        "progname".to_string(),
+        "--test-type".to_string(),
+        "doc".to_string(),
+        "--test-type=bench".to_string(),
+        "-Zunstable-options".to_string(),
+    ];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert_eq!(opts.test_types, [TestTypeFilter::Doc, TestTypeFilter::Bench]);
+
+    let args = vec!["progname".to_string(), "--test-type=doc".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+    let args =
+        vec!["progname".to_string(), "--test-type=fuzz".to_string(), "-Zunstable-options".into()];
+    assert!(parse_opts(&args).unwrap().is_err());
+}
+
+#[test]
+#[cfg(not(bootstrap))]
+fn filter_by_source_file() {
+    fn tests() -> Vec<TestDescAndFn> {
+        let files = [
+            ("lib", Some("src/lib.rs")),
+            ("net_mod", Some("src/net/mod.rs")),
+            ("net_tcp", Some("src/net/tcp.rs")),
+            ("net_tcp_listener", Some("src/net/tcp/listener.rs")),
+            ("network", Some("src/network.rs")),
+            ("windows_net", Some("src\\net\\udp.rs")),
+            ("integration", Some("tests/net.rs")),
+            ("doctest", None),
+        ];
+        files
+            .iter()
+            .map(|&(name, source_file)| TestDescAndFn {
+                desc: TestDesc {
+                    name: StaticTestName(name),
+                    ignore: false,
+                    should_panic: ShouldPanic::No,
+                    allow_fail: false,
+                    compile_fail: false,
+                    no_run: false,
+                    test_type: TestType::Unknown,
+                    #[cfg(not(bootstrap))]
+                    metadata: &[],
+                    #[cfg(not(bootstrap))]
+                    resource: None,
+                    source_file,
+                },
+                testfn: DynTestFn(Box::new(move || {})),
+            })
+            .collect()
+    }
+
+    fn names(filter_files: &[&str]) -> Vec<&'static str> {
+        let filter_files = filter_files.iter().map(|f| f.to_string()).collect();
+        let opts = TestOpts { filter_files, ..TestOpts::new() };
+        filter_tests(&opts, tests())
+            .into_iter()
+            .map(|test| match test.desc.name {
+                StaticTestName(name) => name,
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    assert_eq!(names(&[]).len(), 8);
+    assert_eq!(names(&["src/lib.rs"]), ["lib"]);
+    assert_eq!(names(&["src/net/*"]), ["net_mod", "net_tcp", "windows_net"]);
+    assert_eq!(
+        names(&["src/net/**"]),
+        ["net_mod", "net_tcp", "net_tcp_listener", "windows_net"]
+    );
+    assert_eq!(names(&["src/net*"]), ["network"]);
+    assert_eq!(names(&["**/net.rs", "src/lib.?s"]), ["integration", "lib"]);
+    assert_eq!(names(&["src/**/tcp*.rs"]), ["net_tcp"]);
+    assert_eq!(names(&["**/*.rs"]).len(), 7);
+    assert!(names(&["src/net"]).is_empty());
+
+    let args = vec![
+        "progname".to_string(),
+        "--filter-file".to_string(),
+        "src/net/*".to_string(),
+        "--filter-file=tests/*".to_string(),
+        "-Zunstable-options".to_string(),
+    ];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert_eq!(opts.filter_files, ["src/net/*", "tests/*"]);
+
+    let args = vec!["progname".to_string(), "--filter-file=src/*".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+}
+
 #[test]
 pub fn exact_filter_match() {
     fn tests() -> Vec<TestDescAndFn> {
@@ -502,6 +1310,12 @@ fn tests() -> Vec<TestDescAndFn> {
                     compile_fail: false,
                     no_run: false,
                     test_type: TestType::Unknown,
+                    #[cfg(not(bootstrap))]
+                    metadata: &[],
+                    #[cfg(not(bootstrap))]
+                    resource: None,
+                    #[cfg(not(bootstrap))]
+                    source_file: None,
                 },
                 testfn: DynTestFn(Box::new(move || {})),
             })
@@ -566,16 +1380,246 @@ fn tests() -> Vec<TestDescAndFn> {
 }
 
 #[test]
-pub fn sort_tests() {
-    let mut opts = TestOpts::new();
-    opts.run_tests = true;
-
-    let names = vec![
-        "sha1::test".to_string(),
-        "isize::test_to_str".to_string(),
-        "isize::test_pow".to_string(),
-        "test::do_not_run_ignored_tests".to_string(),
-        "test::ignored_tests_result_in_ignored".to_string(),
+pub fn mixed_exact_and_substring_filters() {
+    fn tests() -> Vec<TestDescAndFn> {
+        vec!["base", "base::test", "base::test1", "other::test", "other::test_more"]
+            .into_iter()
+            .map(|name| TestDescAndFn {
+                desc: TestDesc {
+                    name: StaticTestName(name),
+                    ignore: false,
+                    should_panic: ShouldPanic::No,
+                    allow_fail: false,
+                    compile_fail: false,
+                    no_run: false,
+                    test_type: TestType::Unknown,
+                    #[cfg(not(bootstrap))]
+                    metadata: &[],
+                    #[cfg(not(bootstrap))]
+                    resource: None,
+                    #[cfg(not(bootstrap))]
+                    source_file: None,
+                },
+                testfn: DynTestFn(Box::new(move || {})),
+            })
+            .collect()
+    }
+    fn names(tests: Vec<TestDescAndFn>) -> Vec<String> {
+        tests.iter().map(|t| t.desc.name.as_slice().to_string()).collect()
+    }
+
+    let args = vec![
+        "progname".to_string(),
+        "=base".to_string(),
+        "other".to_string(),
+        "-Zunstable-options".to_string(),
+    ];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert_eq!(opts.filters, ["other"]);
+    assert_eq!(opts.exact_filters, ["base"]);
+    assert_eq!(names(filter_tests(&opts, tests())), ["base", "other::test", "other::test_more"]);
+
+    // `--exact` still applies to the filters without a `=` prefix.
+    let args = vec![
+        "progname".to_string(),
+        "=base::test".to_string(),
+        "other::test".to_string(),
+        "--exact".to_string(),
+        "-Zunstable-options".to_string(),
+    ];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert_eq!(names(filter_tests(&opts, tests())), ["base::test", "other::test"]);
+
+    let args = vec!["progname".to_string(), "=base".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+}
+
+#[test]
+fn zero_test_threads_runs_tests_on_the_main_thread() {
+    let args = vec![
+        "progname".to_string(),
+        "--test-threads".to_string(),
+        "0".to_string(),
+        "-Zunstable-options".to_string(),
+    ];
+    let mut opts = parse_opts(&args).unwrap().unwrap();
+    assert_eq!(opts.test_threads, Some(0));
+    opts.run_tests = true;
+
+    let threads = Arc::new(Mutex::new(Vec::new()));
+    let tests = (0..4)
+        .map(|i| {
+            let threads = threads.clone();
+            TestDescAndFn {
+                desc: TestDesc {
+                    name: DynTestName(format!("test{}", i)),
+                    ignore: false,
+                    should_panic: ShouldPanic::No,
+                    allow_fail: false,
+                    compile_fail: false,
+                    no_run: false,
+                    test_type: TestType::Unknown,
+                    #[cfg(not(bootstrap))]
+                    metadata: &[],
+                    #[cfg(not(bootstrap))]
+                    resource: None,
+                    #[cfg(not(bootstrap))]
+                    source_file: None,
+                },
+                testfn: DynTestFn(Box::new(move || {
+                    threads.lock().unwrap().push(thread::current().id())
+                })),
+            }
+        })
+        .collect();
+    run_tests(&opts, tests, |_| Ok(())).unwrap();
+
+    let threads = threads.lock().unwrap();
+    assert_eq!(threads.len(), 4);
+    assert!(threads.iter().all(|&id| id == thread::current().id()));
+
+    let args = vec!["progname".to_string(), "--test-threads".to_string(), "0".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+}
+
+#[test]
+fn parse_capture_combined_flag() {
+    let args = vec![
+        "progname".to_string(),
+        "--capture-combined".to_string(),
+        "-Zunstable-options".to_string(),
+    ];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert!(opts.capture_combined);
+
+    let args = vec!["progname".to_string(), "--capture-combined".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+}
+
+#[test]
+fn no_capture_on_success_keeps_output_of_failures_only() {
+    fn passes() {
+        println!("chatty but fine");
+    }
+    fn fails() {
+        println!("about to fail");
+        panic!("boom");
+    }
+    let desc = |name: &'static str, testfn: fn()| TestDescAndFn {
+        desc: TestDesc {
+            name: StaticTestName(name),
+            ignore: false,
+            should_panic: ShouldPanic::No,
+            allow_fail: false,
+            compile_fail: false,
+            no_run: false,
+            test_type: TestType::Unknown,
+            #[cfg(not(bootstrap))]
+            metadata: &[],
+            #[cfg(not(bootstrap))]
+            resource: None,
+            #[cfg(not(bootstrap))]
+            source_file: None,
+        },
+        testfn: DynTestFn(Box::new(testfn)),
+    };
+    let run = |opts: &TestOpts, test| {
+        let (tx, rx) = channel();
+        run_test(opts, false, TestId(0), test, RunStrategy::InProcess, tx, Concurrent::No);
+        rx.recv().unwrap()
+    };
+
+    let args = vec![
+        "progname".to_string(),
+        "--no-capture-on-success".to_string(),
+        "-Zunstable-options".to_string(),
+    ];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert!(opts.options.discard_passing_output);
+
+    let mut conflicting = args.clone();
+    conflicting.push("--show-output".to_string());
+    assert!(parse_opts(&conflicting).unwrap().is_err());
+    let stable = vec!["progname".to_string(), "--no-capture-on-success".to_string()];
+    assert!(parse_opts(&stable).unwrap().is_err());
+
+    let completed = run(&opts, desc("passes", passes));
+    assert_eq!(completed.result, TrOk);
+    assert!(completed.stdout.is_empty());
+
+    // Also when `test_main` is given `Options`, as the `panic=abort` runner does.
+    let options = Options::new().panic_abort(true);
+    let merged = parse_opts_with_options(&args, Some(options)).unwrap().unwrap();
+    assert!(merged.options.discard_passing_output);
+    assert!(run(&merged, desc("passes", passes)).stdout.is_empty());
+
+    let completed = run(&opts, desc("fails", fails));
+    assert_eq!(completed.result, TrFailed);
+    let stdout = String::from_utf8(completed.stdout).unwrap();
+    assert!(stdout.contains("about to fail\n"), "captured output: {:?}", stdout);
+
+    // Without the flag, passing tests keep their output.
+    assert_eq!(run(&TestOpts::new(), desc("passes", passes)).stdout, b"chatty but fine\n");
+}
+
+#[test]
+fn combine_subprocess_output_skips_empty_stderr_when_combined() {
+    let name = StaticTestName("a::b");
+    let output = combine_subprocess_output(b"out\nerr\n".to_vec(), b"", &name, true);
+    assert_eq!(output, b"out\nerr\n");
+
+    let output = combine_subprocess_output(b"out".to_vec(), b"abort", &name, true);
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.starts_with("out\n"), "output: {:?}", output);
+    assert!(output.ends_with("abort"), "output: {:?}", output);
+
+    let separate = combine_subprocess_output(b"out".to_vec(), b"", &name, false);
+    assert_ne!(separate, b"out");
+}
+
+#[test]
+fn failures_show_combined_output_if_captured() {
+    let desc = TestDesc {
+        name: StaticTestName("whatever"),
+        ignore: false,
+        should_panic: ShouldPanic::No,
+        allow_fail: false,
+        compile_fail: false,
+        no_run: false,
+        test_type: TestType::Unknown,
+        #[cfg(not(bootstrap))]
+        metadata: &[],
+        #[cfg(not(bootstrap))]
+        resource: None,
+        #[cfg(not(bootstrap))]
+        source_file: None,
+    };
+    let separated = b"13\n---- whatever stderr ----\n24\n".to_vec();
+    let mut completed =
+        CompletedTest::new(TestId(0), desc, TrFailed, None, None, separated.clone());
+    completed.combined_output = Some(b"1234\n".to_vec());
+
+    let mut st = console::ConsoleTestState::new(&TestOpts::new()).unwrap();
+    console::handle_test_result(&mut st, completed.clone());
+    assert_eq!(st.failures[0].1, b"1234\n");
+
+    completed.combined_output = None;
+    let mut st = console::ConsoleTestState::new(&TestOpts::new()).unwrap();
+    console::handle_test_result(&mut st, completed);
+    assert_eq!(st.failures[0].1, separated);
+}
+
+#[test]
+pub fn sort_tests() {
+    let mut opts = TestOpts::new();
+    opts.run_tests = true;
+
+    let names = vec![
+        "sha1::test".to_string(),
+        "isize::test_to_str".to_string(),
+        "isize::test_pow".to_string(),
+        "test::do_not_run_ignored_tests".to_string(),
+        "test::ignored_tests_result_in_ignored".to_string(),
         "test::first_free_arg_should_be_a_filter".to_string(),
         "test::parse_ignored_flag".to_string(),
         "test::parse_include_ignored_flag".to_string(),
@@ -596,6 +1640,12 @@ fn testfn() {}
                     compile_fail: false,
                     no_run: false,
                     test_type: TestType::Unknown,
+                    #[cfg(not(bootstrap))]
+                    metadata: &[],
+                    #[cfg(not(bootstrap))]
+                    resource: None,
+                    #[cfg(not(bootstrap))]
+                    source_file: None,
                 },
                 testfn: DynTestFn(Box::new(testfn)),
             };
@@ -624,6 +1674,54 @@ fn testfn() {}
     }
 }
 
+#[test]
+fn compare_benchmarks_to_baseline() {
+    let mut baseline = MetricMap::new();
+    baseline.insert_metric("fast", 1000.0, 10.0);
+    baseline.insert_metric("steady", 500.0, 5.0);
+    baseline.insert_metric("with \"quotes\"", 100.0, 1.0);
+
+    // The baseline goes through a file, like it would between two runs.
+    let path = std::env::temp_dir().join(format!("libtest-baseline-{}.json", std::process::id()));
+    baseline.save(&path).unwrap();
+    let loaded = MetricMap::load(&path);
+    std::fs::remove_file(&path).unwrap();
+    let baseline = loaded.unwrap();
+
+    let mut current = MetricMap::new();
+    current.insert_metric("fast", 900.0, 10.0);
+    current.insert_metric("steady", 600.0, 5.0);
+    current.insert_metric("with \"quotes\"", 100.0, 1.0);
+    current.insert_metric("new", 42.0, 1.0);
+    assert_eq!(current.ratio_of("steady", &baseline), Some(1.2));
+    assert_eq!(current.ratio_of("new", &baseline), None);
+
+    let compare = |threshold| {
+        let mut out = Vec::new();
+        let ok = console::compare_to_baseline(&current, &baseline, threshold, &mut out).unwrap();
+        (ok, String::from_utf8(out).unwrap())
+    };
+
+    let (ok, report) = compare(None);
+    assert!(ok);
+    assert_eq!(
+        report,
+        "benchmarks compared to baseline:\n    \
+         fast: -10.00%\n    \
+         new: no baseline\n    \
+         steady: +20.00%\n    \
+         with \"quotes\": +0.00%\n"
+    );
+
+    let (ok, report) = compare(Some(10.0));
+    assert!(!ok);
+    assert!(report.contains("    steady: +20.00% REGRESSED\n"), "{}", report);
+    assert!(report.contains("    fast: -10.00%\n"), "{}", report);
+
+    let (ok, _) = compare(Some(25.0));
+    assert!(ok);
+}
+
 #[test]
 pub fn test_metricmap_compare() {
     let mut m1 = MetricMap::new();
@@ -675,9 +1773,15 @@ fn f(_: &mut Bencher) {}
         compile_fail: false,
         no_run: false,
         test_type: TestType::Unknown,
+        #[cfg(not(bootstrap))]
+        metadata: &[],
+        #[cfg(not(bootstrap))]
+        resource: None,
+        #[cfg(not(bootstrap))]
+        source_file: None,
     };
 
-    crate::bench::benchmark(TestId(0), desc, tx, true, f);
+    crate::bench::benchmark(TestId(0), desc, tx, true, Options::new(), f);
     rx.recv().unwrap();
 }
 
@@ -697,12 +1801,42 @@ fn f(b: &mut Bencher) {
         compile_fail: false,
         no_run: false,
         test_type: TestType::Unknown,
+        #[cfg(not(bootstrap))]
+        metadata: &[],
+        #[cfg(not(bootstrap))]
+        resource: None,
+        #[cfg(not(bootstrap))]
+        source_file: None,
     };
 
-    crate::bench::benchmark(TestId(0), desc, tx, true, f);
+    crate::bench::benchmark(TestId(0), desc, tx, true, Options::new(), f);
     rx.recv().unwrap();
 }
 
+#[test]
+pub fn test_bench_time_limit() {
+    // Unbounded, this would take 50 samples of at least 20ms each in the
+    // first round alone.
+    let mut calls = 0;
+    let mut slow = || {
+        calls += 1;
+        std::thread::sleep(Duration::from_millis(20));
+    };
+
+    let start = Instant::now();
+    let summ = bench::iter_bounded(&mut slow, 50, Some(Duration::from_millis(100)));
+    let elapsed = start.elapsed();
+
+    assert!(elapsed >= Duration::from_millis(100));
+    // Every call takes at least 20ms, so the limit is reached after at most
+    // five of them, however slow the machine is.
+    assert!(calls <= 5, "benchmark ran {} times", calls);
+    // The summary only covers the few samples taken before the limit.
+    let samples_taken = (summ.sum / summ.mean).round() as usize;
+    assert!(samples_taken < 50, "took {} samples", samples_taken);
+    assert!(summ.min >= Duration::from_millis(20).as_nanos() as f64);
+}
+
 #[test]
 fn should_sort_failures_before_printing_them() {
     let test_a = TestDesc {
@@ -713,6 +1847,12 @@ fn should_sort_failures_before_printing_them() {
         compile_fail: false,
         no_run: false,
         test_type: TestType::Unknown,
+        #[cfg(not(bootstrap))]
+        metadata: &[],
+        #[cfg(not(bootstrap))]
+        resource: None,
+        #[cfg(not(bootstrap))]
+        source_file: None,
     };
 
     let test_b = TestDesc {
@@ -723,6 +1863,12 @@ fn should_sort_failures_before_printing_them() {
         compile_fail: false,
         no_run: false,
         test_type: TestType::Unknown,
+        #[cfg(not(bootstrap))]
+        metadata: &[],
+        #[cfg(not(bootstrap))]
+        resource: None,
+        #[cfg(not(bootstrap))]
+        source_file: None,
     };
 
     let mut out = PrettyFormatter::new(OutputLocation::Raw(Vec::new()), false, 10, false, None);
@@ -742,6 +1888,9 @@ fn should_sort_failures_before_printing_them() {
         options: Options::new(),
         not_failures: Vec::new(),
         time_failures: Vec::new(),
+        ignored_tests: None,
+        shuffle_seed: None,
+        leaked_threads: None,
     };
 
     out.write_failures(&st).unwrap();
@@ -754,3 +1903,668 @@ fn should_sort_failures_before_printing_them() {
     let bpos = s.find("b").unwrap();
     assert!(apos < bpos);
 }
+
+#[test]
+fn show_output_prints_stdout_of_passing_tests() {
+    fn run(extra_args: &[&str]) -> String {
+        let mut args = vec!["progname".to_string(), "--test-threads=1".to_string()];
+        args.extend(extra_args.iter().map(|arg| arg.to_string()));
+        let opts = parse_opts(&args).unwrap().unwrap();
+        let tests = vec![TestDescAndFn {
+            desc: TestDesc {
+                name: StaticTestName("chatty"),
+                ignore: false,
+                should_panic: ShouldPanic::No,
+                allow_fail: false,
+                compile_fail: false,
+                no_run: false,
+                test_type: TestType::Unknown,
+                #[cfg(not(bootstrap))]
+                metadata: &[],
+                #[cfg(not(bootstrap))]
+                resource: None,
+                #[cfg(not(bootstrap))]
+                source_file: None,
+            },
+            testfn: DynTestFn(Box::new(|| println!("some diagnostic info"))),
+        }];
+
+        let mut st = console::ConsoleTestState::new(&opts).unwrap();
+        let mut out = PrettyFormatter::new(OutputLocation::Raw(Vec::new()), false, 10, false, None);
+        run_tests(&opts, tests, |event| console::on_test_event(&event, &mut st, &mut out))
+            .unwrap();
+        assert!(out.write_run_finish(&st).unwrap());
+
+        match out.output_location() {
+            &OutputLocation::Raw(ref m) => String::from_utf8_lossy(&m[..]).into_owned(),
+            &OutputLocation::Pretty(_) => unreachable!(),
+        }
+    }
+
+    let s = run(&["--show-output"]);
+    assert!(s.contains("successes:"), "{}", s);
+    assert!(s.contains("---- chatty stdout ----\nsome diagnostic info\n"), "{}", s);
+
+    let s = run(&[]);
+    assert!(!s.contains("successes:"), "{}", s);
+    assert!(!s.contains("some diagnostic info"), "{}", s);
+}
+
+#[test]
+fn json_formatter_reports_timeout() {
+    let desc = TestDesc {
+        name: StaticTestName("slow"),
+        ignore: false,
+        should_panic: ShouldPanic::No,
+        allow_fail: false,
+        compile_fail: false,
+        no_run: false,
+        test_type: TestType::Unknown,
+        #[cfg(not(bootstrap))]
+        metadata: &[],
+        #[cfg(not(bootstrap))]
+        resource: None,
+        #[cfg(not(bootstrap))]
+        source_file: None,
+    };
+
+    let mut st = console::ConsoleTestState::new(&TestOpts::new()).unwrap();
+    let mut out = JsonFormatter::new(OutputLocation::Raw(Vec::new()));
+    console::on_test_event(&TestEvent::TeTimeout(desc), &mut st, &mut out).unwrap();
+
+    let s = match out.output_location() {
+        &OutputLocation::Raw(ref m) => String::from_utf8_lossy(&m[..]),
+        &OutputLocation::Pretty(_) => unreachable!(),
+    };
+    assert_eq!(s, "{ \"type\": \"test\", \"event\": \"timeout\", \"name\": \"slow\" }\n");
+    // A timeout is only a warning, the test hasn't failed (yet).
+    assert_eq!(st.failed, 0);
+}
+
+#[test]
+fn json_formatter_reports_bench_results() {
+    fn f(b: &mut Bencher) {
+        b.iter(|| {})
+    }
+
+    let args = vec!["progname".to_string(), "--bench".to_string()];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert!(opts.bench_benchmarks);
+    let tests = vec![TestDescAndFn {
+        desc: TestDesc {
+            name: StaticTestName("bench_nothing"),
+            ignore: false,
+            should_panic: ShouldPanic::No,
+            allow_fail: false,
+            compile_fail: false,
+            no_run: false,
+            test_type: TestType::Unknown,
+            #[cfg(not(bootstrap))]
+            metadata: &[("owner", "libs")],
+            #[cfg(not(bootstrap))]
+            resource: None,
+            #[cfg(not(bootstrap))]
+            source_file: None,
+        },
+        testfn: StaticBenchFn(f),
+    }];
+
+    let mut st = console::ConsoleTestState::new(&opts).unwrap();
+    let mut out = JsonFormatter::new(OutputLocation::Raw(Vec::new()));
+    run_tests(&opts, tests, |event| console::on_test_event(&event, &mut st, &mut out)).unwrap();
+
+    let s = match out.output_location() {
+        &OutputLocation::Raw(ref m) => String::from_utf8_lossy(&m[..]).into_owned(),
+        &OutputLocation::Pretty(_) => unreachable!(),
+    };
+    let bench = s.lines().find(|line| line.starts_with(r#"{ "type": "bench""#));
+    let bench = bench.unwrap_or_else(|| panic!("no bench result in {:?}", s));
+    let prefix = r#"{ "type": "bench", "name": "bench_nothing", "median": "#;
+    assert!(bench.starts_with(prefix), "{}", bench);
+    assert!(bench.contains(r#", "deviation": "#), "{}", bench);
+    #[cfg(not(bootstrap))]
+    assert!(bench.ends_with(r#", "metadata": { "owner": "libs" } }"#), "{}", bench);
+    assert!(!s.contains(r#"{ "type": "test", "name": "bench_nothing""#), "{}", s);
+}
+
+#[test]
+fn reported_metrics_are_attached_to_the_result() {
+    fn f() {
+        report_metric("allocations", 3.0, 0.0);
+        report_metric("size \"bytes\"", 128.0, 1.5);
+        report_metric("allocations", 4.0, 0.0);
+    }
+
+    let desc = TestDescAndFn {
+        desc: TestDesc {
+            name: StaticTestName("measured"),
+            ignore: false,
+            should_panic: ShouldPanic::No,
+            allow_fail: false,
+            compile_fail: false,
+            no_run: false,
+            test_type: TestType::Unknown,
+            #[cfg(not(bootstrap))]
+            metadata: &[],
+            #[cfg(not(bootstrap))]
+            resource: None,
+            #[cfg(not(bootstrap))]
+            source_file: None,
+        },
+        testfn: DynTestFn(Box::new(f)),
+    };
+    let (tx, rx) = channel();
+    run_test(&TestOpts::new(), false, TestId(0), desc, RunStrategy::InProcess, tx, Concurrent::No);
+    let completed_test = rx.recv().unwrap();
+    assert_eq!(completed_test.result, TrOk);
+
+    let mut expected = MetricMap::new();
+    expected.insert_metric("allocations", 4.0, 0.0);
+    expected.insert_metric("size \"bytes\"", 128.0, 1.5);
+    assert_eq!(completed_test.metrics, expected);
+
+    let mut st = console::ConsoleTestState::new(&TestOpts::new()).unwrap();
+    let mut out = JsonFormatter::new(OutputLocation::Raw(Vec::new()));
+    console::on_test_event(&TestEvent::TeResult(completed_test), &mut st, &mut out).unwrap();
+    let s = match out.output_location() {
+        &OutputLocation::Raw(ref m) => String::from_utf8_lossy(&m[..]).into_owned(),
+        &OutputLocation::Pretty(_) => unreachable!(),
+    };
+    assert_eq!(
+        s,
+        concat!(
+            r#"{ "type": "test", "name": "measured", "event": "ok", "metrics": { "#,
+            r#""allocations": { "value": 4, "noise": 0 }, "#,
+            r#""size \"bytes\"": { "value": 128, "noise": 1.5 } } }"#,
+            "\n"
+        )
+    );
+}
+
+#[test]
+fn metrics_do_not_leak_into_later_tests() {
+    fn measured() {
+        report_metric("count", 1.0, 0.0);
+    }
+    fn unmeasured() {}
+
+    let (tx, rx) = channel();
+    for (name, f) in [("measured", measured as fn()), ("unmeasured", unmeasured)] {
+        let desc = TestDescAndFn {
+            desc: TestDesc {
+                name: StaticTestName(name),
+                ignore: false,
+                should_panic: ShouldPanic::No,
+                allow_fail: false,
+                compile_fail: false,
+                no_run: false,
+                test_type: TestType::Unknown,
+                #[cfg(not(bootstrap))]
+                metadata: &[],
+                #[cfg(not(bootstrap))]
+                resource: None,
+                #[cfg(not(bootstrap))]
+                source_file: None,
+            },
+            testfn: DynTestFn(Box::new(f)),
+        };
+        let (opts, strategy) = (TestOpts::new(), RunStrategy::InProcess);
+        run_test(&opts, false, TestId(0), desc, strategy, tx.clone(), Concurrent::No);
+    }
+    assert!(!rx.recv().unwrap().metrics.is_empty());
+    assert!(rx.recv().unwrap().metrics.is_empty());
+}
+
+#[test]
+#[cfg(not(bootstrap))]
+fn test_metadata_is_preserved_and_printed_as_json() {
+    let desc = TestDesc {
+        name: StaticTestName("tagged"),
+        ignore: false,
+        should_panic: ShouldPanic::No,
+        allow_fail: false,
+        compile_fail: false,
+        no_run: false,
+        test_type: TestType::Unknown,
+        #[cfg(not(bootstrap))]
+        metadata: &[("owner", "libs"), ("tag", "say \"slow\"")],
+        #[cfg(not(bootstrap))]
+        resource: None,
+        #[cfg(not(bootstrap))]
+        source_file: None,
+    };
+    let cloned = desc.clone();
+    assert_eq!(cloned.metadata, desc.metadata);
+
+    let expected_metadata = r#", "metadata": { "owner": "libs", "tag": "say \"slow\"" }"#;
+
+    let mut st = console::ConsoleTestState::new(&TestOpts::new()).unwrap();
+    let mut out = JsonFormatter::new(OutputLocation::Raw(Vec::new()));
+    let events = [
+        TestEvent::TeWait(cloned.clone()),
+        TestEvent::TeResult(CompletedTest::new(TestId(0), cloned, TrOk, None, None, Vec::new())),
+    ];
+    for event in &events {
+        console::on_test_event(event, &mut st, &mut out).unwrap();
+    }
+    let s = match out.output_location() {
+        &OutputLocation::Raw(ref m) => String::from_utf8_lossy(&m[..]).into_owned(),
+        &OutputLocation::Pretty(_) => unreachable!(),
+    };
+    let lines: Vec<_> = s.lines().collect();
+    assert_eq!(lines.len(), 2, "{}", s);
+    assert_eq!(
+        lines[0],
+        format!(
+            r#"{{ "type": "test", "event": "started", "name": "tagged"{} }}"#,
+            expected_metadata
+        )
+    );
+    assert_eq!(
+        lines[1],
+        format!(r#"{{ "type": "test", "name": "tagged", "event": "ok"{} }}"#, expected_metadata)
+    );
+
+    let mut opts = TestOpts::new();
+    opts.list = true;
+    opts.format = OutputFormat::Json;
+    let tests = vec![TestDescAndFn { desc, testfn: DynTestFn(Box::new(move || {})) }];
+    let mut output = OutputLocation::Raw(Vec::new());
+    list_tests(&mut output, &opts, tests).unwrap();
+    let s = match output {
+        OutputLocation::Raw(ref m) => String::from_utf8_lossy(m).into_owned(),
+        OutputLocation::Pretty(_) => unreachable!(),
+    };
+    assert_eq!(
+        s,
+        format!(
+            "{{ \"type\": \"test\", \"event\": \"discovered\", \"name\": \"tagged\"{} }}\n",
+            expected_metadata
+        )
+    );
+}
+
+fn named_tests(names: &[&str]) -> Vec<TestDescAndFn> {
+    names
+        .iter()
+        .map(|name| TestDescAndFn {
+            desc: TestDesc {
+                name: DynTestName(name.to_string()),
+                ignore: false,
+                should_panic: ShouldPanic::No,
+                allow_fail: false,
+                compile_fail: false,
+                no_run: false,
+                test_type: TestType::Unknown,
+                #[cfg(not(bootstrap))]
+                metadata: &[],
+                #[cfg(not(bootstrap))]
+                resource: None,
+                #[cfg(not(bootstrap))]
+                source_file: None,
+            },
+            testfn: DynTestFn(Box::new(move || {})),
+        })
+        .collect()
+}
+
+/// Runs `tests` on a single thread and returns the reported shuffle seed along
+/// with the order in which the tests were started.
+fn run_order(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> (Option<u64>, Vec<String>) {
+    let mut seed = None;
+    let mut order = Vec::new();
+    run_tests(opts, tests, |event| {
+        match event {
+            TestEvent::TeFiltered(_, shuffle_seed) => seed = shuffle_seed,
+            TestEvent::TeWait(desc) => order.push(desc.name.as_slice().trim_end().to_string()),
+            _ => {}
+        }
+        Ok(())
+    })
+    .unwrap();
+    (seed, order)
+}
+
+#[test]
+fn reported_shuffle_seed_reproduces_order() {
+    let names: Vec<String> = (0..32).map(|i| format!("test_{:02}", i)).collect();
+    let names: Vec<&str> = names.iter().map(|s| &**s).collect();
+
+    let opts = TestOpts { shuffle: true, test_threads: Some(1), ..TestOpts::new() };
+    let (seed, order) = run_order(&opts, named_tests(&names));
+    let seed = seed.expect("a shuffled run must report its seed");
+
+    let mut sorted = order.clone();
+    sorted.sort();
+    assert_eq!(sorted, names);
+
+    // Feeding the reported seed back in gives the same order, every time.
+    let opts = TestOpts { shuffle_seed: Some(seed), test_threads: Some(1), ..TestOpts::new() };
+    for _ in 0..3 {
+        assert_eq!(run_order(&opts, named_tests(&names)), (Some(seed), order.clone()));
+    }
+
+    // Unshuffled runs report no seed and keep the sorted order.
+    let opts = TestOpts { test_threads: Some(1), ..TestOpts::new() };
+    assert_eq!(run_order(&opts, named_tests(&names)), (None, sorted));
+}
+
+#[test]
+fn shuffle_tests_is_deterministic() {
+    let names = ["a", "b", "c", "d", "e", "f", "g", "h"];
+    let shuffled = |seed| {
+        let mut tests: Vec<_> =
+            named_tests(&names).into_iter().enumerate().map(|(i, t)| (TestId(i), t)).collect();
+        shuffle_tests(seed, &mut tests);
+        tests.into_iter().map(|(id, _)| id).collect::<Vec<_>>()
+    };
+    assert_eq!(shuffled(12345), shuffled(12345));
+    assert_ne!(shuffled(1), shuffled(2));
+}
+
+#[test]
+fn failed_shuffled_run_reports_seed() {
+    let desc = TestDesc {
+        name: StaticTestName("flaky"),
+        ignore: false,
+        should_panic: ShouldPanic::No,
+        allow_fail: false,
+        compile_fail: false,
+        no_run: false,
+        test_type: TestType::Unknown,
+        #[cfg(not(bootstrap))]
+        metadata: &[],
+        #[cfg(not(bootstrap))]
+        resource: None,
+        #[cfg(not(bootstrap))]
+        source_file: None,
+    };
+    let events = [
+        TestEvent::TeFiltered(vec![desc.clone()], Some(12345)),
+        TestEvent::TeResult(CompletedTest::new(
+            TestId(0),
+            desc,
+            TrFailed,
+            Some(FailureReason::Panic),
+            None,
+            Vec::new(),
+        )),
+    ];
+
+    let mut st = console::ConsoleTestState::new(&TestOpts::new()).unwrap();
+    let mut out = PrettyFormatter::new(OutputLocation::Raw(Vec::new()), false, 10, false, None);
+    for event in &events {
+        console::on_test_event(event, &mut st, &mut out).unwrap();
+    }
+    assert!(!out.write_run_finish(&st).unwrap());
+    let s = match out.output_location() {
+        &OutputLocation::Raw(ref m) => String::from_utf8_lossy(&m[..]).into_owned(),
+        &OutputLocation::Pretty(_) => unreachable!(),
+    };
+    assert!(s.contains("rerun with `-Z unstable-options --shuffle-seed 12345`"), "{}", s);
+
+    let mut st = console::ConsoleTestState::new(&TestOpts::new()).unwrap();
+    let mut out = JsonFormatter::new(OutputLocation::Raw(Vec::new()));
+    for event in &events {
+        console::on_test_event(event, &mut st, &mut out).unwrap();
+    }
+    assert!(!out.write_run_finish(&st).unwrap());
+    let s = match out.output_location() {
+        &OutputLocation::Raw(ref m) => String::from_utf8_lossy(&m[..]).into_owned(),
+        &OutputLocation::Pretty(_) => unreachable!(),
+    };
+    let finish = s.lines().last().unwrap();
+    assert!(finish.starts_with(r#"{ "type": "suite", "event": "failed""#), "{}", finish);
+    assert!(finish.ends_with(r#", "shuffle_seed": 12345 }"#), "{}", finish);
+}
+
+#[test]
+fn failure_reason_for_each_way_a_test_can_fail() {
+    use crate::test_result::{calc_result, get_result_from_exit_code, TR_FAILED, TR_OK};
+
+    fn desc(should_panic: ShouldPanic) -> TestDesc {
+        TestDesc {
+            name: StaticTestName("whatever"),
+            ignore: false,
+            should_panic,
+            allow_fail: false,
+            compile_fail: false,
+            no_run: false,
+            test_type: TestType::UnitTest,
+            #[cfg(not(bootstrap))]
+            metadata: &[],
+            #[cfg(not(bootstrap))]
+            resource: None,
+            #[cfg(not(bootstrap))]
+            source_file: None,
+        }
+    }
+    let payload: Box<dyn std::any::Any + Send> = Box::new("boom");
+
+    // Run in process.
+    let no_panic = desc(ShouldPanic::No);
+    assert_eq!(calc_result(&no_panic, Ok(()), &None, &None), (TrOk, None));
+    assert_eq!(
+        calc_result(&no_panic, Err(&*payload), &None, &None),
+        (TrFailed, Some(FailureReason::Panic))
+    );
+    let (_, reason) = calc_result(&desc(ShouldPanic::Yes), Ok(()), &None, &None);
+    assert_eq!(reason, Some(FailureReason::ShouldPanicMismatch));
+    let wrong_message = desc(ShouldPanic::YesWithMessage("bang"));
+    let (_, reason) = calc_result(&wrong_message, Err(&*payload), &None, &None);
+    assert_eq!(reason, Some(FailureReason::ShouldPanicMismatch));
+
+    // `Default` will initialize all the thresholds to 0 milliseconds.
+    let mut time_options = TestTimeOptions::default();
+    time_options.error_on_excess = true;
+    let time_options = Some(time_options);
+    let exec_time = Some(TestExecTime(Duration::from_millis(10)));
+    assert_eq!(
+        calc_result(&no_panic, Ok(()), &time_options, &exec_time),
+        (TestResult::TrTimedFail, Some(FailureReason::Timeout))
+    );
+
+    // Run in a subprocess.
+    assert_eq!(get_result_from_exit_code(&no_panic, TR_OK, &None, &None), (TrOk, None));
+    assert_eq!(
+        get_result_from_exit_code(&no_panic, TR_FAILED, &None, &None),
+        (TrFailed, Some(FailureReason::Panic))
+    );
+    let (_, reason) = get_result_from_exit_code(&desc(ShouldPanic::Yes), TR_FAILED, &None, &None);
+    assert_eq!(reason, Some(FailureReason::ShouldPanicMismatch));
+    let (_, reason) = get_result_from_exit_code(&no_panic, 134, &None, &None);
+    assert_eq!(reason, Some(FailureReason::NonZeroExit));
+    assert_eq!(
+        get_result_from_exit_code(&no_panic, TR_OK, &time_options, &exec_time),
+        (TestResult::TrTimedFail, Some(FailureReason::Timeout))
+    );
+}
+
+#[test]
+fn failure_reason_is_printed_as_json() {
+    let desc = TestDesc {
+        name: StaticTestName("broken"),
+        ignore: false,
+        should_panic: ShouldPanic::No,
+        allow_fail: false,
+        compile_fail: false,
+        no_run: false,
+        test_type: TestType::Unknown,
+        #[cfg(not(bootstrap))]
+        metadata: &[],
+        #[cfg(not(bootstrap))]
+        resource: None,
+        #[cfg(not(bootstrap))]
+        source_file: None,
+    };
+    let cases = [
+        (TrFailed, FailureReason::Panic, r#""reason_code": "panic""#),
+        (
+            TestResult::TrTimedFail,
+            FailureReason::Timeout,
+            r#""reason": "time limit exceeded", "reason_code": "timeout""#,
+        ),
+        (
+            TrFailedMsg("did not panic".to_string()),
+            FailureReason::ShouldPanicMismatch,
+            r#""message": "did not panic", "reason_code": "should_panic_mismatch""#,
+        ),
+        (TrFailed, FailureReason::SpawnFailure, r#""reason_code": "spawn_failure""#),
+        (
+            TrFailedMsg("got unexpected return code 134".to_string()),
+            FailureReason::NonZeroExit,
+            r#""message": "got unexpected return code 134", "reason_code": "nonzero_exit""#,
+        ),
+    ];
+    for (result, reason, expected) in cases.iter().cloned() {
+        let mut st = console::ConsoleTestState::new(&TestOpts::new()).unwrap();
+        let mut out = JsonFormatter::new(OutputLocation::Raw(Vec::new()));
+        let completed =
+            CompletedTest::new(TestId(0), desc.clone(), result, Some(reason), None, vec![]);
+        console::on_test_event(&TestEvent::TeResult(completed), &mut st, &mut out).unwrap();
+        let s = match out.output_location() {
+            &OutputLocation::Raw(ref m) => String::from_utf8_lossy(&m[..]).into_owned(),
+            &OutputLocation::Pretty(_) => unreachable!(),
+        };
+        assert_eq!(
+            s,
+            format!(r#"{{ "type": "test", "name": "broken", "event": "failed", {} }}"#, expected)
+                + "\n"
+        );
+    }
+}
+
+#[test]
+fn parse_json_pretty_flag() {
+    let args = |extra: &[&str]| {
+        let mut args = vec!["progname".to_string(), "-Zunstable-options".to_string()];
+        args.extend(extra.iter().map(|s| s.to_string()));
+        args
+    };
+    let opts = parse_opts(&args(&["--format", "json", "--json-pretty"])).unwrap().unwrap();
+    assert!(opts.options.json_pretty);
+    let opts = parse_opts(&args(&["--format", "json"])).unwrap().unwrap();
+    assert!(!opts.options.json_pretty);
+
+    assert!(parse_opts(&args(&["--json-pretty"])).unwrap().is_err());
+    assert!(parse_opts(&args(&["--format", "terse", "--json-pretty"])).unwrap().is_err());
+    let stable = vec!["progname".to_string(), "--json-pretty".to_string()];
+    assert!(parse_opts(&stable).unwrap().is_err());
+}
+
+#[test]
+fn test_main_keeps_command_line_options() {
+    let args: Vec<_> = ["progname", "-Zunstable-options", "--format", "json", "--json-pretty"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    // The `panic=abort` runner passes `Options` of its own.
+    let options = Options::new().panic_abort(true).failure_exit_code(Some(2));
+    let opts = parse_opts_with_options(&args, Some(options)).unwrap().unwrap();
+    assert!(opts.options.json_pretty);
+    assert!(opts.options.panic_abort);
+    assert_eq!(opts.options.failure_exit_code, Some(2));
+
+    let mut args = args;
+    args.push("--show-output".to_string());
+    let opts = parse_opts_with_options(&args, Some(Options::new())).unwrap().unwrap();
+    assert!(opts.options.display_output);
+    assert!(!opts.options.panic_abort);
+}
+
+#[test]
+fn json_pretty_output_has_the_same_structure() {
+    // Drops the whitespace outside of strings, so that compact and pretty
+    // output can be compared.
+    fn strip_whitespace(json: &str) -> String {
+        let mut stripped = String::new();
+        let (mut in_string, mut escaped) = (false, false);
+        for c in json.chars() {
+            if in_string {
+                in_string = escaped || c != '"';
+                escaped = !escaped && c == '\\';
+            } else if c.is_whitespace() {
+                continue;
+            } else {
+                in_string = c == '"';
+            }
+            stripped.push(c);
+        }
+        stripped
+    }
+
+    let desc = |name| TestDesc {
+        name: StaticTestName(name),
+        ignore: false,
+        should_panic: ShouldPanic::No,
+        allow_fail: false,
+        compile_fail: false,
+        no_run: false,
+        test_type: TestType::Unknown,
+        #[cfg(not(bootstrap))]
+        metadata: &[("owner", "libs"), ("tricky", "{ \"a\": [1, 2] }, :")],
+        #[cfg(not(bootstrap))]
+        resource: None,
+        #[cfg(not(bootstrap))]
+        source_file: None,
+    };
+    let events = [
+        TestEvent::TeFiltered(vec![desc("passes"), desc("fails")], None),
+        TestEvent::TeWait(desc("passes")),
+        TestEvent::TeResult(CompletedTest::new(
+            TestId(0),
+            desc("passes"),
+            TrOk,
+            None,
+            None,
+            Vec::new(),
+        )),
+        TestEvent::TeWait(desc("fails")),
+        TestEvent::TeResult(CompletedTest::new(
+            TestId(1),
+            desc("fails"),
+            TrFailedMsg("expected `[]`, got `{}`".to_string()),
+            Some(FailureReason::Panic),
+            None,
+            b"line one\n\t\"quoted\", [braces] {}\\".to_vec(),
+        )),
+    ];
+    let run = |pretty| {
+        let mut st = console::ConsoleTestState::new(&TestOpts::new()).unwrap();
+        let mut out = JsonFormatter::new(OutputLocation::Raw(Vec::new())).pretty(pretty);
+        for event in &events {
+            console::on_test_event(event, &mut st, &mut out).unwrap();
+        }
+        assert!(!out.write_run_finish(&st).unwrap());
+        match out.output_location() {
+            &OutputLocation::Raw(ref m) => String::from_utf8(m.clone()).unwrap(),
+            &OutputLocation::Pretty(_) => unreachable!(),
+        }
+    };
+
+    let compact = run(false);
+    let pretty = run(true);
+    assert_eq!(compact.lines().count(), 6);
+    assert!(pretty.lines().count() > 6 * 3);
+    assert_eq!(strip_whitespace(&pretty), strip_whitespace(&compact));
+    assert!(
+        pretty.starts_with(concat!(
+            "{\n",
+            "  \"type\": \"suite\",\n",
+            "  \"event\": \"started\",\n",
+            "  \"test_count\": 2\n",
+            "}\n",
+            "{\n",
+            "  \"type\": \"test\",\n",
+            "  \"event\": \"started\",\n",
+            "  \"name\": \"passes\",\n",
+            "  \"metadata\": {\n",
+            "    \"owner\": \"libs\",\n",
+            "    \"tricky\": \"{ \\\"a\\\": [1, 2] }, :\"\n",
+            "  }\n",
+            "}\n",
+        )),
+        "{}",
+        pretty
+    );
+}
@@ -71,6 +71,16 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// The measured CPU time (user + system) consumed by a unit test.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestCpuTime(pub Duration);
+
+impl fmt::Display for TestCpuTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cpu {:.3}s", self.0.as_secs_f64())
+    }
+}
+
 /// The measured execution time of the whole test suite.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct TestSuiteExecTime(pub Duration);
@@ -127,6 +127,21 @@ pub struct TestDesc {
     pub compile_fail: bool,
     pub no_run: bool,
     pub test_type: TestType,
+    /// Arbitrary key/value pairs attached to the test by the code that
+    /// declared it, e.g. tags or owners for a custom test framework. libtest
+    /// doesn't interpret these, it only carries them along and prints them
+    /// in the JSON output.
+    #[cfg(not(bootstrap))]
+    pub metadata: &'static [(&'static str, &'static str)],
+    /// Shared resource the test needs exclusive access to, set with
+    /// `#[test(resource = "...")]`. Tests that name the same resource don't
+    /// run at the same time, unless `--resource-limit` allows more of them.
+    #[cfg(not(bootstrap))]
+    pub resource: Option<&'static str>,
+    /// Path of the source file the test is defined in, as `file!()` would
+    /// expand to there. Filtered on by `--filter-file`.
+    #[cfg(not(bootstrap))]
+    pub source_file: Option<&'static str>,
 }
 
 impl TestDesc {
@@ -143,6 +158,47 @@ pub fn padded_name(&self, column_count: usize, align: NamePadding) -> String {
         }
     }
 
+    /// The test's metadata. The bootstrap compiler's `#[test]` doesn't fill
+    /// in `metadata` yet, so tests built with it never have any.
+    pub(crate) fn metadata(&self) -> &'static [(&'static str, &'static str)] {
+        #[cfg(not(bootstrap))]
+        {
+            self.metadata
+        }
+        #[cfg(bootstrap)]
+        {
+            &[]
+        }
+    }
+
+    /// The shared resource the test needs exclusive access to, if any. The
+    /// bootstrap compiler's `#[test]` doesn't fill in `resource` yet, so tests
+    /// built with it never have one.
+    pub(crate) fn resource(&self) -> Option<&'static str> {
+        #[cfg(not(bootstrap))]
+        {
+            self.resource
+        }
+        #[cfg(bootstrap)]
+        {
+            None
+        }
+    }
+
+    /// Path of the source file the test is defined in, if known. The bootstrap
+    /// compiler's `#[test]` doesn't fill in `source_file` yet, so tests built
+    /// with it never have one.
+    pub(crate) fn source_file(&self) -> Option<&'static str> {
+        #[cfg(not(bootstrap))]
+        {
+            self.source_file
+        }
+        #[cfg(bootstrap)]
+        {
+            None
+        }
+    }
+
     /// Returns None for ignored test or that that are just run, otherwise give a description of the type of test.
     /// Descriptions include "should panic", "compile fail" and "compile".
     pub fn test_mode(&self) -> Option<&'static str> {
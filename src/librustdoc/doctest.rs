@@ -961,6 +961,12 @@ fn add_test(&mut self, test: String, config: LangString, line: usize) {
                 compile_fail: config.compile_fail,
                 no_run,
                 test_type: test::TestType::DocTest,
+                #[cfg(not(bootstrap))]
+                metadata: &[],
+                #[cfg(not(bootstrap))]
+                resource: None,
+                #[cfg(not(bootstrap))]
+                source_file: None,
             },
             testfn: test::DynTestFn(box move || {
                 let report_unused_externs = |uext| {
@@ -0,0 +1,35 @@
+// compile-flags: -O
+// ignore-debug: the debug assertions get in the way
+
+#![crate_type = "lib"]
+
+// `clone_from` on an array of `Copy` elements, and the `clone_from_slice` it
+// goes through, should be a single memcpy, not an element-wise loop.
+
+// CHECK-LABEL: @clone_from_u8_array
+#[no_mangle]
+pub fn clone_from_u8_array(dst: &mut [u8; 1024], src: &[u8; 1024]) {
+    // CHECK-NOT: br
+    // CHECK: call void @llvm.memcpy
+    // CHECK-NOT: br
+    // CHECK: ret void
+    dst.clone_from(src)
+}
+
+// CHECK-LABEL: @clone_u8_array
+#[no_mangle]
+pub fn clone_u8_array(dst: &mut [u8; 1024], src: &[u8; 1024]) {
+    // CHECK-NOT: br
+    // CHECK: call void @llvm.memcpy
+    // CHECK-NOT: br
+    // CHECK: ret void
+    *dst = src.clone()
+}
+
+// CHECK-LABEL: @clone_from_u8_slice
+#[no_mangle]
+pub fn clone_from_u8_slice(dst: &mut [u8], src: &[u8]) {
+    // The length check branches to a panic, but the copy itself is a memcpy.
+    // CHECK: call void @llvm.memcpy
+    dst.clone_from_slice(src)
+}
@@ -0,0 +1,39 @@
+// `maximum` and `minimum` are implemented with comparisons, so they must not
+// lower to a call into libm (`fmax`/`fmin` have different NaN and zero semantics).
+
+// compile-flags: -O
+
+#![crate_type = "lib"]
+#![feature(float_minimum_maximum)]
+
+// CHECK-LABEL: @maximum_f32
+#[no_mangle]
+pub fn maximum_f32(x: f32, y: f32) -> f32 {
+    // CHECK-NOT: call
+    // CHECK: ret float
+    x.maximum(y)
+}
+
+// CHECK-LABEL: @minimum_f32
+#[no_mangle]
+pub fn minimum_f32(x: f32, y: f32) -> f32 {
+    // CHECK-NOT: call
+    // CHECK: ret float
+    x.minimum(y)
+}
+
+// CHECK-LABEL: @maximum_f64
+#[no_mangle]
+pub fn maximum_f64(x: f64, y: f64) -> f64 {
+    // CHECK-NOT: call
+    // CHECK: ret double
+    x.maximum(y)
+}
+
+// CHECK-LABEL: @minimum_f64
+#[no_mangle]
+pub fn minimum_f64(x: f64, y: f64) -> f64 {
+    // CHECK-NOT: call
+    // CHECK: ret double
+    x.minimum(y)
+}
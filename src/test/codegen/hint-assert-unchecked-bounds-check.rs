@@ -0,0 +1,26 @@
+// no-system-llvm
+// compile-flags: -O
+// ignore-debug: the debug assertions get in the way
+#![crate_type = "lib"]
+#![feature(hint_assert_unchecked)]
+
+use std::hint::assert_unchecked;
+
+// Make sure that asserting `index < v.len()` lets the bounds check of the
+// indexing be optimized out.
+// CHECK-LABEL: @index_after_assert
+#[no_mangle]
+pub unsafe fn index_after_assert(v: &[u32], index: usize) -> u32 {
+    // CHECK-NOT: panic_bounds_check
+    assert_unchecked(index < v.len());
+    v[index]
+}
+
+// Make sure we're checking for the right thing: without the assertion the
+// bounds check is emitted.
+// CHECK-LABEL: @index_without_assert
+#[no_mangle]
+pub fn index_without_assert(v: &[u32], index: usize) -> u32 {
+    // CHECK: panic_bounds_check
+    v[index]
+}
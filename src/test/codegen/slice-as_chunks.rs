@@ -31,3 +31,18 @@
     // CHECK: ret
     x.as_chunks()
 }
+
+// CHECK-LABEL: @xor_chunks16
+#[no_mangle]
+pub fn xor_chunks16(x: &[u8]) -> [u8; 16] {
+    // CHECK-NOT: panic_bounds_check
+    // CHECK-NOT: slice_index_len_fail
+    // CHECK: ret
+    let mut acc = [0; 16];
+    for chunk in x.as_chunks::<16>().0 {
+        for i in 0..16 {
+            acc[i] ^= chunk[i];
+        }
+    }
+    acc
+}
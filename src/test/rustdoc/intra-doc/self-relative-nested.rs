@@ -0,0 +1,19 @@
+// Paths starting with `self::` must resolve relative to the module the doc
+// comment is in, not the crate root, even when modules are nested.
+#![crate_name = "foo"]
+
+pub struct Thing;
+
+pub mod outer {
+    pub struct Thing;
+
+    pub mod inner {
+        pub struct Thing;
+
+        // @has foo/outer/inner/struct.Linker.html '//a[@href="struct.Thing.html"]' 'self::Thing'
+        // @has foo/outer/inner/struct.Linker.html '//a[@href="../struct.Thing.html"]' 'super::Thing'
+        // @has foo/outer/inner/struct.Linker.html '//a[@href="../../struct.Thing.html"]' 'crate::Thing'
+        /// Links to [self::Thing], [super::Thing] and [crate::Thing].
+        pub struct Linker;
+    }
+}
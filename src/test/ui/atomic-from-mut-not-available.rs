@@ -4,4 +4,6 @@
 fn main() {
     core::sync::atomic::AtomicU64::from_mut(&mut 0u64);
     //~^ ERROR: no function or associated item named `from_mut` found for struct `AtomicU64`
+    core::sync::atomic::AtomicU64::from_mut_slice(&mut [0u64]);
+    //~^ ERROR: no function or associated item named `from_mut_slice` found for struct `AtomicU64`
 }
@@ -0,0 +1,10 @@
+// error-pattern: evaluation of constant value failed
+#![feature(hint_assert_unchecked)]
+
+use std::hint::assert_unchecked;
+
+const BAR: () = unsafe { assert_unchecked(false) };
+
+fn main() {
+    BAR
+}
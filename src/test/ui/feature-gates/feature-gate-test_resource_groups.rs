@@ -0,0 +1,4 @@
+// compile-flags: --test
+
+#[test(resource = "db")] //~ ERROR the `resource` argument of `#[test]` is experimental
+fn uses_the_database() {}
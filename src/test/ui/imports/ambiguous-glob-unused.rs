@@ -0,0 +1,31 @@
+// Two glob imports that disagree on what a name refers to are only an error
+// once the name is used. The opt-in `ambiguous_glob_imports` lint reports
+// them at the imports, even if the name is never used.
+
+#![deny(ambiguous_glob_imports)]
+#![allow(dead_code)]
+
+mod a {
+    pub struct Foo;
+    pub fn bar() {}
+    pub fn same() {}
+}
+
+mod b {
+    pub struct Foo;
+    pub fn bar() {}
+    pub use super::a::same;
+}
+
+pub use a::*; //~ ERROR `Foo` is ambiguous
+pub use b::*;
+//~^^ ERROR `bar` is ambiguous
+
+mod allowed {
+    #![allow(ambiguous_glob_imports)]
+
+    pub use super::a::*;
+    pub use super::b::*;
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+// Globs that all fail because of the same missing crate are reported once.
+
+use nonexistent::{*, a::*, b::c::*};
+//~^ ERROR unresolved import `nonexistent`
+
+fn main() {}
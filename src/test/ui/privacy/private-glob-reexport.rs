@@ -0,0 +1,14 @@
+// Names that are only reachable through a private glob import are reported as
+// private instead of as missing.
+
+mod outer {
+    mod inner {
+        pub struct S;
+    }
+
+    use self::inner::*;
+}
+
+use outer::S; //~ ERROR struct import `S` is private
+
+fn main() {}
@@ -0,0 +1,16 @@
+// run-rustfix
+// Checks that a private item of the same crate gets a machine-applicable
+// suggestion to make it `pub(crate)` at its definition.
+
+mod a {
+    fn f() {}
+
+    /// Some docs.
+    #[derive(Debug)]
+    struct S;
+}
+
+fn main() {
+    a::f(); //~ ERROR function `f` is private
+    let _s: Option<a::S> = None; //~ ERROR struct `S` is private
+}
@@ -0,0 +1,15 @@
+// edition:2018
+// aux-crate:serde_json=serde_json.rs
+
+// Check that a misspelled crate name at the start of a path suggests the
+// closest crate name from the extern prelude.
+
+use serde_jsonn::Value;
+//~^ ERROR unresolved import `serde_jsonn`
+//~| HELP there is a crate with a similar name
+
+fn main() {
+    let _ = serde_jsn::to_string(&0);
+    //~^ ERROR failed to resolve: use of undeclared crate or module `serde_jsn`
+    //~| HELP there is a crate with a similar name
+}
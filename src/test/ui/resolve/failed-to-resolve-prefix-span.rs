@@ -0,0 +1,14 @@
+// Check that E0433 points out the part of the path that was resolved
+// successfully before resolution failed.
+
+mod outer {
+    pub mod inner {
+        pub(in crate::outer::missing) struct S;
+        //~^ ERROR failed to resolve: could not find `missing` in `outer` [E0433]
+    }
+}
+
+fn main() {
+    let _: outer::inner::nope::T;
+    //~^ ERROR failed to resolve: could not find `nope` in `inner` [E0433]
+}
@@ -0,0 +1,7 @@
+// Tests in this file are defined in a different source file than the test crate root, so
+// `--filter-file` in `test-filter-file.rs` leaves them out.
+
+#[test]
+fn test3() {
+    panic!("this should not run");
+}
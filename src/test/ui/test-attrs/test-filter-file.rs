@@ -0,0 +1,17 @@
+// run-pass
+// compile-flags: --test
+// run-flags: --test-threads=1 --filter-file **/test-attrs/test-filter-file.rs -Zunstable-options
+// check-run-results
+// normalize-stdout-test "finished in \d+\.\d+s" -> "finished in $$TIME"
+// ignore-emscripten no threads support
+
+#[path = "auxiliary/test-filter-file-other.rs"]
+mod other;
+
+#[test]
+fn test1() {}
+
+mod inner {
+    #[test]
+    fn test2() {}
+}
@@ -0,0 +1,29 @@
+// run-pass
+// compile-flags: --test
+// ignore-emscripten no threads support
+#![feature(test_resource_groups)]
+
+// Both tests hold the same resource, so they never run at the same time even
+// though the harness runs tests in parallel.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+
+static IN_USE: AtomicBool = AtomicBool::new(false);
+
+fn use_the_database() {
+    assert!(!IN_USE.swap(true, Ordering::SeqCst), "the database is already in use");
+    thread::sleep(Duration::from_millis(20));
+    IN_USE.store(false, Ordering::SeqCst);
+}
+
+#[test(resource = "db")]
+fn first() {
+    use_the_database();
+}
+
+#[test(resource = "db")]
+fn second() {
+    use_the_database();
+}
@@ -906,6 +906,12 @@ pub fn make_test_description<R: Read>(
         compile_fail: false,
         no_run: false,
         test_type: test::TestType::Unknown,
+        #[cfg(not(bootstrap))]
+        metadata: &[],
+        #[cfg(not(bootstrap))]
+        resource: None,
+        #[cfg(not(bootstrap))]
+        source_file: None,
     }
 }
 
@@ -492,7 +492,9 @@ pub fn test_opts(config: &Config) -> test::TestOpts {
         exclude_should_panic: false,
         filters: config.filters.clone(),
         filter_exact: config.filter_exact,
+        exact_filters: vec![],
         run_ignored: if config.run_ignored { test::RunIgnored::Yes } else { test::RunIgnored::No },
+        deny_ignored: false,
         format: if config.quiet { test::OutputFormat::Terse } else { test::OutputFormat::Pretty },
         logfile: config.logfile.clone(),
         run_tests: true,
@@ -504,10 +506,21 @@ pub fn test_opts(config: &Config) -> test::TestOpts {
         color: config.color,
         test_threads: None,
         skip: vec![],
+        filter_files: vec![],
+        test_types: vec![],
         list: false,
         options: test::Options::new(),
         time_options: None,
         force_run_in_process: false,
+        capture_combined: false,
+        shuffle: false,
+        shuffle_seed: None,
+        metrics_out: None,
+        bench_baseline: None,
+        bench_fail_threshold: None,
+        repeat: 1,
+        detect_leaked_threads: false,
+        resource_limits: vec![],
     }
 }
 
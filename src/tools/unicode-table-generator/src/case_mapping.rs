@@ -19,6 +19,16 @@ pub(crate) fn generate_case_mapping(data: &UnicodeData) -> String {
         decl_type,
         fmt_list(data.to_upper.iter().map(to_mapping))
     ));
+    file.push_str("\n\n");
+    file.push_str(&format!(
+        "static CASE_FOLDING_TABLE: &[(char, char)] = &[{}];",
+        fmt_list(data.simple_fold.iter().map(|(key, folded)| {
+            (
+                CharEscape(std::char::from_u32(*key).unwrap()),
+                CharEscape(std::char::from_u32(*folded).unwrap()),
+            )
+        }))
+    ));
     file
 }
 
@@ -56,6 +66,17 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+pub fn to_simple_fold(c: char) -> char {
+    if c.is_ascii() {
+        (c as u8).to_ascii_lowercase() as char
+    } else {
+        match CASE_FOLDING_TABLE.binary_search_by(|&(key, _)| key.cmp(&c)) {
+            Err(_) => c,
+            Ok(index) => CASE_FOLDING_TABLE[index].1,
+        }
+    }
+}
+
 fn bsearch_case_table(c: char, table: &[(char, [char; 3])]) -> Option<usize> {
     table.binary_search_by(|&(key, _)| key.cmp(&c)).ok()
 }
@@ -72,11 +72,13 @@
 //! or not.
 
 use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
 use std::ops::Range;
 use ucd_parse::Codepoints;
 
 mod case_mapping;
 mod raw_emitter;
+mod script_mapping;
 mod skiplist;
 mod unicode_download;
 
@@ -98,6 +100,21 @@ struct UnicodeData {
     ranges: Vec<(&'static str, Vec<Range<u32>>)>,
     to_upper: BTreeMap<u32, (u32, u32, u32)>,
     to_lower: BTreeMap<u32, (u32, u32, u32)>,
+    /// Simple case folding, i.e. the common (`C`) and simple (`S`) entries of CaseFolding.txt.
+    simple_fold: BTreeMap<u32, u32>,
+    scripts: ScriptData,
+}
+
+struct ScriptData {
+    /// Long names of all scripts, sorted. The index of a name is its value in the runs below.
+    names: Vec<String>,
+    /// Runs of codepoints with the same Script, as `(start, script)`, covering all codepoints.
+    script: Vec<(u32, u8)>,
+    /// The distinct sets of scripts listed in ScriptExtensions.txt.
+    extension_sets: Vec<Vec<u8>>,
+    /// Runs of codepoints with the same Script_Extensions, as `(start, set index + 1)`, covering
+    /// all codepoints. The value is 0 for codepoints whose extensions are just their script.
+    script_extensions: Vec<(u32, u8)>,
 }
 
 fn to_mapping(origin: u32, codepoints: Vec<ucd_parse::Codepoint>) -> Option<(u32, u32, u32)> {
@@ -126,6 +143,79 @@ fn to_mapping(origin: u32, codepoints: Vec<ucd_parse::Codepoint>) -> Option<(u32
 
 static UNICODE_DIRECTORY: &str = "unicode-downloads";
 
+fn codepoint_values(codepoints: Codepoints) -> Vec<u32> {
+    match codepoints {
+        Codepoints::Single(c) => vec![c.value()],
+        Codepoints::Range(c) => c.into_iter().map(|c| c.value()).collect(),
+    }
+}
+
+/// Collapses a value per codepoint into runs of equal values, as `(start, value)`.
+fn values_to_runs(values: &[u8]) -> Vec<(u32, u8)> {
+    let mut runs: Vec<(u32, u8)> = Vec::new();
+    for (codepoint, &value) in values.iter().enumerate() {
+        if runs.last().map_or(true, |&(_, last)| last != value) {
+            runs.push((codepoint as u32, value));
+        }
+    }
+    runs
+}
+
+fn load_scripts() -> ScriptData {
+    // ScriptExtensions.txt refers to scripts by their short names.
+    let mut long_names = HashMap::new();
+    for row in ucd_parse::parse::<_, ucd_parse::PropertyValueAlias>(&UNICODE_DIRECTORY).unwrap() {
+        if row.property == "sc" {
+            for name in std::iter::once(&row.abbreviation).chain(&row.aliases) {
+                long_names.insert(name.clone(), row.long.clone());
+            }
+            long_names.insert(row.long.clone(), row.long);
+        }
+    }
+
+    let script_rows = ucd_parse::parse::<_, ucd_parse::Script>(&UNICODE_DIRECTORY).unwrap();
+    let mut names = script_rows.iter().map(|row| row.script.clone()).collect::<Vec<_>>();
+    // Codepoints that aren't listed have the script `Unknown`.
+    names.push(String::from("Unknown"));
+    names.sort();
+    names.dedup();
+    assert!(names.len() <= 256, "{} scripts don't fit into a u8", names.len());
+    let index = |name: &str| names.binary_search_by(|n| n.as_str().cmp(name)).unwrap() as u8;
+
+    let mut script = vec![index("Unknown"); std::char::MAX as usize + 1];
+    for row in &script_rows {
+        for codepoint in codepoint_values(row.codepoints) {
+            script[codepoint as usize] = index(&row.script);
+        }
+    }
+
+    let mut extension_sets: Vec<Vec<u8>> = Vec::new();
+    let mut script_extensions = vec![0; std::char::MAX as usize + 1];
+    for row in ucd_parse::parse::<_, ucd_parse::ScriptExtension>(&UNICODE_DIRECTORY).unwrap() {
+        let mut set = row.scripts.iter().map(|name| index(&long_names[name])).collect::<Vec<_>>();
+        set.sort();
+        set.dedup();
+        let set_idx = match extension_sets.iter().position(|s| *s == set) {
+            Some(idx) => idx,
+            None => {
+                extension_sets.push(set);
+                extension_sets.len() - 1
+            }
+        };
+        let value = u8::try_from(set_idx + 1).expect("too many distinct script extension sets");
+        for codepoint in codepoint_values(row.codepoints) {
+            script_extensions[codepoint as usize] = value;
+        }
+    }
+
+    ScriptData {
+        names,
+        script: values_to_runs(&script),
+        extension_sets,
+        script_extensions: values_to_runs(&script_extensions),
+    }
+}
+
 fn load_data() -> UnicodeData {
     unicode_download::fetch_latest();
 
@@ -185,6 +275,19 @@ fn load_data() -> UnicodeData {
         }
     }
 
+    let mut simple_fold = BTreeMap::new();
+    for row in ucd_parse::parse::<_, ucd_parse::CaseFold>(&UNICODE_DIRECTORY).unwrap() {
+        match row.status {
+            ucd_parse::CaseStatus::Common | ucd_parse::CaseStatus::Simple => {
+                assert_eq!(row.mapping.len(), 1, "simple case folding maps to a single codepoint");
+                simple_fold.insert(row.codepoint.value(), row.mapping[0].value());
+            }
+            // Full foldings may expand to several codepoints, and Turkic ones depend on the
+            // locale; neither fits a char-to-char table.
+            ucd_parse::CaseStatus::Full | ucd_parse::CaseStatus::Special => {}
+        }
+    }
+
     let mut properties: HashMap<&'static str, Vec<Range<u32>>> = properties
         .into_iter()
         .map(|(k, v)| {
@@ -213,7 +316,7 @@ fn load_data() -> UnicodeData {
 
     let mut properties = properties.into_iter().collect::<Vec<_>>();
     properties.sort_by_key(|p| p.0);
-    UnicodeData { ranges: properties, to_lower, to_upper }
+    UnicodeData { ranges: properties, to_lower, to_upper, simple_fold, scripts: load_scripts() }
 }
 
 fn main() {
@@ -234,7 +337,8 @@ fn main() {
     let ranges_by_property = &unicode_data.ranges;
 
     if let Some(path) = test_path {
-        std::fs::write(&path, generate_tests(&write_location, &ranges_by_property)).unwrap();
+        let tests = generate_tests(&write_location, &ranges_by_property, &unicode_data.scripts);
+        std::fs::write(&path, tests).unwrap();
     }
 
     let mut total_bytes = 0;
@@ -258,6 +362,27 @@ fn main() {
         total_bytes += emitter.bytes_used;
     }
 
+    let scripts = &unicode_data.scripts;
+    for (property, runs, emitter) in vec![
+        ("Script", &scripts.script, script_mapping::generate_script(scripts)),
+        (
+            "Script_Extensions",
+            &scripts.script_extensions,
+            script_mapping::generate_script_extensions(scripts),
+        ),
+    ] {
+        println!(
+            "{:15}: {} bytes, {} scripts in {} runs using {}",
+            property,
+            emitter.bytes_used,
+            scripts.names.len(),
+            runs.len(),
+            emitter.desc,
+        );
+        total_bytes += emitter.bytes_used;
+        modules.push((property.to_lowercase(), emitter.file));
+    }
+
     let mut table_file = String::new();
 
     table_file.push_str(
@@ -330,7 +455,11 @@ fn fmt_list<V: std::fmt::Debug>(values: impl IntoIterator<Item = V>) -> String {
     out
 }
 
-fn generate_tests(data_path: &str, ranges: &[(&str, Vec<Range<u32>>)]) -> String {
+fn generate_tests(
+    data_path: &str,
+    ranges: &[(&str, Vec<Range<u32>>)],
+    scripts: &ScriptData,
+) -> String {
     let mut s = String::new();
     s.push_str("#![allow(incomplete_features, unused)]\n");
     s.push_str("#![feature(const_generics)]\n\n");
@@ -366,10 +495,70 @@ fn generate_tests(data_path: &str, ranges: &[(&str, Vec<Range<u32>>)]) -> String
         s.push_str("    }\n\n");
     }
 
+    generate_script_asserts(&mut s, scripts);
+
     s.push_str("}");
     s
 }
 
+/// Checks the Script and Script_Extensions lookups for every codepoint.
+fn generate_script_asserts(s: &mut String, scripts: &ScriptData) {
+    let variant =
+        |idx: u8| format!("Script::{}", script_mapping::variant_name(&scripts.names[idx as usize]));
+
+    s.push_str("    println!(\"Testing Script\");\n");
+    s.push_str("    script();\n");
+    s.push_str("    println!(\"Testing Script_Extensions\");\n");
+    s.push_str("    script_extensions();\n");
+
+    s.push_str("    fn script() {\n");
+    s.push_str("        use unicode_data::script::{lookup, Script};\n");
+    for (range, script) in runs_to_ranges(&scripts.script) {
+        s.push_str(&format!("        for chn in {:?}u32 {{\n", range));
+        s.push_str("            if let Some(c) = std::char::from_u32(chn) {\n");
+        s.push_str(&format!(
+            "                assert_eq!(lookup(c), {}, \"{{:?}}\", chn);\n",
+            variant(script)
+        ));
+        s.push_str("            }\n");
+        s.push_str("        }\n");
+    }
+    s.push_str("    }\n\n");
+
+    s.push_str("    fn script_extensions() {\n");
+    s.push_str("        use unicode_data::script::{self, Script};\n");
+    s.push_str("        use unicode_data::script_extensions::lookup;\n");
+    for (range, set_idx) in runs_to_ranges(&scripts.script_extensions) {
+        let expected = match set_idx {
+            0 => String::from("script::lookup(c)"),
+            idx => scripts.extension_sets[idx as usize - 1]
+                .iter()
+                .map(|&script| variant(script))
+                .collect::<Vec<_>>()
+                .join(", "),
+        };
+        s.push_str(&format!("        for chn in {:?}u32 {{\n", range));
+        s.push_str("            if let Some(c) = std::char::from_u32(chn) {\n");
+        s.push_str(&format!(
+            "                assert_eq!(lookup(c), &[{}][..], \"{{:?}}\", chn);\n",
+            expected
+        ));
+        s.push_str("            }\n");
+        s.push_str("        }\n");
+    }
+    s.push_str("    }\n\n");
+}
+
+/// Turns runs as `(start, value)` back into the ranges of codepoints they cover.
+fn runs_to_ranges(runs: &[(u32, u8)]) -> Vec<(Range<u32>, u8)> {
+    let ends = runs
+        .iter()
+        .skip(1)
+        .map(|&(start, _)| start)
+        .chain(std::iter::once(std::char::MAX as u32 + 1));
+    runs.iter().zip(ends).map(|(&(start, value), end)| (start..end, value)).collect()
+}
+
 fn generate_asserts(s: &mut String, property: &str, points: &[u32], truthy: bool) {
     for range in ranges_from_set(points) {
         if range.end == range.start + 1 {
@@ -57,6 +57,19 @@ fn skip_search<const SOR: usize, const OFFSETS: usize>(
     short_offset_runs: &[u32; SOR],
     offsets: &[u8; OFFSETS],
 ) -> bool {
+    // The boundaries alternate between range starts and range ends, so the needle is in a range
+    // iff an odd number of them are at or below it.
+    skip_search_index(needle, short_offset_runs, offsets) % 2 == 1
+}
+
+/// Returns the number of boundaries encoded in `short_offset_runs` and `offsets` that are at or
+/// below `needle`.
+#[inline(always)]
+fn skip_search_index<const SOR: usize, const OFFSETS: usize>(
+    needle: u32,
+    short_offset_runs: &[u32; SOR],
+    offsets: &[u8; OFFSETS],
+) -> usize {
     // Note that this *cannot* be past the end of the array, as the last
     // element is greater than std::char::MAX (the largest possible needle).
     //
@@ -89,5 +102,5 @@ fn skip_search<const SOR: usize, const OFFSETS: usize>(
         }
         offset_idx += 1;
     }
-    offset_idx % 2 == 1
+    offset_idx
 }
@@ -168,6 +168,51 @@ pub fn emit_codepoints(emitter: &mut RawEmitter, ranges: &[Range<u32>]) {
     }
 }
 
+/// Emits a `lookup_index` function mapping each codepoint to a small value, given as runs of
+/// equal values sorted by their start. The runs must cover all codepoints, starting at 0.
+pub fn emit_codepoint_values(emitter: &mut RawEmitter, runs: &[(u32, u8)]) {
+    emitter.blank_line();
+
+    let mut run_table = emitter.clone();
+    run_table.emit_run_table(runs);
+
+    let mut skiplist = emitter.clone();
+    if skiplist.emit_skiplist_values(runs) && skiplist.bytes_used < run_table.bytes_used {
+        *emitter = skiplist;
+        emitter.desc = String::from("skiplist");
+    } else {
+        *emitter = run_table;
+        emitter.desc = String::from("run table");
+    }
+}
+
+impl RawEmitter {
+    /// Emits the runs as a sorted table of their starts, each packed with the value of the run
+    /// in the lowest 8 bits, which is binary searched.
+    fn emit_run_table(&mut self, runs: &[(u32, u8)]) {
+        assert_eq!(runs[0].0, 0, "runs must start at codepoint 0");
+        writeln!(
+            &mut self.file,
+            "static RUNS: [u32; {}] = [{}];",
+            runs.len(),
+            fmt_list(runs.iter().map(|&(start, value)| start << 8 | value as u32))
+        )
+        .unwrap();
+        self.bytes_used += 4 * runs.len();
+
+        writeln!(&mut self.file, "pub fn lookup_index(c: char) -> u8 {{").unwrap();
+        writeln!(&mut self.file, "    // The first run starts at 0, so this can't underflow.")
+            .unwrap();
+        writeln!(
+            &mut self.file,
+            "    let idx = RUNS.partition_point(|run| run >> 8 <= c as u32) - 1;"
+        )
+        .unwrap();
+        writeln!(&mut self.file, "    RUNS[idx] as u8").unwrap();
+        writeln!(&mut self.file, "}}").unwrap();
+    }
+}
+
 struct Canonicalized {
     canonical_words: Vec<u64>,
     canonicalized_words: Vec<(u8, u8)>,
@@ -0,0 +1,103 @@
+use crate::raw_emitter::{emit_codepoint_values, RawEmitter};
+use crate::{fmt_list, ScriptData};
+use std::fmt::{self, Write};
+
+/// Emits the `script` module: the `Script` enum and the lookup of the Script property.
+pub(crate) fn generate_script(data: &ScriptData) -> RawEmitter {
+    let mut emitter = RawEmitter::new();
+    emit_allow_dead_code(&mut emitter);
+
+    writeln!(&mut emitter.file, "#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]").unwrap();
+    writeln!(&mut emitter.file, "#[repr(u8)]").unwrap();
+    writeln!(&mut emitter.file, "pub enum Script {{").unwrap();
+    for name in &data.names {
+        writeln!(&mut emitter.file, "    {},", variant_name(name)).unwrap();
+    }
+    writeln!(&mut emitter.file, "}}").unwrap();
+    writeln!(&mut emitter.file).unwrap();
+
+    writeln!(
+        &mut emitter.file,
+        "pub static ALL: [Script; {}] = [{}];",
+        data.names.len(),
+        fmt_list(data.names.iter().map(|name| ScriptVariant(name)))
+    )
+    .unwrap();
+    emitter.bytes_used += data.names.len();
+    writeln!(&mut emitter.file).unwrap();
+
+    writeln!(&mut emitter.file, "pub fn lookup(c: char) -> Script {{").unwrap();
+    writeln!(&mut emitter.file, "    ALL[lookup_index(c) as usize]").unwrap();
+    writeln!(&mut emitter.file, "}}").unwrap();
+
+    emit_codepoint_values(&mut emitter, &data.script);
+    emitter
+}
+
+/// Emits the `script_extensions` module, which looks up the Script_Extensions property as a
+/// slice of `script::Script`s.
+pub(crate) fn generate_script_extensions(data: &ScriptData) -> RawEmitter {
+    let mut emitter = RawEmitter::new();
+    emit_allow_dead_code(&mut emitter);
+
+    writeln!(&mut emitter.file, "use super::script::{{self, Script}};").unwrap();
+    writeln!(&mut emitter.file).unwrap();
+
+    writeln!(
+        &mut emitter.file,
+        "static SETS: [&[Script]; {}] = [{}];",
+        data.extension_sets.len(),
+        fmt_list(data.extension_sets.iter().map(|set| ScriptSet(&data.names, set)))
+    )
+    .unwrap();
+    // A slice reference, plus one byte per script in it.
+    let slice_size = 2 * std::mem::size_of::<usize>();
+    emitter.bytes_used +=
+        data.extension_sets.iter().map(|set| slice_size + set.len()).sum::<usize>();
+    writeln!(&mut emitter.file).unwrap();
+
+    writeln!(&mut emitter.file, "pub fn lookup(c: char) -> &'static [Script] {{").unwrap();
+    writeln!(&mut emitter.file, "    match lookup_index(c) {{").unwrap();
+    writeln!(&mut emitter.file, "        // Without explicit extensions, they are just the script.")
+        .unwrap();
+    writeln!(&mut emitter.file, "        0 => {{").unwrap();
+    writeln!(&mut emitter.file, "            let idx = script::lookup(c) as usize;").unwrap();
+    writeln!(&mut emitter.file, "            &script::ALL[idx..idx + 1]").unwrap();
+    writeln!(&mut emitter.file, "        }}").unwrap();
+    writeln!(&mut emitter.file, "        idx => SETS[idx as usize - 1],").unwrap();
+    writeln!(&mut emitter.file, "    }}").unwrap();
+    writeln!(&mut emitter.file, "}}").unwrap();
+
+    emit_codepoint_values(&mut emitter, &data.script_extensions);
+    emitter
+}
+
+fn emit_allow_dead_code(emitter: &mut RawEmitter) {
+    writeln!(&mut emitter.file, "// FIXME: no `char` method exposes the script lookups yet.")
+        .unwrap();
+    writeln!(&mut emitter.file, "#![allow(dead_code)]").unwrap();
+    writeln!(&mut emitter.file).unwrap();
+}
+
+/// Turns a long script name like `Old_Italic` into the name of its `Script` variant.
+pub(crate) fn variant_name(name: &str) -> String {
+    name.replace('_', "")
+}
+
+struct ScriptVariant<'a>(&'a str);
+
+impl fmt::Debug for ScriptVariant<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Script::{}", variant_name(self.0))
+    }
+}
+
+struct ScriptSet<'a>(&'a [String], &'a [u8]);
+
+impl fmt::Debug for ScriptSet<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ScriptSet(names, set) = *self;
+        let variants = set.iter().map(|&idx| ScriptVariant(&names[idx as usize]));
+        write!(f, "&[{}]", variants.map(|v| format!("{:?}", v)).collect::<Vec<_>>().join(", "))
+    }
+}
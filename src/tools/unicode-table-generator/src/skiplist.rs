@@ -26,10 +26,54 @@ fn pack(&self) -> u32 {
 
 impl RawEmitter {
     pub fn emit_skiplist(&mut self, ranges: &[Range<u32>]) {
-        let mut offsets = Vec::<u32>::new();
         let points = ranges.iter().flat_map(|r| vec![r.start, r.end]).collect::<Vec<u32>>();
+        self.emit_short_offset_runs(&points);
+
+        writeln!(&mut self.file, "pub fn lookup(c: char) -> bool {{").unwrap();
+        writeln!(&mut self.file, "    super::skip_search(",).unwrap();
+        writeln!(&mut self.file, "        c as u32,").unwrap();
+        writeln!(&mut self.file, "        &SHORT_OFFSET_RUNS,").unwrap();
+        writeln!(&mut self.file, "        &OFFSETS,").unwrap();
+        writeln!(&mut self.file, "    )").unwrap();
+        writeln!(&mut self.file, "}}").unwrap();
+    }
+
+    /// Emits a skiplist over the starts of `runs`, which must cover all codepoints starting
+    /// at 0, along with the value of every run. Returns `false` without emitting anything if
+    /// there are too many runs for the 11 bits of offset index in the run headers.
+    pub fn emit_skiplist_values(&mut self, runs: &[(u32, u8)]) -> bool {
+        assert_eq!(runs[0].0, 0, "runs must start at codepoint 0");
+        // Every start but the first is a boundary, so the number of boundaries at or below a
+        // codepoint is the index of the run containing it.
+        let points = runs[1..].iter().map(|&(start, _)| start).collect::<Vec<u32>>();
+        if points.len() >= 1 << 11 {
+            return false;
+        }
+        self.emit_short_offset_runs(&points);
+
+        writeln!(
+            &mut self.file,
+            "static VALUES: [u8; {}] = [{}];",
+            runs.len(),
+            fmt_list(runs.iter().map(|&(_, value)| value))
+        )
+        .unwrap();
+        self.bytes_used += runs.len();
+
+        writeln!(&mut self.file, "pub fn lookup_index(c: char) -> u8 {{").unwrap();
+        writeln!(&mut self.file, "    VALUES[super::skip_search_index(",).unwrap();
+        writeln!(&mut self.file, "        c as u32,").unwrap();
+        writeln!(&mut self.file, "        &SHORT_OFFSET_RUNS,").unwrap();
+        writeln!(&mut self.file, "        &OFFSETS,").unwrap();
+        writeln!(&mut self.file, "    )]").unwrap();
+        writeln!(&mut self.file, "}}").unwrap();
+        true
+    }
+
+    fn emit_short_offset_runs(&mut self, points: &[u32]) {
+        let mut offsets = Vec::<u32>::new();
         let mut offset = 0;
-        for pt in points {
+        for &pt in points {
             let delta = pt - offset;
             offsets.push(delta);
             offset = pt;
@@ -86,13 +130,5 @@ pub fn emit_skiplist(&mut self, ranges: &[Range<u32>]) {
         )
         .unwrap();
         self.bytes_used += coded_offsets.len();
-
-        writeln!(&mut self.file, "pub fn lookup(c: char) -> bool {{").unwrap();
-        writeln!(&mut self.file, "    super::skip_search(",).unwrap();
-        writeln!(&mut self.file, "        c as u32,").unwrap();
-        writeln!(&mut self.file, "        &SHORT_OFFSET_RUNS,").unwrap();
-        writeln!(&mut self.file, "        &OFFSETS,").unwrap();
-        writeln!(&mut self.file, "    )").unwrap();
-        writeln!(&mut self.file, "}}").unwrap();
     }
 }
@@ -6,8 +6,16 @@
 
 static README: &str = "ReadMe.txt";
 
-static RESOURCES: &[&str] =
-    &["DerivedCoreProperties.txt", "PropList.txt", "UnicodeData.txt", "SpecialCasing.txt"];
+static RESOURCES: &[&str] = &[
+    "DerivedCoreProperties.txt",
+    "PropList.txt",
+    "UnicodeData.txt",
+    "SpecialCasing.txt",
+    "CaseFolding.txt",
+    "Scripts.txt",
+    "ScriptExtensions.txt",
+    "PropertyValueAliases.txt",
+];
 
 pub fn fetch_latest() {
     let directory = Path::new(UNICODE_DIRECTORY);